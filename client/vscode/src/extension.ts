@@ -14,6 +14,8 @@ import {
     LanguageClient,
     type LanguageClientOptions,
     type ServerOptions,
+    type Location,
+    type Position,
 } from "vscode-languageclient/node";
 
 let client: LanguageClient | undefined = undefined;
@@ -48,6 +50,26 @@ async function startClient(context: ExtensionContext): Promise<void> {
 
     client = new LanguageClient("csslancer", "CssLancer", serverOptions, clientOptions);
 
+    // the server's code lenses peek at references with protocol objects, which
+    // `editor.action.showReferences` only takes as VS Code's own objects
+    context.subscriptions.push(
+        commands.registerCommand(
+            "csslancer.showReferences",
+            (uri: string, position: Position, locations: Location[]) => {
+                if (client === undefined) {
+                    return;
+                }
+                const converter = client.protocol2CodeConverter;
+                void commands.executeCommand(
+                    "editor.action.showReferences",
+                    converter.asUri(uri),
+                    converter.asPosition(position),
+                    locations.map((location) => converter.asLocation(location)),
+                );
+            },
+        ),
+    );
+
     client.start();
 }
 
@@ -4,6 +4,9 @@ use serde::Deserialize;
 use serde_json::{Map, Value};
 use tower_lsp::lsp_types::{self, InitializeParams, PositionEncodingKind};
 
+use crate::css_language_types::{
+    AliasSettings, CSSFormatConfiguration, CompletionSettings, LintSettings,
+};
 use crate::ext::InitializeParamsExt;
 
 #[derive(Debug, Clone, Copy)]
@@ -54,6 +57,44 @@ impl From<&InitializeParams> for ConstConfig {
     }
 }
 
+/// Which files under the workspace roots are indexed
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FilesSettings {
+    /// Glob, relative to a workspace root, of the stylesheets to index
+    pub include: String,
+    /// Globs, relative to a workspace root, of paths to skip on top of `.gitignore` rules
+    pub exclude: Vec<String>,
+}
+
+impl Default for FilesSettings {
+    fn default() -> Self {
+        Self {
+            include: "**/*.css".to_owned(),
+            exclude: vec!["**/node_modules".to_owned()],
+        }
+    }
+}
+
+/// Which kinds of inlay hints are shown
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct InlayHintsSettings {
+    /// The value of the custom property after each `var()`
+    pub variable_values: bool,
+    /// The specificity after each selector of a rule
+    pub selector_specificity: bool,
+}
+
+impl Default for InlayHintsSettings {
+    fn default() -> Self {
+        Self {
+            variable_values: true,
+            selector_specificity: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SemanticTokensMode {
@@ -72,6 +113,12 @@ pub struct Config {
     // pub export_pdf: ExportPdfMode,
     // pub root_path: Option<PathBuf>,
     pub semantic_tokens: SemanticTokensMode,
+    pub completion: CompletionSettings,
+    pub files: FilesSettings,
+    pub lint: LintSettings,
+    pub format: CSSFormatConfiguration,
+    pub alias: AliasSettings,
+    pub inlay_hints: InlayHintsSettings,
     // pub formatter: ExperimentalFormatterMode,
     semantic_tokens_listeners: Vec<Listener<SemanticTokensMode>>,
     // formatter_listeners: Vec<Listener<ExperimentalFormatterMode>>,
@@ -128,6 +175,54 @@ impl Config {
             self.semantic_tokens = semantic_tokens;
         }
 
+        let completion = update
+            .get("completion")
+            .map(CompletionSettings::deserialize)
+            .and_then(Result::ok);
+        if let Some(completion) = completion {
+            self.completion = completion;
+        }
+
+        let files = update
+            .get("files")
+            .map(FilesSettings::deserialize)
+            .and_then(Result::ok);
+        if let Some(files) = files {
+            self.files = files;
+        }
+
+        let lint = update
+            .get("lint")
+            .map(LintSettings::deserialize)
+            .and_then(Result::ok);
+        if let Some(lint) = lint {
+            self.lint = lint;
+        }
+
+        let format = update
+            .get("format")
+            .map(CSSFormatConfiguration::deserialize)
+            .and_then(Result::ok);
+        if let Some(format) = format {
+            self.format = format;
+        }
+
+        let alias = update
+            .get("alias")
+            .map(AliasSettings::deserialize)
+            .and_then(Result::ok);
+        if let Some(alias) = alias {
+            self.alias = alias;
+        }
+
+        let inlay_hints = update
+            .get("inlayHints")
+            .map(InlayHintsSettings::deserialize)
+            .and_then(Result::ok);
+        if let Some(inlay_hints) = inlay_hints {
+            self.inlay_hints = inlay_hints;
+        }
+
         Ok(())
     }
 }
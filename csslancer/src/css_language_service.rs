@@ -2,15 +2,39 @@ use crate::css_language_types::*;
 
 use std::collections::HashMap;
 
+impl Default for CompletionSettings {
+    fn default() -> Self {
+        CompletionSettings {
+            trigger_property_value_completion: true,
+            complete_property_with_semicolon: true,
+        }
+    }
+}
+
+impl Default for CSSFormatConfiguration {
+    fn default() -> Self {
+        CSSFormatConfiguration {
+            tab_size: 4,
+            insert_spaces: true,
+            insert_final_new_line: false,
+            newline_between_selectors: true,
+            newline_between_rules: true,
+            space_around_selector_seperator: false,
+            brace_style: BraceStyle::Collapse,
+            preserve_new_lines: true,
+            max_preserve_new_lines: None,
+            wrap_line_length: 0,
+            indent_empty_lines: false,
+        }
+    }
+}
+
 impl Default for LanguageSettings {
     fn default() -> Self {
         LanguageSettings {
             validate: true,
             lint: HashMap::new(),
-            completion: CompletionSettings {
-                trigger_property_value_completion: true,
-                complete_property_with_semicolon: true,
-            },
+            completion: CompletionSettings::default(),
             hover: HoverSettings {
                 documentation: true,
                 references: true,
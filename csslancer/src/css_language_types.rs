@@ -2,20 +2,23 @@
 
 use std::collections::HashMap;
 use std::hash::Hash;
-use std::path::PathBuf;
 
 use async_trait::async_trait;
 pub use lsp_types::*;
 use serde::de;
 
-type LintSettings = HashMap<String, String>;
+pub type LintSettings = HashMap<String, String>;
 
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct CompletionSettings {
     pub trigger_property_value_completion: bool,
     pub complete_property_with_semicolon: bool,
 }
 
-type AliasSettings = HashMap<String, String>;
+/// Prefixes of references to stylesheets and the paths, relative to the workspace root,
+/// they stand for, like `@` for `src`
+pub type AliasSettings = HashMap<String, String>;
 
 #[derive(Debug, Default)]
 pub struct HoverSettings {
@@ -215,6 +218,7 @@ pub struct PropertyData {
     pub values: Vec<ValueData>,
     pub references: Option<Vec<Reference>>,
     pub relevance: i64,
+    #[serde(rename = "atRule")]
     pub at_rule: Option<String>,
 }
 
@@ -230,6 +234,7 @@ pub struct PropertyDataSource {
     pub values: Vec<AtDirectiveDataSource>,
     pub references: Option<Vec<Reference>>,
     pub relevance: i64,
+    #[serde(rename = "atRule")]
     pub at_rule: Option<String>,
 }
 
@@ -263,6 +268,8 @@ pub struct AtDirectiveData {
     pub browsers: Option<Vec<String>>,
     pub status: Option<EntryStatus>,
     pub references: Option<Vec<Reference>>,
+    #[serde(default)]
+    pub relevance: Option<i64>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -272,6 +279,8 @@ pub struct AtDirectiveDataSource {
     pub browsers: Option<Vec<String>>,
     pub status: Option<EntryStatus>,
     pub references: Option<Vec<Reference>>,
+    #[serde(default)]
+    pub relevance: Option<i64>,
 }
 
 impl From<AtDirectiveDataSource> for AtDirectiveData {
@@ -282,6 +291,7 @@ impl From<AtDirectiveDataSource> for AtDirectiveData {
             browsers: value.browsers,
             status: value.status,
             references: value.references,
+            relevance: value.relevance,
         }
     }
 }
@@ -292,6 +302,54 @@ impl Hash for AtDirectiveData {
     }
 }
 
+/// A parameter of a function, `name` is how it is shown in the signature, e.g. `<min>`
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct FunctionParameterData {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct FunctionData {
+    pub name: String,
+    pub description: Option<Content>,
+    pub browsers: Option<Vec<String>>,
+    pub status: Option<EntryStatus>,
+    #[serde(default)]
+    pub parameters: Vec<FunctionParameterData>,
+    pub references: Option<Vec<Reference>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct FunctionDataSource {
+    pub name: String,
+    pub description: Option<String>,
+    pub browsers: Option<Vec<String>>,
+    pub status: Option<EntryStatus>,
+    #[serde(default)]
+    pub parameters: Vec<FunctionParameterData>,
+    pub references: Option<Vec<Reference>>,
+}
+
+impl From<FunctionDataSource> for FunctionData {
+    fn from(value: FunctionDataSource) -> Self {
+        FunctionData {
+            name: value.name,
+            description: value.description.map(Content::String),
+            browsers: value.browsers,
+            status: value.status,
+            parameters: value.parameters,
+            references: value.references,
+        }
+    }
+}
+
+impl Hash for FunctionData {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write(&self.name.clone().into_bytes());
+    }
+}
+
 pub type PseudoClassData = AtDirectiveData;
 pub type PseudoElementData = AtDirectiveData;
 pub type ValueData = AtDirectiveData;
@@ -371,19 +429,24 @@ pub struct CssDataV1 {
     pub pseudo_classes: Vec<PseudoClassData>,
     #[serde(alias = "pseudoElements")]
     pub pseudo_elements: Vec<PseudoElementData>,
+    #[serde(default)]
+    pub functions: Vec<FunctionData>,
 }
 
 
 #[derive(serde::Deserialize, serde::Serialize)]
 pub struct CssDataV1Source {
     pub version: f64,
+    #[serde(default)]
     pub properties: Vec<PropertyDataSource>,
-    #[serde(alias = "atDirectives")]
+    #[serde(default, alias = "atDirectives")]
     pub at_directives: Vec<AtDirectiveDataSource>,
-    #[serde(alias = "pseudoClasses")]
+    #[serde(default, alias = "pseudoClasses")]
     pub pseudo_classes: Vec<AtDirectiveDataSource>,
-    #[serde(alias = "pseudoElements")]
+    #[serde(default, alias = "pseudoElements")]
     pub pseudo_elements: Vec<AtDirectiveDataSource>,
+    #[serde(default)]
+    pub functions: Vec<FunctionDataSource>,
 }
 
 impl From<CssDataV1Source> for CssDataV1 {
@@ -394,6 +457,7 @@ impl From<CssDataV1Source> for CssDataV1 {
             at_directives: value.at_directives.into_iter().map(|x| x.into()).collect(), 
             pseudo_classes: value.pseudo_classes.into_iter().map(|x| x.into()).collect(), 
             pseudo_elements: value.pseudo_elements.into_iter().map(|x| x.into()).collect(), 
+            functions: value.functions.into_iter().map(|x| x.into()).collect(),
         }
     }
 }
@@ -403,6 +467,9 @@ pub trait ProvideCssData {
     fn provide_at_directives(&mut self) -> Vec<AtDirectiveData>;
     fn provide_pseudo_classes(&mut self) -> Vec<PseudoClassData>;
     fn provide_pseudo_elements(&mut self) -> Vec<PseudoElementData>;
+    fn provide_functions(&mut self) -> Vec<FunctionData> {
+        Vec::new()
+    }
 }
 
 
@@ -419,10 +486,14 @@ impl ProvideCssData for CssDataV1 {
     fn provide_pseudo_elements(&mut self) -> Vec<PseudoElementData> {
         std::mem::take(&mut self.pseudo_elements)
     }
+    fn provide_functions(&mut self) -> Vec<FunctionData> {
+        std::mem::take(&mut self.functions)
+    }
 }
 
 //
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileType {
     Unknown,
     File,
@@ -430,49 +501,54 @@ pub enum FileType {
     SymbolicLink,
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct FileStat {
-    typ: FileType,
-    ctime: u64, // The creation timestamp in milliseconds elapsed since January 1, 1970 00:00:00 UTC.
-    mtime: u64,
-    size: u64, // in bytes
+    pub typ: FileType,
+    pub ctime: u64, // The creation timestamp in milliseconds elapsed since January 1, 1970 00:00:00 UTC.
+    pub mtime: u64,
+    pub size: u64, // in bytes
 }
 
 #[async_trait]
-pub trait FileSystemProvider {
-    async fn stat(uri: PathBuf) -> FileStat
-    where
-        Self: Sized;
-    async fn read_directory(uri: PathBuf) -> Vec<(String, FileType)>
-    where
-        Self: Sized;
+pub trait FileSystemProvider: Send + Sync {
+    /// `None` if nothing exists at `uri`
+    async fn stat(&self, uri: &Url) -> Option<FileStat>;
+    async fn read_directory(&self, uri: &Url) -> Vec<(String, FileType)>;
+    async fn get_content(&self, uri: &Url) -> Option<String>;
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum BraceStyle {
     Collapse,
     Expand,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct CSSFormatConfiguration {
     // indentation size. Default: 4
-    tab_size: u32,
+    pub tab_size: u32,
     // Whether to use spaces or tabs
-    insert_spaces: bool,
+    pub insert_spaces: bool,
     // end with a newline: Default: false
-    insert_final_new_line: bool,
+    #[serde(rename = "insertFinalNewline")]
+    pub insert_final_new_line: bool,
     // separate selectors with newline (e.g. "a,\nbr" or "a, br"): Default: true
-    newline_between_selectors: bool,
+    pub newline_between_selectors: bool,
     // add a new line after every css rule: Default: true
-    newline_between_rules: bool,
+    pub newline_between_rules: bool,
     // ensure space around selector separators:  '>', '+', '~' (e.g. "a>b" -> "a > b"): Default: false
-    space_around_selector_seperator: bool,
+    #[serde(rename = "spaceAroundSelectorSeparator")]
+    pub space_around_selector_seperator: bool,
     // put braces on the same line as rules (`collapse`), or put braces on own line, Allman / ANSI style (`expand`). Default `collapse`
-    brace_style: BraceStyle,
+    pub brace_style: BraceStyle,
     // whether existing line breaks before elements should be preserved. Default: true
-    preserve_new_lines: bool,
+    pub preserve_new_lines: bool,
     // maximum number of line breaks to be preserved in one chunk. Default: unlimited
-    max_preserve_new_lines: u32,
+    pub max_preserve_new_lines: Option<u32>,
     // maximum amount of characters per line (0/undefined = disabled). Default: disabled
-    wrap_line_length: u32,
+    pub wrap_line_length: u32,
     // add indenting whitespace to empty lines. Default: false
-    indent_empty_lines: bool,
+    pub indent_empty_lines: bool,
 }
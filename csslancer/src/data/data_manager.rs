@@ -1,4 +1,5 @@
-use crate::css_language_types::{AtDirectiveData, CssDataV1, CssDataV1Source, EntryStatus, PropertyData, ProvideCssData, PseudoClassData, PseudoElementData};
+use crate::css_language_types::{AtDirectiveData, CssDataV1, CssDataV1Source, EntryStatus, FunctionData, PropertyData, ProvideCssData, PseudoClassData, PseudoElementData};
+use crate::data::value_syntax::{self, Term};
 
 use std::collections::HashMap;
 
@@ -10,6 +11,9 @@ pub struct CssDataManager {
     at_directive_set: HashMap<String, AtDirectiveData>,
     pseudo_class_set: HashMap<String, PseudoClassData>,
     pseudo_element_set: HashMap<String, PseudoElementData>,
+    function_set: HashMap<String, FunctionData>,
+    /// The parsed value definition syntaxes of the properties and descriptors, by their text
+    syntax_set: HashMap<String, Term>,
 
     // properties: Vec<&PropertyData>,
     // at_directives: Vec<&AtDirectiveData>,
@@ -23,6 +27,8 @@ impl CssDataManager {
         if use_default_data_provider {
             let json_str = include_str!("./WebData.json");
             data_providers.push(Box::<CssDataV1>::new(serde_json::from_str::<'_, CssDataV1Source>(json_str).unwrap().into()));
+            let json_str = include_str!("./FunctionData.json");
+            data_providers.push(Box::<CssDataV1>::new(serde_json::from_str::<'_, CssDataV1Source>(json_str).unwrap().into()));
         }
         if let Some(mut custom_data_providers) = custom_data_providers {
             data_providers.append(&mut custom_data_providers);
@@ -42,6 +48,14 @@ impl CssDataManager {
             data_provider.provide_at_directives().into_iter().for_each(|i| {self.at_directive_set.insert(i.name.clone(), i);});
             data_provider.provide_pseudo_classes().into_iter().for_each(|i| {self.pseudo_class_set.insert(i.name.clone(), i);});
             data_provider.provide_pseudo_elements().into_iter().for_each(|i| {self.pseudo_element_set.insert(i.name.clone(), i);});
+            data_provider.provide_functions().into_iter().for_each(|i| {self.function_set.insert(i.name.clone(), i);});
+        }
+        for syntax in self.property_set.values().filter_map(|p| p.syntax.as_ref()) {
+            if !self.syntax_set.contains_key(syntax) {
+                if let Some(term) = value_syntax::parse(syntax) {
+                    self.syntax_set.insert(syntax.clone(), term);
+                }
+            }
         }
         // self.properties = self.property_set.iter().collect();
         // self.at_directives = self.at_directive_set.iter().collect();
@@ -53,6 +67,24 @@ impl CssDataManager {
     pub fn get_at_directive(&self, name: &str) -> Option<&AtDirectiveData> {return self.at_directive_set.get(name)}
     pub fn get_pseudo_class(&self, name: &str) -> Option<&PseudoClassData> {return self.pseudo_class_set.get(name)}
     pub fn get_pseudo_element(&self, name: &str) -> Option<&PseudoElementData> {return self.pseudo_element_set.get(name)}
+    pub fn get_function(&self, name: &str) -> Option<&FunctionData> {self.function_set.get(name)}
+    /// The parsed value definition `syntax` of a property or descriptor
+    pub fn get_syntax(&self, syntax: &str) -> Option<&Term> {self.syntax_set.get(syntax)}
+
+    pub fn properties(&self) -> impl Iterator<Item = &PropertyData> {self.property_set.values()}
+    pub fn at_directives(&self) -> impl Iterator<Item = &AtDirectiveData> {self.at_directive_set.values()}
+    pub fn pseudo_classes(&self) -> impl Iterator<Item = &PseudoClassData> {self.pseudo_class_set.values()}
+    pub fn pseudo_elements(&self) -> impl Iterator<Item = &PseudoElementData> {self.pseudo_element_set.values()}
+    pub fn functions(&self) -> impl Iterator<Item = &FunctionData> {self.function_set.values()}
+
+    /// The descriptor `name` of `at_rule`, like `system` of `@counter-style`
+    pub fn get_descriptor(&self, at_rule: &str, name: &str) -> Option<&PropertyData> {
+        self.property_set.get(name).filter(|p| p.at_rule.as_deref() == Some(at_rule))
+    }
+
+    pub fn descriptors<'a>(&'a self, at_rule: &'a str) -> impl Iterator<Item = &'a PropertyData> {
+        self.property_set.values().filter(move |p| p.at_rule.as_deref() == Some(at_rule))
+    }
 
     pub fn is_known_property(&self, name: &str) -> bool {
         let name = name.to_lowercase();
@@ -3,7 +3,7 @@
 use regex::Regex;
 use tracing::trace;
 
-use crate::css_language_types::{ AtDirectiveData, Content, EntryStatus, HoverSettings, MarkedString, MarkupContent, MarkupKind, PropertyData, PseudoClassData, PseudoElementData, Reference, ValueData };
+use crate::css_language_types::{ AtDirectiveData, Content, EntryStatus, FunctionData, HoverSettings, MarkedString, MarkupContent, MarkupKind, PropertyData, PseudoClassData, PseudoElementData, Reference, ValueData };
 
 // struct Browsers {
 //     E?: string;
@@ -183,15 +183,15 @@ pub fn get_browser_label(browsers: &[String]) -> Option<String> {
         return None
     }
 
+    let reg = Regex::new(r"(?<name>[A-Z]+)(?<version>\d+)?").unwrap();
     return Some(browsers
         .iter().map(|b| {
             let mut result = "".to_owned();
-            let reg = Regex::new(r"(?<name>[A-Z]+)(?<version>\d+)?").unwrap();
             let mut matches = reg.captures_iter(b);
 
             let first_mat = matches.next();
             let name = first_mat.as_ref().map(|f| f["name"].to_owned());
-            let version = first_mat.map(|f| f["version"].to_owned());
+            let version = first_mat.and_then(|f| f.name("version").map(|v| v.as_str().to_owned()));
 
             if let Some(name) = name {
                 trace!(name = name);
@@ -216,6 +216,7 @@ pub enum IEntry2<'a> {
     PseuClass(&'a PseudoClassData),
     PseuEle(&'a PseudoElementData),
     Value(&'a ValueData),
+    Func(&'a FunctionData),
 }
 
 impl IEntry2<'_> {
@@ -225,7 +226,8 @@ impl IEntry2<'_> {
             Self::AtDir(AtDirectiveData {description, ..}) |
             Self::PseuClass(PseudoClassData {description, ..}) |
             Self::PseuEle(PseudoElementData {description, ..}) |
-            Self::Value(ValueData {description, ..}) => {
+            Self::Value(ValueData {description, ..}) |
+            Self::Func(FunctionData {description, ..}) => {
                 description
             }
         }
@@ -237,7 +239,8 @@ impl IEntry2<'_> {
             Self::AtDir(AtDirectiveData {status, ..}) |
             Self::PseuClass(PseudoClassData {status, ..}) |
             Self::PseuEle(PseudoElementData {status, ..}) |
-            Self::Value(ValueData {status, ..}) => {
+            Self::Value(ValueData {status, ..}) |
+            Self::Func(FunctionData {status, ..}) => {
                 status
             }
         }
@@ -249,7 +252,8 @@ impl IEntry2<'_> {
             Self::AtDir(AtDirectiveData {browsers, ..}) |
             Self::PseuClass(PseudoClassData {browsers, ..}) |
             Self::PseuEle(PseudoElementData {browsers, ..}) |
-            Self::Value(ValueData {browsers, ..}) => {
+            Self::Value(ValueData {browsers, ..}) |
+            Self::Func(FunctionData {browsers, ..}) => {
                 browsers
             }
         }
@@ -268,7 +272,8 @@ impl IEntry2<'_> {
             Self::AtDir(AtDirectiveData {references, ..}) |
             Self::PseuClass(PseudoClassData {references, ..}) |
             Self::PseuEle(PseudoElementData {references, ..}) |
-            Self::Value(ValueData {references, ..}) => {
+            Self::Value(ValueData {references, ..}) |
+            Self::Func(FunctionData {references, ..}) => {
                 references
             }
         }
@@ -16,3 +16,336 @@ pub const PAGE_BOX_DIRECTIVES: &[&str; 16] = &[
     "@top-right",
     "@top-right-corner",
 ];
+
+/// CSS named colors and their sRGB hex value, see https://drafts.csswg.org/css-color/#named-colors
+pub const COLORS: &[(&str, &str)] = &[
+    ("aliceblue", "#f0f8ff"),
+    ("antiquewhite", "#faebd7"),
+    ("aqua", "#00ffff"),
+    ("aquamarine", "#7fffd4"),
+    ("azure", "#f0ffff"),
+    ("beige", "#f5f5dc"),
+    ("bisque", "#ffe4c4"),
+    ("black", "#000000"),
+    ("blanchedalmond", "#ffebcd"),
+    ("blue", "#0000ff"),
+    ("blueviolet", "#8a2be2"),
+    ("brown", "#a52a2a"),
+    ("burlywood", "#deb887"),
+    ("cadetblue", "#5f9ea0"),
+    ("chartreuse", "#7fff00"),
+    ("chocolate", "#d2691e"),
+    ("coral", "#ff7f50"),
+    ("cornflowerblue", "#6495ed"),
+    ("cornsilk", "#fff8dc"),
+    ("crimson", "#dc143c"),
+    ("cyan", "#00ffff"),
+    ("darkblue", "#00008b"),
+    ("darkcyan", "#008b8b"),
+    ("darkgoldenrod", "#b8860b"),
+    ("darkgray", "#a9a9a9"),
+    ("darkgreen", "#006400"),
+    ("darkgrey", "#a9a9a9"),
+    ("darkkhaki", "#bdb76b"),
+    ("darkmagenta", "#8b008b"),
+    ("darkolivegreen", "#556b2f"),
+    ("darkorange", "#ff8c00"),
+    ("darkorchid", "#9932cc"),
+    ("darkred", "#8b0000"),
+    ("darksalmon", "#e9967a"),
+    ("darkseagreen", "#8fbc8f"),
+    ("darkslateblue", "#483d8b"),
+    ("darkslategray", "#2f4f4f"),
+    ("darkslategrey", "#2f4f4f"),
+    ("darkturquoise", "#00ced1"),
+    ("darkviolet", "#9400d3"),
+    ("deeppink", "#ff1493"),
+    ("deepskyblue", "#00bfff"),
+    ("dimgray", "#696969"),
+    ("dimgrey", "#696969"),
+    ("dodgerblue", "#1e90ff"),
+    ("firebrick", "#b22222"),
+    ("floralwhite", "#fffaf0"),
+    ("forestgreen", "#228b22"),
+    ("fuchsia", "#ff00ff"),
+    ("gainsboro", "#dcdcdc"),
+    ("ghostwhite", "#f8f8ff"),
+    ("gold", "#ffd700"),
+    ("goldenrod", "#daa520"),
+    ("gray", "#808080"),
+    ("green", "#008000"),
+    ("greenyellow", "#adff2f"),
+    ("grey", "#808080"),
+    ("honeydew", "#f0fff0"),
+    ("hotpink", "#ff69b4"),
+    ("indianred", "#cd5c5c"),
+    ("indigo", "#4b0082"),
+    ("ivory", "#fffff0"),
+    ("khaki", "#f0e68c"),
+    ("lavender", "#e6e6fa"),
+    ("lavenderblush", "#fff0f5"),
+    ("lawngreen", "#7cfc00"),
+    ("lemonchiffon", "#fffacd"),
+    ("lightblue", "#add8e6"),
+    ("lightcoral", "#f08080"),
+    ("lightcyan", "#e0ffff"),
+    ("lightgoldenrodyellow", "#fafad2"),
+    ("lightgray", "#d3d3d3"),
+    ("lightgreen", "#90ee90"),
+    ("lightgrey", "#d3d3d3"),
+    ("lightpink", "#ffb6c1"),
+    ("lightsalmon", "#ffa07a"),
+    ("lightseagreen", "#20b2aa"),
+    ("lightskyblue", "#87cefa"),
+    ("lightslategray", "#778899"),
+    ("lightslategrey", "#778899"),
+    ("lightsteelblue", "#b0c4de"),
+    ("lightyellow", "#ffffe0"),
+    ("lime", "#00ff00"),
+    ("limegreen", "#32cd32"),
+    ("linen", "#faf0e6"),
+    ("magenta", "#ff00ff"),
+    ("maroon", "#800000"),
+    ("mediumaquamarine", "#66cdaa"),
+    ("mediumblue", "#0000cd"),
+    ("mediumorchid", "#ba55d3"),
+    ("mediumpurple", "#9370db"),
+    ("mediumseagreen", "#3cb371"),
+    ("mediumslateblue", "#7b68ee"),
+    ("mediumspringgreen", "#00fa9a"),
+    ("mediumturquoise", "#48d1cc"),
+    ("mediumvioletred", "#c71585"),
+    ("midnightblue", "#191970"),
+    ("mintcream", "#f5fffa"),
+    ("mistyrose", "#ffe4e1"),
+    ("moccasin", "#ffe4b5"),
+    ("navajowhite", "#ffdead"),
+    ("navy", "#000080"),
+    ("oldlace", "#fdf5e6"),
+    ("olive", "#808000"),
+    ("olivedrab", "#6b8e23"),
+    ("orange", "#ffa500"),
+    ("orangered", "#ff4500"),
+    ("orchid", "#da70d6"),
+    ("palegoldenrod", "#eee8aa"),
+    ("palegreen", "#98fb98"),
+    ("paleturquoise", "#afeeee"),
+    ("palevioletred", "#db7093"),
+    ("papayawhip", "#ffefd5"),
+    ("peachpuff", "#ffdab9"),
+    ("peru", "#cd853f"),
+    ("pink", "#ffc0cb"),
+    ("plum", "#dda0dd"),
+    ("powderblue", "#b0e0e6"),
+    ("purple", "#800080"),
+    ("rebeccapurple", "#663399"),
+    ("red", "#ff0000"),
+    ("rosybrown", "#bc8f8f"),
+    ("royalblue", "#4169e1"),
+    ("saddlebrown", "#8b4513"),
+    ("salmon", "#fa8072"),
+    ("sandybrown", "#f4a460"),
+    ("seagreen", "#2e8b57"),
+    ("seashell", "#fff5ee"),
+    ("sienna", "#a0522d"),
+    ("silver", "#c0c0c0"),
+    ("skyblue", "#87ceeb"),
+    ("slateblue", "#6a5acd"),
+    ("slategray", "#708090"),
+    ("slategrey", "#708090"),
+    ("snow", "#fffafa"),
+    ("springgreen", "#00ff7f"),
+    ("steelblue", "#4682b4"),
+    ("tan", "#d2b48c"),
+    ("teal", "#008080"),
+    ("thistle", "#d8bfd8"),
+    ("tomato", "#ff6347"),
+    ("turquoise", "#40e0d0"),
+    ("violet", "#ee82ee"),
+    ("wheat", "#f5deb3"),
+    ("white", "#ffffff"),
+    ("whitesmoke", "#f5f5f5"),
+    ("yellow", "#ffff00"),
+    ("yellowgreen", "#9acd32"),
+];
+
+/// Color keywords that do not map to a fixed color
+pub const COLOR_KEYWORDS: &[(&str, &str)] = &[
+    ("currentcolor", "The value of the 'color' property. The computed value of the 'currentColor' keyword is the computed value of the 'color' property."),
+    ("transparent", "Fully transparent. This keyword can be considered a shorthand for rgba(0,0,0,0) which is its computed value."),
+];
+
+/// A function that can appear in a property value, `insert_text` is a snippet
+pub struct FunctionFact {
+    pub name: &'static str,
+    pub insert_text: &'static str,
+    pub description: &'static str,
+}
+
+pub const COLOR_FUNCTIONS: &[FunctionFact] = &[
+    FunctionFact { name: "rgb", insert_text: "rgb(${1:red} ${2:green} ${3:blue})", description: "Creates a Color from red, green, and blue values." },
+    FunctionFact { name: "rgba", insert_text: "rgba(${1:red} ${2:green} ${3:blue} / ${4:alpha})", description: "Creates a Color from red, green, blue, and alpha values." },
+    FunctionFact { name: "hsl", insert_text: "hsl(${1:hue} ${2:saturation} ${3:lightness})", description: "Creates a Color from hue, saturation, and lightness values." },
+    FunctionFact { name: "hsla", insert_text: "hsla(${1:hue} ${2:saturation} ${3:lightness} / ${4:alpha})", description: "Creates a Color from hue, saturation, lightness, and alpha values." },
+    FunctionFact { name: "hwb", insert_text: "hwb(${1:hue} ${2:white} ${3:black})", description: "Creates a Color from hue, white, and black values." },
+    FunctionFact { name: "lab", insert_text: "lab(${1:lightness} ${2:a} ${3:b})", description: "Creates a Color from lightness, a, and b values." },
+    FunctionFact { name: "lch", insert_text: "lch(${1:lightness} ${2:chroma} ${3:hue})", description: "Creates a Color from lightness, chroma, and hue values." },
+    FunctionFact { name: "oklab", insert_text: "oklab(${1:lightness} ${2:a} ${3:b})", description: "Creates a Color from lightness, a, and b values in the Oklab color space." },
+    FunctionFact { name: "oklch", insert_text: "oklch(${1:lightness} ${2:chroma} ${3:hue})", description: "Creates a Color from lightness, chroma, and hue values in the Oklch color space." },
+    FunctionFact { name: "color", insert_text: "color(${1:srgb} ${2:r} ${3:g} ${4:b})", description: "Creates a Color in a given color space." },
+    FunctionFact { name: "color-mix", insert_text: "color-mix(in ${1:srgb}, ${2:color} ${3:50%}, ${4:color})", description: "Mixes two colors by a given amount in a given color space." },
+];
+
+pub const IMAGE_FUNCTIONS: &[FunctionFact] = &[
+    FunctionFact { name: "url", insert_text: "url($1)", description: "Reference an image file by URL." },
+    FunctionFact { name: "image", insert_text: "image($1)", description: "Provide image fallbacks and annotations." },
+    FunctionFact { name: "image-set", insert_text: "image-set($1)", description: "Provide multiple resolutions of an image and let the UA decide which is most appropriate." },
+    FunctionFact { name: "element", insert_text: "element(#${1:id})", description: "Use an element in the document as an image." },
+    FunctionFact { name: "cross-fade", insert_text: "cross-fade(${1:50%}, $2)", description: "Blend two images." },
+    FunctionFact { name: "linear-gradient", insert_text: "linear-gradient($1)", description: "A linear gradient image." },
+    FunctionFact { name: "radial-gradient", insert_text: "radial-gradient($1)", description: "A radial gradient image." },
+    FunctionFact { name: "conic-gradient", insert_text: "conic-gradient($1)", description: "A conic gradient image." },
+    FunctionFact { name: "repeating-linear-gradient", insert_text: "repeating-linear-gradient($1)", description: "A repeating linear gradient image." },
+    FunctionFact { name: "repeating-radial-gradient", insert_text: "repeating-radial-gradient($1)", description: "A repeating radial gradient image." },
+    FunctionFact { name: "repeating-conic-gradient", insert_text: "repeating-conic-gradient($1)", description: "A repeating conic gradient image." },
+];
+
+pub const TIMING_FUNCTIONS: &[FunctionFact] = &[
+    FunctionFact { name: "cubic-bezier", insert_text: "cubic-bezier(${1:0.1}, ${2:0.7}, ${3:1.0}, ${4:0.1})", description: "Defines a Cubic Bezier function." },
+    FunctionFact { name: "steps", insert_text: "steps(${1:1}, ${2:end})", description: "Defines a stepping function with a given number of intervals." },
+    FunctionFact { name: "linear", insert_text: "linear(${1:0}, ${2:1})", description: "Defines a piecewise linear easing function." },
+];
+
+pub const TIMING_KEYWORDS: &[(&str, &str)] = &[
+    ("ease", "Equivalent to cubic-bezier(0.25, 0.1, 0.25, 1.0)."),
+    ("ease-in", "Equivalent to cubic-bezier(0.42, 0, 1.0, 1.0)."),
+    ("ease-out", "Equivalent to cubic-bezier(0, 0, 0.58, 1.0)."),
+    ("ease-in-out", "Equivalent to cubic-bezier(0.42, 0, 0.58, 1.0)."),
+    ("linear", "Equivalent to cubic-bezier(0.0, 0.0, 1.0, 1.0)."),
+    ("step-start", "Equivalent to steps(1, start)."),
+    ("step-end", "Equivalent to steps(1, end)."),
+];
+
+/// At-rules that are missing from `WebData.json`
+pub const AT_RULES_MISSING_IN_DATA: &[&str] = &[
+    "@container",
+    "@font-palette-values",
+    "@position-try",
+    "@scope",
+    "@starting-style",
+    "@view-transition",
+];
+
+/// At-rule, name and value definition syntax of descriptors that are missing from
+/// `WebData.json`
+pub const DESCRIPTORS_MISSING_IN_DATA: &[(&str, &str, &str)] = &[
+    ("@font-feature-values", "font-display", "auto | block | swap | fallback | optional"),
+    ("@font-palette-values", "font-family", "<family-name>#"),
+    ("@view-transition", "navigation", "auto | none"),
+    ("@view-transition", "types", "none | <custom-ident>+"),
+];
+
+/// Syntax of the declarations in the feature value blocks of `@font-feature-values`, see
+/// https://drafts.csswg.org/css-fonts/#font-feature-values-syntax
+pub const FONT_FEATURE_VALUE_TYPES: &[(&str, &str)] = &[
+    ("@annotation", "<integer [0,∞]>"),
+    ("@character-variant", "<integer [1,99]> <integer [0,∞]>?"),
+    ("@historical-forms", "<integer [0,∞]>"),
+    ("@ornaments", "<integer [0,∞]>"),
+    ("@styleset", "<integer [1,20]>+"),
+    ("@stylistic", "<integer [0,∞]>"),
+    ("@swash", "<integer [0,∞]>"),
+];
+
+/// Pseudo-classes and pseudo-elements that are missing from `WebData.json`
+pub const PSEUDO_SELECTORS_MISSING_IN_DATA: &[&str] = &[
+    ":autofill",
+    ":modal",
+    ":muted",
+    ":open",
+    ":popover-open",
+    ":state",
+    ":volume-locked",
+    "::details-content",
+    "::file-selector-button",
+    "::highlight",
+];
+
+/// Pseudo-elements from CSS 2 that may also be written with a single colon
+pub const LEGACY_PSEUDO_ELEMENTS: &[&str] = &["after", "before", "first-letter", "first-line"];
+
+/// Units of `<length>`, see https://drafts.csswg.org/css-values/#lengths
+pub const LENGTH_UNITS: &[&str] = &[
+    "em", "rem", "ex", "rex", "cap", "rcap", "ch", "rch", "ic", "ric", "lh", "rlh", "vw", "svw",
+    "lvw", "dvw", "vh", "svh", "lvh", "dvh", "vi", "svi", "lvi", "dvi", "vb", "svb", "lvb", "dvb",
+    "vmin", "svmin", "lvmin", "dvmin", "vmax", "svmax", "lvmax", "dvmax", "cqw", "cqh", "cqi",
+    "cqb", "cqmin", "cqmax", "cm", "mm", "q", "in", "pt", "pc", "px",
+];
+
+/// Functions that compute a number, dimension or percentage, see
+/// https://drafts.csswg.org/css-values/#math
+pub const MATH_FUNCTIONS: &[&str] = &[
+    "calc", "min", "max", "clamp", "round", "mod", "rem", "sin", "cos", "tan", "asin", "acos",
+    "atan", "atan2", "pow", "sqrt", "hypot", "log", "exp", "abs", "sign",
+];
+
+/// System colors, including the deprecated ones, see https://drafts.csswg.org/css-color/#css-system-colors
+pub const SYSTEM_COLORS: &[&str] = &[
+    "accentcolor", "accentcolortext", "activetext", "buttonborder", "buttonface", "buttontext",
+    "canvas", "canvastext", "field", "fieldtext", "graytext", "highlight", "highlighttext",
+    "linktext", "mark", "marktext", "selecteditem", "selecteditemtext", "visitedtext",
+    "activeborder", "activecaption", "appworkspace", "background", "buttonhighlight",
+    "buttonshadow", "captiontext", "inactiveborder", "inactivecaption", "inactivecaptiontext",
+    "infobackground", "infotext", "menu", "menutext", "scrollbar", "threeddarkshadow",
+    "threedface", "threedhighlight", "threedlightshadow", "threedshadow", "window", "windowframe",
+    "windowtext",
+];
+
+/// Value definition syntax of types that property syntaxes in `WebData.json` refer to but
+/// that are not defined there
+pub const VALUE_TYPES: &[(&str, &str)] = &[
+    ("viewport-length", "auto | <length-percentage>"),
+    ("number-percentage", "<number> | <percentage>"),
+    ("alpha-value", "<number> | <percentage>"),
+    ("ratio", "<number [0,∞]> [ / <number [0,∞]> ]?"),
+    ("line-width", "<length> | thin | medium | thick"),
+    ("line-style", "none | hidden | dotted | dashed | solid | double | groove | ridge | inset | outset"),
+    ("box", "border-box | padding-box | content-box"),
+    ("absolute-size", "xx-small | x-small | small | medium | large | x-large | xx-large | xxx-large"),
+    ("relative-size", "larger | smaller"),
+    ("attachment", "scroll | fixed | local"),
+    ("repeat-style", "repeat-x | repeat-y | [ repeat | space | round | no-repeat ]{1,2}"),
+    ("self-position", "center | start | end | self-start | self-end | flex-start | flex-end"),
+    ("content-position", "center | start | end | flex-start | flex-end"),
+    ("content-distribution", "space-between | space-around | space-evenly | stretch"),
+    ("overflow-position", "unsafe | safe"),
+    ("baseline-position", "[ first | last ]? baseline"),
+    ("display-outside", "block | inline | run-in"),
+    ("display-inside", "flow | flow-root | table | flex | grid | ruby"),
+    ("display-listitem", "<display-outside>? && [ flow | flow-root ]? && list-item"),
+    ("display-internal", "table-row-group | table-header-group | table-footer-group | table-row | table-cell | table-column-group | table-column | table-caption | ruby-base | ruby-text | ruby-base-container | ruby-text-container"),
+    ("display-box", "contents | none"),
+    ("display-legacy", "inline-block | inline-list-item | inline-table | inline-flex | inline-grid"),
+    ("symbol", "<string> | <image> | <custom-ident>"),
+    ("counter-style-name", "<custom-ident>"),
+    ("family-name", "<string> | <custom-ident>+"),
+    ("generic-family", "serif | sans-serif | cursive | fantasy | monospace | system-ui | ui-serif | ui-sans-serif | ui-monospace | ui-rounded | math | emoji | fangsong"),
+    ("font-weight-absolute", "normal | bold | <number [1,1000]>"),
+    ("font-stretch-absolute", "normal | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded | expanded | extra-expanded | ultra-expanded | <percentage [0,∞]>"),
+];
+
+/// Value definition syntax of properties whose entry in `WebData.json` is the descriptor
+/// of the same name, like `width` of `@viewport`
+pub const PROPERTY_SYNTAXES_MISSING_IN_DATA: &[(&str, &str)] = &[
+    ("width", "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content | fit-content( <length-percentage [0,∞]> ) | stretch | -webkit-fill-available | -moz-available"),
+    ("height", "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content | fit-content( <length-percentage [0,∞]> ) | stretch | -webkit-fill-available | -moz-available"),
+    ("min-width", "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content | fit-content( <length-percentage [0,∞]> ) | stretch | -webkit-fill-available | -moz-available"),
+    ("min-height", "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content | fit-content( <length-percentage [0,∞]> ) | stretch | -webkit-fill-available | -moz-available"),
+    ("max-width", "none | <length-percentage [0,∞]> | min-content | max-content | fit-content | fit-content( <length-percentage [0,∞]> ) | stretch | -webkit-fill-available | -moz-available"),
+    ("max-height", "none | <length-percentage [0,∞]> | min-content | max-content | fit-content | fit-content( <length-percentage [0,∞]> ) | stretch | -webkit-fill-available | -moz-available"),
+    ("font-family", "[ <family-name> | <generic-family> ]#"),
+    ("font-weight", "<font-weight-absolute> | bolder | lighter"),
+    ("font-style", "normal | italic | oblique <angle>?"),
+    ("font-stretch", "<font-stretch-absolute>"),
+    ("zoom", "normal | reset | <number [0,∞]> | <percentage [0,∞]>"),
+];
@@ -1,4 +1,5 @@
 
 pub mod facts;
 pub mod data_manager;
-pub mod entry;
\ No newline at end of file
+pub mod entry;
+pub mod value_syntax;
@@ -0,0 +1,435 @@
+//! Parser for the CSS Value Definition Syntax, the grammar of the `syntax` strings in
+//! the css data, see https://drafts.csswg.org/css-values/#value-defs
+
+/// A component of a value definition, e.g. `[ <length> | auto ]{1,4}`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    /// `auto`
+    Keyword(String),
+    /// `,`, `/` or a quoted character like `'['`
+    Literal(String),
+    /// `<length>`, or `<length [0,∞]>` with a range
+    Type {
+        name: String,
+        range: Option<(f64, f64)>,
+    },
+    /// `<'margin-top'>`, any value of the property
+    Property(String),
+    /// `fit-content( <length-percentage> )`
+    Function {
+        name: String,
+        arguments: Option<Box<Term>>,
+    },
+    /// `a b`, all in order
+    Sequence(Vec<Term>),
+    /// `a && b`, all in any order
+    AllOf(Vec<Term>),
+    /// `a || b`, one or more in any order
+    AnyOf(Vec<Term>),
+    /// `a | b`, exactly one
+    OneOf(Vec<Term>),
+    /// `a?`, `a*`, `a+`, `a{1,4}` and with `comma_separated`, `a#` or `a#{1,4}`
+    Repeat {
+        term: Box<Term>,
+        min: usize,
+        max: Option<usize>,
+        comma_separated: bool,
+    },
+}
+
+impl Term {
+    /// This term and all terms nested in it, in the order they appear in the syntax
+    pub fn descendants(&self) -> Vec<&Term> {
+        let mut terms = vec![self];
+        match self {
+            Term::Function {
+                arguments: Some(arguments),
+                ..
+            } => terms.extend(arguments.descendants()),
+            Term::Sequence(children)
+            | Term::AllOf(children)
+            | Term::AnyOf(children)
+            | Term::OneOf(children) => {
+                terms.extend(children.iter().flat_map(Term::descendants));
+            }
+            Term::Repeat { term, .. } => terms.extend(term.descendants()),
+            _ => {}
+        }
+        terms
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Function(String),
+    Angled(String),
+    Literal(String),
+    OpenBracket,
+    CloseBracket,
+    CloseParen,
+    Bar,
+    DoubleBar,
+    DoubleAmpersand,
+    Question,
+    Star,
+    Plus,
+    Hash,
+    Exclamation,
+    Braces(usize, Option<usize>),
+}
+
+/// Parses `syntax`, returns `None` if it is not valid value definition syntax
+pub fn parse(syntax: &str) -> Option<Term> {
+    let tokens = tokenize(syntax)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let term = parser.one_of()?;
+    (parser.pos == parser.tokens.len()).then_some(term)
+}
+
+fn tokenize(syntax: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = syntax.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '[' => Token::OpenBracket,
+            ']' => Token::CloseBracket,
+            ')' => Token::CloseParen,
+            '|' if chars.next_if(|(_, c)| *c == '|').is_some() => Token::DoubleBar,
+            '|' => Token::Bar,
+            '&' if chars.next_if(|(_, c)| *c == '&').is_some() => Token::DoubleAmpersand,
+            '?' => Token::Question,
+            '*' => Token::Star,
+            '+' => Token::Plus,
+            '#' => Token::Hash,
+            '!' => Token::Exclamation,
+            '<' => {
+                let end = start + syntax[start..].find('>')?;
+                while chars.next_if(|(i, _)| *i <= end).is_some() {}
+                Token::Angled(syntax[start + 1..end].trim().to_owned())
+            }
+            '{' => {
+                let end = start + syntax[start..].find('}')?;
+                while chars.next_if(|(i, _)| *i <= end).is_some() {}
+                let inner = &syntax[start + 1..end];
+                let (min, max) = match inner.split_once(',') {
+                    Some((min, "")) => (min.trim().parse().ok()?, None),
+                    Some((min, max)) => (min.trim().parse().ok()?, Some(max.trim().parse().ok()?)),
+                    None => {
+                        let n = inner.trim().parse().ok()?;
+                        (n, Some(n))
+                    }
+                };
+                Token::Braces(min, max)
+            }
+            '\'' => {
+                let end = start + 1 + syntax[start + 1..].find('\'')?;
+                while chars.next_if(|(i, _)| *i <= end).is_some() {}
+                Token::Literal(syntax[start + 1..end].to_owned())
+            }
+            c if c.is_alphanumeric() || c == '-' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) =
+                    chars.next_if(|(_, c)| c.is_alphanumeric() || *c == '-' || *c == '_')
+                {
+                    end = i + c.len_utf8();
+                }
+                let word = syntax[start..end].to_owned();
+                if chars.next_if(|(_, c)| *c == '(').is_some() {
+                    Token::Function(word)
+                } else {
+                    Token::Word(word)
+                }
+            }
+            c => Token::Literal(c.to_string()),
+        };
+        tokens.push(token);
+    }
+    Some(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// Combinators from loosest to tightest binding: `|`, `||`, `&&`, juxtaposition
+    fn one_of(&mut self) -> Option<Term> {
+        self.combined(&Token::Bar, Self::any_of, Term::OneOf)
+    }
+
+    fn any_of(&mut self) -> Option<Term> {
+        self.combined(&Token::DoubleBar, Self::all_of, Term::AnyOf)
+    }
+
+    fn all_of(&mut self) -> Option<Term> {
+        self.combined(&Token::DoubleAmpersand, Self::sequence, Term::AllOf)
+    }
+
+    fn combined(
+        &mut self,
+        separator: &Token,
+        operand: fn(&mut Self) -> Option<Term>,
+        combine: fn(Vec<Term>) -> Term,
+    ) -> Option<Term> {
+        let mut terms = vec![operand(self)?];
+        while self.eat(separator) {
+            terms.push(operand(self)?);
+        }
+        Some(if terms.len() == 1 {
+            terms.pop().unwrap()
+        } else {
+            combine(terms)
+        })
+    }
+
+    fn sequence(&mut self) -> Option<Term> {
+        let mut terms = Vec::new();
+        while let Some(term) = self.repeated() {
+            terms.push(term?);
+        }
+        match terms.len() {
+            0 => None,
+            1 => terms.pop(),
+            _ => Some(Term::Sequence(terms)),
+        }
+    }
+
+    /// `None` when the next token does not start a term, `Some(None)` when it does but
+    /// the term is invalid
+    fn repeated(&mut self) -> Option<Option<Term>> {
+        let mut term = match self.primary()? {
+            Some(term) => term,
+            None => return Some(None),
+        };
+        loop {
+            let (min, max, comma_separated) = match self.peek() {
+                Some(Token::Question) => (0, Some(1), false),
+                Some(Token::Star) => (0, None, false),
+                Some(Token::Plus) => (1, None, false),
+                Some(Token::Braces(min, max)) => (*min, *max, false),
+                Some(Token::Hash) => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Token::Braces(min, max)) => (*min, *max, true),
+                        _ => {
+                            self.pos -= 1;
+                            (1, None, true)
+                        }
+                    }
+                }
+                Some(Token::Exclamation) => {
+                    // `[ ... ]!` requires the group to produce a value, which a match
+                    // consuming components always does
+                    self.pos += 1;
+                    continue;
+                }
+                _ => break,
+            };
+            self.pos += 1;
+            term = Term::Repeat {
+                term: Box::new(term),
+                min,
+                max,
+                comma_separated,
+            };
+        }
+        Some(Some(term))
+    }
+
+    fn primary(&mut self) -> Option<Option<Term>> {
+        let term = match self.peek()?.clone() {
+            Token::OpenBracket => {
+                self.pos += 1;
+                let term = self.one_of();
+                if !self.eat(&Token::CloseBracket) {
+                    return Some(None);
+                }
+                term
+            }
+            Token::Word(word) => {
+                self.pos += 1;
+                Some(Term::Keyword(word))
+            }
+            Token::Literal(literal) => {
+                self.pos += 1;
+                Some(Term::Literal(literal))
+            }
+            Token::Angled(inner) => {
+                self.pos += 1;
+                Some(angled(&inner))
+            }
+            Token::Function(name) => {
+                self.pos += 1;
+                let arguments = if self.peek() == Some(&Token::CloseParen) {
+                    None
+                } else {
+                    match self.one_of() {
+                        Some(arguments) => Some(Box::new(arguments)),
+                        None => return Some(None),
+                    }
+                };
+                if !self.eat(&Token::CloseParen) {
+                    return Some(None);
+                }
+                Some(Term::Function { name, arguments })
+            }
+            _ => return None,
+        };
+        Some(term)
+    }
+}
+
+/// The contents of `<...>`: a property reference or a type with an optional range
+fn angled(inner: &str) -> Term {
+    if let Some(property) = inner.strip_prefix('\'').and_then(|i| i.strip_suffix('\'')) {
+        return Term::Property(property.to_owned());
+    }
+    let (name, range) = match inner.split_once('[') {
+        Some((name, range)) => (name.trim(), range.trim_end_matches(']').split_once(',')),
+        None => (inner, None),
+    };
+    let bound = |bound: &str| match bound.trim() {
+        "∞" | "+∞" => Some(f64::INFINITY),
+        "-∞" | "−∞" => Some(f64::NEG_INFINITY),
+        bound => bound.parse().ok(),
+    };
+    let range = range.and_then(|(min, max)| Some((bound(min)?, bound(max)?)));
+    Term::Type {
+        name: name.trim_end_matches("()").to_owned(),
+        range,
+    }
+}
+
+#[cfg(test)]
+mod value_syntax_test {
+    use super::{parse, Term};
+
+    fn keyword(k: &str) -> Term {
+        Term::Keyword(k.to_owned())
+    }
+
+    fn ty(name: &str) -> Term {
+        Term::Type {
+            name: name.to_owned(),
+            range: None,
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        assert_eq!(
+            parse("a b | c && d || e"),
+            Some(Term::OneOf(vec![
+                Term::Sequence(vec![keyword("a"), keyword("b")]),
+                Term::AnyOf(vec![
+                    Term::AllOf(vec![keyword("c"), keyword("d")]),
+                    keyword("e"),
+                ]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn multipliers() {
+        assert_eq!(
+            parse("[ <length> | auto ]{1,4}"),
+            Some(Term::Repeat {
+                term: Box::new(Term::OneOf(vec![ty("length"), keyword("auto")])),
+                min: 1,
+                max: Some(4),
+                comma_separated: false,
+            })
+        );
+        assert_eq!(
+            parse("<shadow>#"),
+            Some(Term::Repeat {
+                term: Box::new(ty("shadow")),
+                min: 1,
+                max: None,
+                comma_separated: true,
+            })
+        );
+        assert_eq!(
+            parse("<x>#{2,}"),
+            Some(Term::Repeat {
+                term: Box::new(ty("x")),
+                min: 2,
+                max: None,
+                comma_separated: true,
+            })
+        );
+    }
+
+    #[test]
+    fn types_and_functions() {
+        assert_eq!(
+            parse("<number [0,∞]> [ / <'line-height'> ]?"),
+            Some(Term::Sequence(vec![
+                Term::Type {
+                    name: "number".to_owned(),
+                    range: Some((0.0, f64::INFINITY)),
+                },
+                Term::Repeat {
+                    term: Box::new(Term::Sequence(vec![
+                        Term::Literal("/".to_owned()),
+                        Term::Property("line-height".to_owned()),
+                    ])),
+                    min: 0,
+                    max: Some(1),
+                    comma_separated: false,
+                },
+            ]))
+        );
+        assert_eq!(
+            parse("fit-content( <length-percentage> )"),
+            Some(Term::Function {
+                name: "fit-content".to_owned(),
+                arguments: Some(Box::new(ty("length-percentage"))),
+            })
+        );
+        assert_eq!(parse("[ a | b"), None);
+        assert_eq!(parse("a ]"), None);
+    }
+
+    #[test]
+    fn descendants() {
+        let term = parse(
+            "[ <length [0,∞]> | <'margin-top'> | auto ]{1,4} | fit-content( <length-percentage> )",
+        )
+        .unwrap();
+        let leaves = term
+            .descendants()
+            .into_iter()
+            .filter_map(|t| match t {
+                Term::Keyword(name) | Term::Property(name) => Some(name.as_str()),
+                Term::Type { name, .. } | Term::Function { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            leaves,
+            vec![
+                "length",
+                "margin-top",
+                "auto",
+                "fit-content",
+                "length-percentage"
+            ]
+        );
+    }
+}
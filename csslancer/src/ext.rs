@@ -1,5 +1,7 @@
 use std::path::Path;
+use std::time::SystemTime;
 
+use async_trait::async_trait;
 use ego_tree::{NodeId, Tree};
 use tower_lsp::lsp_types::{DocumentFormattingClientCapabilities, Url};
 use tower_lsp::lsp_types::{
@@ -7,6 +9,7 @@ use tower_lsp::lsp_types::{
 };
 
 use crate::config::PositionEncoding;
+use crate::css_language_types::{FileStat, FileSystemProvider, FileType};
 
 pub struct LocalFs {}
 
@@ -18,6 +21,56 @@ impl LocalFs {
     }
 }
 
+fn file_type(typ: std::fs::FileType) -> FileType {
+    if typ.is_symlink() {
+        FileType::SymbolicLink
+    } else if typ.is_dir() {
+        FileType::Directory
+    } else if typ.is_file() {
+        FileType::File
+    } else {
+        FileType::Unknown
+    }
+}
+
+/// The local file system, for `file://` URIs only
+#[async_trait]
+impl FileSystemProvider for LocalFs {
+    async fn stat(&self, uri: &Url) -> Option<FileStat> {
+        let metadata = tokio::fs::metadata(uri.to_file_path().ok()?).await.ok()?;
+        let millis = |time: std::io::Result<SystemTime>| {
+            time.ok()
+                .and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok())
+                .map_or(0, |duration| duration.as_millis() as u64)
+        };
+        Some(FileStat {
+            typ: file_type(metadata.file_type()),
+            ctime: millis(metadata.created()),
+            mtime: millis(metadata.modified()),
+            size: metadata.len(),
+        })
+    }
+
+    async fn read_directory(&self, uri: &Url) -> Vec<(String, FileType)> {
+        let Ok(path) = uri.to_file_path() else {
+            return Vec::new();
+        };
+        let Ok(mut entries) = tokio::fs::read_dir(path).await else {
+            return Vec::new();
+        };
+        let mut result = Vec::new();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let typ = entry.file_type().await.map_or(FileType::Unknown, file_type);
+            result.push((entry.file_name().to_string_lossy().into_owned(), typ));
+        }
+        result
+    }
+
+    async fn get_content(&self, uri: &Url) -> Option<String> {
+        tokio::fs::read_to_string(uri.to_file_path().ok()?).await.ok()
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum FsPathToUriError {
     #[error("cannot convert to URI since path is not absolute")]
@@ -145,6 +145,15 @@ pub mod csslancer_to_client {
         ClientRange::new(raw_range, lsp_position_encoding)
     }
 
+    /// The LSP range of the text covered by `text_range` in `source`
+    pub fn text_range(
+        text_range: rowan::TextRange,
+        source: &Source,
+        lsp_position_encoding: LspPositionEncoding,
+    ) -> ClientRawRange {
+        range(text_range.into(), source, lsp_position_encoding).raw_client_range
+    }
+
     // fn completion_kind(typst_completion_kind: TypstCompletionKind) -> LspCompletionKind {
     //     match typst_completion_kind {
     //         TypstCompletionKind::Syntax => LspCompletionKind::SNIPPET,
@@ -55,14 +55,14 @@ impl Parser<'_> {
     // }
 
     pub fn err_pe(&mut self, error: ParseError) {
-        self.error(error.issue().desc);
+        self.error(error);
     }
 
     pub fn err_and_bump_pe(
         &mut self,
         error: ParseError,
     ) {
-        self.err_and_bump(error.issue().desc);
+        self.err_and_bump(error);
     }
 
     // pub fn fintio_recover(
@@ -83,7 +83,7 @@ impl Parser<'_> {
         resync_tokens: Option<TokenSet>,
         resync_stop_tokens: Option<TokenSet>,
     ) -> bool {
-        self.err_resync(error.issue().desc, resync_tokens.unwrap_or(TokenSet::EMPTY), resync_stop_tokens.unwrap_or(TokenSet::EMPTY))
+        self.err_resync(error, resync_tokens.unwrap_or(TokenSet::EMPTY), resync_stop_tokens.unwrap_or(TokenSet::EMPTY))
         //self.error(error.issue().desc);
         //self.resync(resync_tokens.unwrap_or(&[]), resync_stop_tokens.unwrap_or(&[]));
     }
@@ -142,7 +142,7 @@ impl Parser<'_> {
                         && Self::needs_semicolon_after(kind)
                         && !self.eat(T![;])
                     {
-                        self.error(ParseError::SemiColonExpected.issue().desc);
+                        self.error(ParseError::SemiColonExpected);
                     }
                 }
                 while self.eat(T![;])
@@ -164,9 +164,9 @@ impl Parser<'_> {
 
             if !in_recovery {
                 if self.current().is_at_keyword() {
-                    self.error(ParseError::UnknownAtRule.issue().desc);
+                    self.error(ParseError::UnknownAtRule);
                 } else {
-                    self.error(ParseError::RuleOrSelectorExpected.issue().desc);
+                    self.error(ParseError::RuleOrSelectorExpected);
                 }
                 in_recovery = true;
             }
@@ -208,6 +208,13 @@ impl Parser<'_> {
             .or_else(|| self.parse_namespace_opt().map(|_| SyntaxKind::NAMESPACE))
             .or_else(|| self.parse_document_opt().map(|_| SyntaxKind::DOCUMENT))
             .or_else(|| self.parse_container().map(|_| SyntaxKind::CONTAINER))
+            .or_else(|| self.parse_scope_opt().map(|_| SyntaxKind::SCOPE))
+            .or_else(|| self.parse_starting_style_opt(is_nested).map(|_| SyntaxKind::STARTING_STYLE))
+            .or_else(|| self.parse_view_transition_opt().map(|_| SyntaxKind::VIEW_TRANSITION))
+            .or_else(|| self.parse_position_try_opt().map(|_| SyntaxKind::POSITION_TRY))
+            .or_else(|| self.parse_counter_style_opt().map(|_| SyntaxKind::COUNTER_STYLE))
+            .or_else(|| self.parse_font_feature_values_opt().map(|_| SyntaxKind::FONT_FEATURE_VALUES))
+            .or_else(|| self.parse_font_palette_values_opt().map(|_| SyntaxKind::FONT_PALETTE_VALUES))
             .or_else(|| self.parse_unknown_at_rule().map(|_| SyntaxKind::UNKNOWN_AT_RULE))
     }
 
@@ -253,6 +260,8 @@ impl Parser<'_> {
             .parse_media_opt(true).map(|_| SyntaxKind::MEDIA)
             .or_else(|| self.parse_supports_opt(true).map(|_| SyntaxKind::SUPPORTS))
             .or_else(|| self.parse_layer_opt(true).map(|_| SyntaxKind::LAYER))
+            .or_else(|| self.parse_scope_opt().map(|_| SyntaxKind::SCOPE))
+            .or_else(|| self.parse_starting_style_opt(true).map(|_| SyntaxKind::STARTING_STYLE))
             .or_else(|| self.parse_unknown_at_rule().map(|_| SyntaxKind::UNKNOWN_AT_RULE))
     }
 
@@ -290,6 +299,14 @@ impl Parser<'_> {
             | SyntaxKind::SCSS_MIXIN_CONTENT_DECLARATION 
             | SyntaxKind::PROPERTY_AT_RULE
             | SyntaxKind::CONTAINER
+            | SyntaxKind::SCOPE
+            | SyntaxKind::STARTING_STYLE
+            | SyntaxKind::VIEW_TRANSITION
+            | SyntaxKind::POSITION_TRY
+            | SyntaxKind::COUNTER_STYLE
+            | SyntaxKind::FONT_FEATURE_VALUES
+            | SyntaxKind::FONT_FEATURE_VALUE_BLOCK
+            | SyntaxKind::FONT_PALETTE_VALUES
             | SyntaxKind::FONT_FACE
             | SyntaxKind::LAYER
             | SyntaxKind::SUPPORTS
@@ -1439,6 +1456,190 @@ impl Parser<'_> {
         self.varnish(m, SyntaxKind::UNDEFINED)
     }
 
+    pub fn parse_scope_opt(&mut self) -> Option<()> {
+        // @scope [(<scope-start>)]? [to (<scope-end>)]? { <block-contents> }
+        if !self.at(T![@scope]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        if self.at(SyntaxKind::L_PAREN) {
+            self.parse_scope_limit(SyntaxKind::SCOPE_START);
+        }
+        if self.at_contextual_token(T![cxid_to]) {
+            self.parse_scope_limit(SyntaxKind::SCOPE_END);
+        }
+        // the body can contain rulesets, but also declarations that apply to the scoping root
+        self.parse_body(|s: &mut Self| s.parse_rule_set_declaration_opt());
+        Some(self.varnish(m, SyntaxKind::SCOPE))
+    }
+
+    pub fn parse_scope_limit(&mut self, kind: SyntaxKind) {
+        // <scope-start> and <scope-end> are selector lists, which can use :scope and
+        // start with a combinator relative to the scoping root
+        let m = self.start();
+        self.eat_contextual_token(T![cxid_to]);
+        if !self.eat(SyntaxKind::L_PAREN) {
+            self.err_resync_pe(
+                ParseError::LeftParenthesisExpected,
+                None,
+                Some(TokenSet::new(&[SyntaxKind::L_CURLY])),
+            );
+            self.varnish(m, kind);
+            return
+        }
+        if self.parse_selector_opt(true).is_none() {
+            self.err_resync_pe(
+                ParseError::SelectorExpected,
+                None,
+                Some(TokenSet::new(&[SyntaxKind::R_PAREN, SyntaxKind::L_CURLY])),
+            );
+        }
+        while self.eat(T![,]) {
+            if self.parse_selector_opt(true).is_none() {
+                self.err_resync_pe(
+                    ParseError::SelectorExpected,
+                    None,
+                    Some(TokenSet::new(&[SyntaxKind::R_PAREN, SyntaxKind::L_CURLY])),
+                );
+            }
+        }
+        if !self.eat(SyntaxKind::R_PAREN) {
+            self.err_resync_pe(
+                ParseError::RightParenthesisExpected,
+                None,
+                Some(TokenSet::new(&[SyntaxKind::L_CURLY])),
+            );
+        }
+        self.varnish(m, kind);
+    }
+
+    pub fn parse_starting_style_opt(&mut self, is_nested: bool) -> Option<()> {
+        // @starting-style { <rule-list> }
+        // nested in a style rule: @starting-style { <declaration-list> }
+        if !self.at(T![@starting_style]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        self.parse_body(|s: &mut Self| s.parse_starting_style_declaration(is_nested));
+        Some(self.varnish(m, SyntaxKind::STARTING_STYLE))
+    }
+
+    pub fn parse_starting_style_declaration(&mut self, is_nested: bool) -> Option<SyntaxKind> {
+        if is_nested {
+            // if nested, the body can contain rulesets, but also declarations
+            return self
+                .try_parse_rule_set_opt(true).map(|_| SyntaxKind::RULE_SET)
+                .or_else(|| self.try_parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION))
+                .or_else(|| self.parse_stylesheet_statement_opt(true));
+        }
+        self.parse_stylesheet_statement_opt(false)
+    }
+
+    pub fn parse_view_transition_opt(&mut self) -> Option<()> {
+        // @view-transition { <declaration-list> }
+        if !self.at(T![@view_transition]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        self.parse_body(|s: &mut Self| s.parse_rule_set_declaration_opt());
+        Some(self.varnish(m, SyntaxKind::VIEW_TRANSITION))
+    }
+
+    pub fn parse_position_try_opt(&mut self) -> Option<()> {
+        // @position-try <dashed-ident> { <declaration-list> }
+        if !self.at(T![@position_try]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        if !self.at_contextual_token(T![cxid_valid_custom_prop]) {
+            self.err_pe(ParseError::IdentifierExpected);
+        }
+        self.parse_ident_opt(None);
+        self.parse_body(|s: &mut Self| s.parse_rule_set_declaration_opt());
+        Some(self.varnish(m, SyntaxKind::POSITION_TRY))
+    }
+
+    pub fn parse_counter_style_opt(&mut self) -> Option<()> {
+        // @counter-style <counter-style-name> { <declaration-list> }
+        if !self.at(T![@counter_style]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        if self.parse_ident_opt(None).is_none() {
+            self.err_pe(ParseError::IdentifierExpected);
+        }
+        self.parse_body(|s: &mut Self| s.parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION));
+        Some(self.varnish(m, SyntaxKind::COUNTER_STYLE))
+    }
+
+    pub fn parse_font_feature_values_opt(&mut self) -> Option<()> {
+        // @font-feature-values <family-name># { <declaration-rule-list> }
+        if !self.at(T![@font_feature_values]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        if self.parse_family_name_opt().is_none() {
+            self.err_pe(ParseError::IdentifierExpected);
+        }
+        while self.eat(T![,]) {
+            if self.parse_family_name_opt().is_none() {
+                self.err_pe(ParseError::IdentifierExpected);
+            }
+        }
+        self.parse_body(|s: &mut Self| s.parse_font_feature_values_declaration());
+        Some(self.varnish(m, SyntaxKind::FONT_FEATURE_VALUES))
+    }
+
+    pub fn parse_family_name_opt(&mut self) -> Option<()> {
+        // <family-name> = <string> | <custom-ident>+
+        if self.parse_string_literal().is_some() {
+            return Some(())
+        }
+        self.parse_ident_opt(None)?;
+        while self.parse_ident_opt(None).is_some() {
+            // consume the other words of an unquoted family name
+        }
+        Some(())
+    }
+
+    pub fn parse_font_feature_values_declaration(&mut self) -> Option<SyntaxKind> {
+        self
+            .parse_font_feature_value_block_opt().map(|_| SyntaxKind::FONT_FEATURE_VALUE_BLOCK)
+            .or_else(|| self.parse_rule_set_declaration_opt())
+    }
+
+    pub fn parse_font_feature_value_block_opt(&mut self) -> Option<()> {
+        // @swash { <declaration-list> }, and likewise for the other feature types
+        if !self.at(T![@font_feature_value_type]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        self.parse_body(|s: &mut Self| s.parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION));
+        Some(self.varnish(m, SyntaxKind::FONT_FEATURE_VALUE_BLOCK))
+    }
+
+    pub fn parse_font_palette_values_opt(&mut self) -> Option<()> {
+        // @font-palette-values <dashed-ident> { <declaration-list> }
+        if !self.at(T![@font_palette_values]) {
+            return None
+        }
+        let m = self.start();
+        self.bump_any();
+        if !self.at_contextual_token(T![cxid_valid_custom_prop]) {
+            self.err_pe(ParseError::IdentifierExpected);
+        }
+        self.parse_ident_opt(None);
+        self.parse_body(|s: &mut Self| s.parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION));
+        Some(self.varnish(m, SyntaxKind::FONT_PALETTE_VALUES))
+    }
+
     // https://www.w3.org/TR/css-syntax-3/#consume-an-at-rule
     pub fn parse_unknown_at_rule(&mut self) -> Option<()> {
         if !self.current().is_at_keyword() {
@@ -2084,7 +2285,7 @@ impl Parser<'_> {
                     break;
                 }
                 if self.parse_function_argument().is_none() {
-                    self.error(ParseError::ExpressionExpected.issue().desc);
+                    self.error(ParseError::ExpressionExpected);
                 }
             }
         }
@@ -283,6 +283,82 @@ mod css_grammar_test {
         );
     }
 
+    #[test]
+    fn at_scope() {
+        let f = |p: &mut Parser| p.parse_scope_opt();
+        let src_f = |p: &mut Parser| Some(p.parse_source_file());
+        assert_node("@scope (.card) { img { border: 1px solid; } }", f);
+        assert_node("@scope (.card, #main) to (.content, > footer) { :scope > p { color: red; } }", f);
+        assert_node("@scope to (.content) { }", f);
+        assert_node("@scope { color: red; .a { color: blue; } }", f);
+        assert_node("@scope (.card) { @media screen { :scope { top: 0 } } }", f);
+        assert_node(".a { @scope (> .b) { top: 0; } }", src_f);
+        assert_error("@scope (.card { }", f, RightParenthesisExpected);
+        assert_error("@scope () { }", f, SelectorExpected);
+        assert_error("@scope (.card) to .content { }", f, LeftParenthesisExpected);
+        assert_error("@scope (.card)", f, LeftCurlyExpected);
+    }
+
+    #[test]
+    fn at_starting_style() {
+        let f = |p: &mut Parser| p.parse_starting_style_opt(false);
+        let src_f = |p: &mut Parser| Some(p.parse_source_file());
+        assert_node("@starting-style { .a { opacity: 0; } }", f);
+        assert_node("@starting-style { @media screen { .a { opacity: 0; } } }", f);
+        assert_node(".a { transition: opacity 1s; @starting-style { opacity: 0; .b { top: 0 } } }", src_f);
+        assert_node("@media screen { .a { @starting-style { opacity: 0 } } }", src_f);
+        assert_error("@starting-style", f, LeftCurlyExpected);
+    }
+
+    #[test]
+    fn at_view_transition() {
+        let f = |p: &mut Parser| p.parse_view_transition_opt();
+        assert_node("@view-transition { navigation: auto; }", f);
+        assert_node("@view-transition { navigation: auto; types: slide, forwards }", f);
+        assert_node("@view-transition { }", f);
+        assert_error("@view-transition navigation: auto;", f, LeftCurlyExpected);
+    }
+
+    #[test]
+    fn at_position_try() {
+        let f = |p: &mut Parser| p.parse_position_try_opt();
+        assert_node("@position-try --custom-bottom { top: anchor(bottom); left: anchor(center); }", f);
+        assert_node("@position-try --a { }", f);
+        assert_error("@position-try { top: 0 }", f, IdentifierExpected);
+        assert_error("@position-try bottom { top: 0 }", f, IdentifierExpected);
+        assert_error("@position-try --a top: 0", f, LeftCurlyExpected);
+    }
+
+    #[test]
+    fn at_counter_style() {
+        let f = |p: &mut Parser| p.parse_counter_style_opt();
+        assert_node("@counter-style thumbs { system: cyclic; symbols: \"👍\"; suffix: \" \"; }", f);
+        assert_node("@counter-style a { }", f);
+        assert_error("@counter-style { system: cyclic }", f, IdentifierExpected);
+        assert_error("@counter-style a system: cyclic", f, LeftCurlyExpected);
+        assert_error("@counter-style a { .b { } }", f, RightCurlyExpected);
+    }
+
+    #[test]
+    fn at_font_feature_values() {
+        let f = |p: &mut Parser| p.parse_font_feature_values_opt();
+        assert_node("@font-feature-values Font One { @styleset { nice-style: 12; } }", f);
+        assert_node("@font-feature-values Font One, \"Other\" { font-display: swap; @swash { fancy: 1 } @character-variant { cv: 1 2 } }", f);
+        assert_node("@font-feature-values a { @unknown { } }", f);
+        assert_error("@font-feature-values { @swash { fancy: 1 } }", f, IdentifierExpected);
+        assert_error("@font-feature-values a, { }", f, IdentifierExpected);
+        assert_error("@font-feature-values a { @swash fancy: 1 }", f, LeftCurlyExpected);
+    }
+
+    #[test]
+    fn at_font_palette_values() {
+        let f = |p: &mut Parser| p.parse_font_palette_values_opt();
+        assert_node("@font-palette-values --identifier { font-family: Bixa; override-colors: 0 red, 1 blue; }", f);
+        assert_node("@font-palette-values --a { base-palette: 1 }", f);
+        assert_error("@font-palette-values { base-palette: 1 }", f, IdentifierExpected);
+        assert_error("@font-palette-values a { base-palette: 1 }", f, IdentifierExpected);
+    }
+
     #[test]
     fn at_import() {
         let f = |p: &mut Parser| p.parse_import_opt();
@@ -6,6 +6,7 @@ use std::mem;
 
 use super::{
     output::Output,
+    parse_error::ParseError,
     syntax_kind_gen::SyntaxKind::{self, *},
 };
 
@@ -77,7 +78,7 @@ pub(crate) enum Event {
     //     ends_in_dot: bool,
     // },
     Error {
-        msg: String,
+        error: ParseError,
     },
 }
 
@@ -131,7 +132,7 @@ pub(super) fn process(mut events: Vec<Event>) -> Output {
             //     let ev = mem::replace(&mut events[i + 1], Event::tombstone());
             //     assert!(matches!(ev, Event::Finish), "{ev:?}");
             // }
-            Event::Error { msg } => res.error(msg),
+            Event::Error { error } => res.error(error),
         }
     }
 
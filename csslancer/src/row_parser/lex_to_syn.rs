@@ -203,6 +203,13 @@ impl<'a> Converter<'a> {
                         "@page" => SyntaxKind::ATKW_PAGE,
                         "@-moz-document" => SyntaxKind::ATKW__MOZ_DOCUMENT,
                         "@container" => SyntaxKind::ATKW_CONTAINER,
+                        "@scope" => SyntaxKind::ATKW_SCOPE,
+                        "@starting-style" => SyntaxKind::ATKW_STARTING_STYLE,
+                        "@view-transition" => SyntaxKind::ATKW_VIEW_TRANSITION,
+                        "@position-try" => SyntaxKind::ATKW_POSITION_TRY,
+                        "@counter-style" => SyntaxKind::ATKW_COUNTER_STYLE,
+                        "@font-feature-values" => SyntaxKind::ATKW_FONT_FEATURE_VALUES,
+                        "@font-palette-values" => SyntaxKind::ATKW_FONT_PALETTE_VALUES,
 
                         // https://developer.mozilla.org/en-US/docs/Web/CSS/@page#margin_at-rules
                         "@top-left-corner" |
@@ -221,6 +228,15 @@ impl<'a> Converter<'a> {
                         "@right-top" |
                         "@right-middle" |
                         "@right-bottom" => SyntaxKind::ATKW_MARGIN_AT_RULE,
+
+                        // https://developer.mozilla.org/en-US/docs/Web/CSS/@font-feature-values#feature_value_blocks
+                        "@stylistic" |
+                        "@historical-forms" |
+                        "@styleset" |
+                        "@character-variant" |
+                        "@swash" |
+                        "@ornaments" |
+                        "@annotation" => SyntaxKind::ATKW_FONT_FEATURE_VALUE_TYPE,
                         
                         _ => SyntaxKind::ATKW_UNKNOWN,
                     }
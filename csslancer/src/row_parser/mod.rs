@@ -235,8 +235,8 @@ pub(crate) fn build_tree(
         shortcut::StrStep::Token { kind, text } => builder.token(kind, text),
         shortcut::StrStep::Enter { kind } => builder.start_node(kind),
         shortcut::StrStep::Exit => builder.finish_node(),
-        shortcut::StrStep::Error { msg, pos } => {
-            builder.error(msg.to_owned(), pos.try_into().unwrap())
+        shortcut::StrStep::Error { error, pos } => {
+            builder.error(error, pos.try_into().unwrap())
         }
     });
 
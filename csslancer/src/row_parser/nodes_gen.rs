@@ -25,6 +25,17 @@ pub struct Container {
 impl Container {
     pub fn todo(&self) -> Option<Todo> { support::child(&self.syntax) }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CounterStyle {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CounterStyle {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+    pub fn identifier_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![identifier])
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CustomPropertySet {
@@ -105,6 +116,33 @@ pub struct FontFace {
 impl FontFace {
     pub fn todo(&self) -> Option<Todo> { support::child(&self.syntax) }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FontFeatureValueBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl FontFeatureValueBlock {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FontFeatureValues {
+    pub(crate) syntax: SyntaxNode,
+}
+impl FontFeatureValues {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FontPaletteValues {
+    pub(crate) syntax: SyntaxNode,
+}
+impl FontPaletteValues {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+    pub fn identifier_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![identifier])
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Function {
@@ -265,6 +303,17 @@ impl PageBoxMarginBox {
     pub fn todo(&self) -> Option<Todo> { support::child(&self.syntax) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PositionTry {
+    pub(crate) syntax: SyntaxNode,
+}
+impl PositionTry {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+    pub fn identifier_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![identifier])
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Prio {
     pub(crate) syntax: SyntaxNode,
@@ -309,6 +358,36 @@ impl RuleSet {
     pub fn selectors(&self) -> AstChildren<Selector> { support::children(&self.syntax) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scope {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Scope {
+    pub fn scope_end(&self) -> Option<ScopeEnd> { support::child(&self.syntax) }
+    pub fn scope_start(&self) -> Option<ScopeStart> { support::child(&self.syntax) }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScopeEnd {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScopeEnd {
+    pub fn selectors(&self) -> AstChildren<Selector> { support::children(&self.syntax) }
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['(']) }
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![')']) }
+    pub fn cxid_to_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![cxid_to]) }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScopeStart {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScopeStart {
+    pub fn selectors(&self) -> AstChildren<Selector> { support::children(&self.syntax) }
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['(']) }
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![')']) }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Selector {
     pub(crate) syntax: SyntaxNode,
@@ -428,6 +507,14 @@ impl SourceFile {
     pub fn todo(&self) -> Option<Todo> { support::child(&self.syntax) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StartingStyle {
+    pub(crate) syntax: SyntaxNode,
+}
+impl StartingStyle {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StringLiteral {
     pub(crate) syntax: SyntaxNode,
@@ -505,6 +592,14 @@ impl ViewPort {
     pub fn todo(&self) -> Option<Todo> { support::child(&self.syntax) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ViewTransition {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ViewTransition {
+    pub fn declarations(&self) -> Option<Declarations> { support::child(&self.syntax) }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AbstractDeclaration {
     Declaration(Declaration),
@@ -514,20 +609,27 @@ pub enum AbstractDeclaration {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BodyDeclaration {
     Container(Container),
+    CounterStyle(CounterStyle),
     CustomPropertySet(CustomPropertySet),
     Document(Document),
     FontFace(FontFace),
+    FontFeatureValues(FontFeatureValues),
+    FontPaletteValues(FontPaletteValues),
     Keyframe(Keyframe),
     KeyframeSelector(KeyframeSelector),
     Layer(Layer),
     Media(Media),
     Page(Page),
     PageBoxMarginBox(PageBoxMarginBox),
+    PositionTry(PositionTry),
     PropertyAtRule(PropertyAtRule),
     RuleSet(RuleSet),
+    Scope(Scope),
+    StartingStyle(StartingStyle),
     Supports(Supports),
     UnknownAtRule(UnknownAtRule),
     ViewPort(ViewPort),
+    ViewTransition(ViewTransition),
 }
 impl AstNode for BinaryExpression {
     fn can_cast(kind: SyntaxKind) -> bool { kind == BINARY_EXPRESSION }
@@ -551,6 +653,17 @@ impl AstNode for Container {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for CounterStyle {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == COUNTER_STYLE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for CustomPropertySet {
     fn can_cast(kind: SyntaxKind) -> bool { kind == CUSTOM_PROPERTY_SET }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -650,6 +763,39 @@ impl AstNode for FontFace {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for FontFeatureValueBlock {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == FONT_FEATURE_VALUE_BLOCK }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for FontFeatureValues {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == FONT_FEATURE_VALUES }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for FontPaletteValues {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == FONT_PALETTE_VALUES }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for Function {
     fn can_cast(kind: SyntaxKind) -> bool { kind == FUNCTION }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -859,6 +1005,17 @@ impl AstNode for PageBoxMarginBox {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for PositionTry {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == POSITION_TRY }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for Prio {
     fn can_cast(kind: SyntaxKind) -> bool { kind == PRIO }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -914,6 +1071,39 @@ impl AstNode for RuleSet {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for Scope {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SCOPE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for ScopeEnd {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SCOPE_END }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for ScopeStart {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SCOPE_START }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for Selector {
     fn can_cast(kind: SyntaxKind) -> bool { kind == SELECTOR }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -1068,6 +1258,17 @@ impl AstNode for SourceFile {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for StartingStyle {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == STARTING_STYLE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for StringLiteral {
     fn can_cast(kind: SyntaxKind) -> bool { kind == STRING_LITERAL }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -1167,6 +1368,17 @@ impl AstNode for ViewPort {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for ViewTransition {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == VIEW_TRANSITION }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl From<Declaration> for AbstractDeclaration {
     fn from(node: Declaration) -> AbstractDeclaration { AbstractDeclaration::Declaration(node) }
 }
@@ -1199,6 +1411,9 @@ impl AstNode for AbstractDeclaration {
 impl From<Container> for BodyDeclaration {
     fn from(node: Container) -> BodyDeclaration { BodyDeclaration::Container(node) }
 }
+impl From<CounterStyle> for BodyDeclaration {
+    fn from(node: CounterStyle) -> BodyDeclaration { BodyDeclaration::CounterStyle(node) }
+}
 impl From<CustomPropertySet> for BodyDeclaration {
     fn from(node: CustomPropertySet) -> BodyDeclaration { BodyDeclaration::CustomPropertySet(node) }
 }
@@ -1208,6 +1423,12 @@ impl From<Document> for BodyDeclaration {
 impl From<FontFace> for BodyDeclaration {
     fn from(node: FontFace) -> BodyDeclaration { BodyDeclaration::FontFace(node) }
 }
+impl From<FontFeatureValues> for BodyDeclaration {
+    fn from(node: FontFeatureValues) -> BodyDeclaration { BodyDeclaration::FontFeatureValues(node) }
+}
+impl From<FontPaletteValues> for BodyDeclaration {
+    fn from(node: FontPaletteValues) -> BodyDeclaration { BodyDeclaration::FontPaletteValues(node) }
+}
 impl From<Keyframe> for BodyDeclaration {
     fn from(node: Keyframe) -> BodyDeclaration { BodyDeclaration::Keyframe(node) }
 }
@@ -1226,12 +1447,21 @@ impl From<Page> for BodyDeclaration {
 impl From<PageBoxMarginBox> for BodyDeclaration {
     fn from(node: PageBoxMarginBox) -> BodyDeclaration { BodyDeclaration::PageBoxMarginBox(node) }
 }
+impl From<PositionTry> for BodyDeclaration {
+    fn from(node: PositionTry) -> BodyDeclaration { BodyDeclaration::PositionTry(node) }
+}
 impl From<PropertyAtRule> for BodyDeclaration {
     fn from(node: PropertyAtRule) -> BodyDeclaration { BodyDeclaration::PropertyAtRule(node) }
 }
 impl From<RuleSet> for BodyDeclaration {
     fn from(node: RuleSet) -> BodyDeclaration { BodyDeclaration::RuleSet(node) }
 }
+impl From<Scope> for BodyDeclaration {
+    fn from(node: Scope) -> BodyDeclaration { BodyDeclaration::Scope(node) }
+}
+impl From<StartingStyle> for BodyDeclaration {
+    fn from(node: StartingStyle) -> BodyDeclaration { BodyDeclaration::StartingStyle(node) }
+}
 impl From<Supports> for BodyDeclaration {
     fn from(node: Supports) -> BodyDeclaration { BodyDeclaration::Supports(node) }
 }
@@ -1241,44 +1471,61 @@ impl From<UnknownAtRule> for BodyDeclaration {
 impl From<ViewPort> for BodyDeclaration {
     fn from(node: ViewPort) -> BodyDeclaration { BodyDeclaration::ViewPort(node) }
 }
+impl From<ViewTransition> for BodyDeclaration {
+    fn from(node: ViewTransition) -> BodyDeclaration { BodyDeclaration::ViewTransition(node) }
+}
 impl AstNode for BodyDeclaration {
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
             CONTAINER
+                | COUNTER_STYLE
                 | CUSTOM_PROPERTY_SET
                 | DOCUMENT
                 | FONT_FACE
+                | FONT_FEATURE_VALUES
+                | FONT_PALETTE_VALUES
                 | KEYFRAME
                 | KEYFRAME_SELECTOR
                 | LAYER
                 | MEDIA
                 | PAGE
                 | PAGE_BOX_MARGIN_BOX
+                | POSITION_TRY
                 | PROPERTY_AT_RULE
                 | RULE_SET
+                | SCOPE
+                | STARTING_STYLE
                 | SUPPORTS
                 | UNKNOWN_AT_RULE
                 | VIEW_PORT
+                | VIEW_TRANSITION
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             CONTAINER => BodyDeclaration::Container(Container { syntax }),
+            COUNTER_STYLE => BodyDeclaration::CounterStyle(CounterStyle { syntax }),
             CUSTOM_PROPERTY_SET => BodyDeclaration::CustomPropertySet(CustomPropertySet { syntax }),
             DOCUMENT => BodyDeclaration::Document(Document { syntax }),
             FONT_FACE => BodyDeclaration::FontFace(FontFace { syntax }),
+            FONT_FEATURE_VALUES => BodyDeclaration::FontFeatureValues(FontFeatureValues { syntax }),
+            FONT_PALETTE_VALUES => BodyDeclaration::FontPaletteValues(FontPaletteValues { syntax }),
             KEYFRAME => BodyDeclaration::Keyframe(Keyframe { syntax }),
             KEYFRAME_SELECTOR => BodyDeclaration::KeyframeSelector(KeyframeSelector { syntax }),
             LAYER => BodyDeclaration::Layer(Layer { syntax }),
             MEDIA => BodyDeclaration::Media(Media { syntax }),
             PAGE => BodyDeclaration::Page(Page { syntax }),
             PAGE_BOX_MARGIN_BOX => BodyDeclaration::PageBoxMarginBox(PageBoxMarginBox { syntax }),
+            POSITION_TRY => BodyDeclaration::PositionTry(PositionTry { syntax }),
             PROPERTY_AT_RULE => BodyDeclaration::PropertyAtRule(PropertyAtRule { syntax }),
             RULE_SET => BodyDeclaration::RuleSet(RuleSet { syntax }),
+            SCOPE => BodyDeclaration::Scope(Scope { syntax }),
+            STARTING_STYLE => BodyDeclaration::StartingStyle(StartingStyle { syntax }),
             SUPPORTS => BodyDeclaration::Supports(Supports { syntax }),
             UNKNOWN_AT_RULE => BodyDeclaration::UnknownAtRule(UnknownAtRule { syntax }),
             VIEW_PORT => BodyDeclaration::ViewPort(ViewPort { syntax }),
+            VIEW_TRANSITION => BodyDeclaration::ViewTransition(ViewTransition { syntax }),
             _ => return None,
         };
         Some(res)
@@ -1286,20 +1533,27 @@ impl AstNode for BodyDeclaration {
     fn syntax(&self) -> &SyntaxNode {
         match self {
             BodyDeclaration::Container(it) => &it.syntax,
+            BodyDeclaration::CounterStyle(it) => &it.syntax,
             BodyDeclaration::CustomPropertySet(it) => &it.syntax,
             BodyDeclaration::Document(it) => &it.syntax,
             BodyDeclaration::FontFace(it) => &it.syntax,
+            BodyDeclaration::FontFeatureValues(it) => &it.syntax,
+            BodyDeclaration::FontPaletteValues(it) => &it.syntax,
             BodyDeclaration::Keyframe(it) => &it.syntax,
             BodyDeclaration::KeyframeSelector(it) => &it.syntax,
             BodyDeclaration::Layer(it) => &it.syntax,
             BodyDeclaration::Media(it) => &it.syntax,
             BodyDeclaration::Page(it) => &it.syntax,
             BodyDeclaration::PageBoxMarginBox(it) => &it.syntax,
+            BodyDeclaration::PositionTry(it) => &it.syntax,
             BodyDeclaration::PropertyAtRule(it) => &it.syntax,
             BodyDeclaration::RuleSet(it) => &it.syntax,
+            BodyDeclaration::Scope(it) => &it.syntax,
+            BodyDeclaration::StartingStyle(it) => &it.syntax,
             BodyDeclaration::Supports(it) => &it.syntax,
             BodyDeclaration::UnknownAtRule(it) => &it.syntax,
             BodyDeclaration::ViewPort(it) => &it.syntax,
+            BodyDeclaration::ViewTransition(it) => &it.syntax,
         }
     }
 }
@@ -1323,6 +1577,11 @@ impl std::fmt::Display for Container {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CounterStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CustomPropertySet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1368,6 +1627,21 @@ impl std::fmt::Display for FontFace {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for FontFeatureValueBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for FontFeatureValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for FontPaletteValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1463,6 +1737,11 @@ impl std::fmt::Display for PageBoxMarginBox {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for PositionTry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Prio {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1488,6 +1767,21 @@ impl std::fmt::Display for RuleSet {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScopeEnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScopeStart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for Selector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1558,6 +1852,11 @@ impl std::fmt::Display for SourceFile {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for StartingStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for StringLiteral {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1603,3 +1902,8 @@ impl std::fmt::Display for ViewPort {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for ViewTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
@@ -11,6 +11,7 @@
 use rowan::{GreenNodeBuilder, GreenNode, Language, TextSize};
 
 use super::Parse;
+use super::parse_error::ParseError;
 use super::syntax_kind_gen::SyntaxKind;
 use super::syntax_error::SyntaxError;
 
@@ -72,8 +73,8 @@ impl SyntaxTreeBuilder {
         self.inner.finish_node();
     }
 
-    pub fn error(&mut self, error: String, text_pos: TextSize) {
-        self.errors.push(SyntaxError::new_at_offset(error, text_pos));
+    pub fn error(&mut self, error: ParseError, text_pos: TextSize) {
+        self.errors.push(SyntaxError::from_parse_error(error, text_pos));
     }
 }
 
@@ -1,5 +1,6 @@
 //! See [`Output`]
 
+use super::parse_error::ParseError;
 use super::syntax_kind_gen::SyntaxKind;
 
 /// Output of the parser -- a DFS traversal of a concrete syntax tree.
@@ -19,15 +20,15 @@ pub struct Output {
     ///    |16 bit kind|8 bit n_input_tokens|4 bit tag|4 bit leftover|
     ///
     event: Vec<u32>,
-    error: Vec<String>,
+    error: Vec<ParseError>,
 }
 
 #[derive(Debug)]
-pub enum Step<'a> {
+pub enum Step {
     Token { kind: SyntaxKind, n_input_tokens: u8 },
     Enter { kind: SyntaxKind },
     Exit,
-    Error { msg: &'a str },
+    Error { error: ParseError },
 }
 
 impl Output {
@@ -45,11 +46,11 @@ impl Output {
     const ENTER_EVENT: u8 = 1;
     const EXIT_EVENT: u8 = 2;
 
-    pub fn iter(&self) -> impl Iterator<Item = Step<'_>> {
+    pub fn iter(&self) -> impl Iterator<Item = Step> + '_ {
         self.event.iter().map(|&event| {
             if event & Self::EVENT_MASK == 0 {
                 return Step::Error {
-                    msg: self.error[(event as usize) >> Self::ERROR_SHIFT].as_str(),
+                    error: self.error[(event as usize) >> Self::ERROR_SHIFT],
                 };
             }
             let tag = ((event & Self::TAG_MASK) >> Self::TAG_SHIFT) as u8;
@@ -98,7 +99,7 @@ impl Output {
         self.event.push(e)
     }
 
-    pub(crate) fn error(&mut self, error: String) {
+    pub(crate) fn error(&mut self, error: ParseError) {
         let idx = self.error.len();
         self.error.push(error);
         let e = (idx as u32) << Self::ERROR_SHIFT;
@@ -13,7 +13,7 @@ impl Rule {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ParseError {
     NumberExpected,
     ConditionExpected,
@@ -13,6 +13,7 @@ use ra_ap_limit::Limit;
 use super::{
     event::Event,
     input::Input,
+    parse_error::ParseError,
     SyntaxKind::{self, EOF, ERROR, TOMBSTONE},
     token_set::TokenSet, T,
 };
@@ -292,13 +293,12 @@ impl<'t> Parser<'t> {
         self.do_bump(kind, 1);
     }
 
-    /// Emit error with the `message`
+    /// Emit `error`
     /// FIXME: this should be much more fancy and support
     /// structured errors with spans and notes, like rustc
     /// does.
-    pub(crate) fn error<T: Into<String>>(&mut self, message: T) {
-        let msg = message.into();
-        self.push_event(Event::Error { msg });
+    pub(crate) fn error(&mut self, error: ParseError) {
+        self.push_event(Event::Error { error });
     }
 
     /// Consume the next token if it is `kind` or emit an error
@@ -312,12 +312,12 @@ impl<'t> Parser<'t> {
     // }
 
     /// Create an error node and consume the next token.
-    pub(crate) fn err_and_bump(&mut self, message: &str) {
+    pub(crate) fn err_and_bump(&mut self, message: ParseError) {
         self.err_recover(message, TokenSet::EMPTY);
     }
 
     /// Create an error node and consume the next token.
-    pub(crate) fn err_recover(&mut self, message: &str, recovery: TokenSet) {
+    pub(crate) fn err_recover(&mut self, message: ParseError, recovery: TokenSet) {
         if matches!(self.current(), T!['{'] | T!['}']) {
             self.error(message);
             return;
@@ -335,7 +335,7 @@ impl<'t> Parser<'t> {
     }
 
     /// Create an error node and consume the next token.
-    pub(crate) fn err_resync(&mut self, message: &str, resync_tokens: TokenSet, resync_stop_tokens: TokenSet) -> bool {
+    pub(crate) fn err_resync(&mut self, message: ParseError, resync_tokens: TokenSet, resync_stop_tokens: TokenSet) -> bool {
 
         let kind = self.current();
         if matches!(kind, T!['{'] | T!['}']) || resync_stop_tokens.contains(kind) {
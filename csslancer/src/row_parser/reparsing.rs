@@ -60,6 +60,18 @@ pub(crate) fn reparser(node: &SyntaxNode) -> Option<fn(&mut Parser<'_>) -> Optio
         },
         SyntaxKind::PAGE => |p: &mut Parser| p.parse_page_declaration(),
         SyntaxKind::PAGE_BOX_MARGIN_BOX => |p: &mut Parser| p.parse_rule_set_declaration_opt(),
+        SyntaxKind::SCOPE => |p: &mut Parser| p.parse_rule_set_declaration_opt(),
+        SyntaxKind::STARTING_STYLE => if is_nested {
+            |p: &mut Parser| p.parse_starting_style_declaration(true)
+        } else {
+            |p: &mut Parser| p.parse_starting_style_declaration(false)
+        },
+        SyntaxKind::VIEW_TRANSITION => |p: &mut Parser| p.parse_rule_set_declaration_opt(),
+        SyntaxKind::POSITION_TRY => |p: &mut Parser| p.parse_rule_set_declaration_opt(),
+        SyntaxKind::COUNTER_STYLE => |p: &mut Parser| p.parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION),
+        SyntaxKind::FONT_FEATURE_VALUES => |p: &mut Parser| p.parse_font_feature_values_declaration(),
+        SyntaxKind::FONT_FEATURE_VALUE_BLOCK => |p: &mut Parser| p.parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION),
+        SyntaxKind::FONT_PALETTE_VALUES => |p: &mut Parser| p.parse_declaration_opt(None).map(|_| SyntaxKind::DECLARATION),
         SyntaxKind::DOCUMENT => if is_nested {
             |p: &mut Parser| p.parse_stylesheet_statement_opt(true)
         } else {
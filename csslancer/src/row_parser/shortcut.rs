@@ -16,6 +16,7 @@ use crate::T;
 use super::{
     lex_to_syn::LexedStr, 
     output::Step,
+    parse_error::ParseError,
     syntax_kind_gen::SyntaxKind::{self, *},
 };
 
@@ -24,7 +25,7 @@ pub enum StrStep<'a> {
     Token { kind: SyntaxKind, text: &'a str },
     Enter { kind: SyntaxKind },
     Exit,
-    Error { msg: &'a str, pos: usize },
+    Error { error: ParseError, pos: usize },
 }
 
 fn cx_hash(s: &str) -> Option<SyntaxKind> {
@@ -64,6 +65,7 @@ fn cx_id(s: &str) -> Option<SyntaxKind> {
     // "attrib_s",
     // "an_plus_b_syntax_an",
     // "of",
+    // "to",
     // "important",
     // "progid",
     // "url",
@@ -79,6 +81,7 @@ fn cx_id(s: &str) -> Option<SyntaxKind> {
         "s" => Some(T![cxid_attrib_s]),
         "n" | "-n" => Some(T![cxid_an_plus_b_syntax_an]),
         "of" => Some(T![cxid_of]),
+        "to" => Some(T![cxid_to]),
         "important" => Some(T![cxid_important]),
         "progid" => Some(T![cxid_progid]),
         "urlprefix" => Some(T![cxid_urlprefix]),
@@ -160,9 +163,9 @@ impl LexedStr<'_> {
                 },
                 Step::Enter { kind } => builder.enter(kind),
                 Step::Exit => builder.exit(),
-                Step::Error { msg } => {
+                Step::Error { error } => {
                     let text_pos = builder.lexed.text_start(builder.pos);
-                    (builder.sink)(StrStep::Error { msg, pos: text_pos });
+                    (builder.sink)(StrStep::Error { error, pos: text_pos });
                 }
             }
         }
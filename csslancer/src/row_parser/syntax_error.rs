@@ -4,10 +4,12 @@ use std::fmt;
 
 use rowan::{TextRange, TextSize};
 
+use super::parse_error::ParseError;
+
 /// Represents the result of unsuccessful tokenization, parsing
-/// or tree validation.
+/// or tree validation. Errors of the parser keep their `ParseError`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SyntaxError(String, TextRange);
+pub struct SyntaxError(String, TextRange, Option<ParseError>);
 
 // FIXME: there was an unused SyntaxErrorKind previously (before this enum was removed)
 // It was introduced in this PR: https://github.com/rust-lang/rust-analyzer/pull/846/files#diff-827da9b03b8f9faa1bade5cdd44d5dafR95
@@ -21,16 +23,23 @@ pub struct SyntaxError(String, TextRange);
 
 impl SyntaxError {
     pub fn new(message: impl Into<String>, range: TextRange) -> Self {
-        Self(message.into(), range)
+        Self(message.into(), range, None)
     }
     pub fn new_at_offset(message: impl Into<String>, offset: TextSize) -> Self {
-        Self(message.into(), TextRange::empty(offset))
+        Self(message.into(), TextRange::empty(offset), None)
+    }
+    pub fn from_parse_error(error: ParseError, offset: TextSize) -> Self {
+        Self(error.issue().desc.to_owned(), TextRange::empty(offset), Some(error))
     }
 
     pub fn range(&self) -> TextRange {
         self.1
     }
 
+    pub fn parse_error(&self) -> Option<ParseError> {
+        self.2
+    }
+
     pub fn with_range(mut self, range: TextRange) -> Self {
         self.1 = range;
         self
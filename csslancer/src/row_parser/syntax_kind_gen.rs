@@ -43,7 +43,15 @@ pub enum SyntaxKind {
     ATKW_PAGE,
     ATKW__MOZ_DOCUMENT,
     ATKW_CONTAINER,
+    ATKW_SCOPE,
+    ATKW_STARTING_STYLE,
+    ATKW_VIEW_TRANSITION,
+    ATKW_POSITION_TRY,
+    ATKW_COUNTER_STYLE,
+    ATKW_FONT_FEATURE_VALUES,
+    ATKW_FONT_PALETTE_VALUES,
     ATKW_MARGIN_AT_RULE,
+    ATKW_FONT_FEATURE_VALUE_TYPE,
     SEMICOLON,
     COMMA,
     EXCLAMATION,
@@ -132,6 +140,7 @@ pub enum SyntaxKind {
     CXID_ATTRIB_S,
     CXID_AN_PLUS_B_SYNTAX_AN,
     CXID_OF,
+    CXID_TO,
     CXID_IMPORTANT,
     CXID_PROGID,
     CXID_URLPREFIX,
@@ -158,6 +167,16 @@ pub enum SyntaxKind {
     KEYFRAME,
     KEYFRAME_SELECTOR,
     CONTAINER,
+    SCOPE,
+    SCOPE_START,
+    SCOPE_END,
+    STARTING_STYLE,
+    VIEW_TRANSITION,
+    POSITION_TRY,
+    COUNTER_STYLE,
+    FONT_FEATURE_VALUES,
+    FONT_FEATURE_VALUE_BLOCK,
+    FONT_PALETTE_VALUES,
     PROPERTY_AT_RULE,
     UNKNOWN_AT_RULE,
     SELECTOR,
@@ -342,7 +361,15 @@ impl SyntaxKind {
                 | ATKW_PAGE
                 | ATKW__MOZ_DOCUMENT
                 | ATKW_CONTAINER
+                | ATKW_SCOPE
+                | ATKW_STARTING_STYLE
+                | ATKW_VIEW_TRANSITION
+                | ATKW_POSITION_TRY
+                | ATKW_COUNTER_STYLE
+                | ATKW_FONT_FEATURE_VALUES
+                | ATKW_FONT_PALETTE_VALUES
                 | ATKW_MARGIN_AT_RULE
+                | ATKW_FONT_FEATURE_VALUE_TYPE
         )
     }
     pub fn is_cx_keyword(self) -> bool {
@@ -358,6 +385,7 @@ impl SyntaxKind {
                 | CXID_ATTRIB_S
                 | CXID_AN_PLUS_B_SYNTAX_AN
                 | CXID_OF
+                | CXID_TO
                 | CXID_IMPORTANT
                 | CXID_PROGID
                 | CXID_URLPREFIX
@@ -376,6 +404,7 @@ impl SyntaxKind {
             "attrib_s" => CXID_ATTRIB_S,
             "an_plus_b_syntax_an" => CXID_AN_PLUS_B_SYNTAX_AN,
             "of" => CXID_OF,
+            "to" => CXID_TO,
             "important" => CXID_IMPORTANT,
             "progid" => CXID_PROGID,
             "urlprefix" => CXID_URLPREFIX,
@@ -420,4 +449,4 @@ impl SyntaxKind {
     }
 }
 #[macro_export]
-macro_rules ! T { [;] => { SyntaxKind :: SEMICOLON } ; [,] => { SyntaxKind :: COMMA } ; [!] => { SyntaxKind :: EXCLAMATION } ; ['('] => { SyntaxKind :: L_PAREN } ; [')'] => { SyntaxKind :: R_PAREN } ; ['{'] => { SyntaxKind :: L_CURLY } ; ['}'] => { SyntaxKind :: R_CURLY } ; ['['] => { SyntaxKind :: L_BRACK } ; [']'] => { SyntaxKind :: R_BRACK } ; [<] => { SyntaxKind :: L_ANGLE } ; [>] => { SyntaxKind :: R_ANGLE } ; [@] => { SyntaxKind :: AT } ; [#] => { SyntaxKind :: POUND } ; [~] => { SyntaxKind :: TILDE } ; [?] => { SyntaxKind :: QUESTION } ; [$] => { SyntaxKind :: DOLLAR } ; [&] => { SyntaxKind :: AMP } ; [|] => { SyntaxKind :: PIPE } ; [+] => { SyntaxKind :: PLUS } ; [*] => { SyntaxKind :: STAR } ; [/] => { SyntaxKind :: SLASH } ; [^] => { SyntaxKind :: CARET } ; [%] => { SyntaxKind :: PERCENT } ; [_] => { SyntaxKind :: UNDERSCORE } ; [.] => { SyntaxKind :: DOT } ; [..] => { SyntaxKind :: DOT2 } ; [...] => { SyntaxKind :: DOT3 } ; [..=] => { SyntaxKind :: DOT2EQ } ; [:] => { SyntaxKind :: COLON } ; [::] => { SyntaxKind :: COLON2 } ; [=] => { SyntaxKind :: EQ } ; [==] => { SyntaxKind :: EQ2 } ; [=>] => { SyntaxKind :: FAT_ARROW } ; [!=] => { SyntaxKind :: NEQ } ; [-] => { SyntaxKind :: MINUS } ; [->] => { SyntaxKind :: THIN_ARROW } ; [<=] => { SyntaxKind :: LTEQ } ; [>=] => { SyntaxKind :: GTEQ } ; [+=] => { SyntaxKind :: PLUSEQ } ; [-=] => { SyntaxKind :: MINUSEQ } ; [|=] => { SyntaxKind :: OPERATOR_DASHMATCH } ; [~=] => { SyntaxKind :: OPERATOR_INCLUDES } ; [^=] => { SyntaxKind :: OPERATOR_PREFIX } ; [DOLLAR =] => { SyntaxKind :: OPERATOR_SUFFIX } ; [*=] => { SyntaxKind :: OPERATOR_SUBSTRING } ; [%=] => { SyntaxKind :: PERCENTEQ } ; [&&] => { SyntaxKind :: AMP2 } ; [||] => { SyntaxKind :: PIPE2 } ; [<<] => { SyntaxKind :: SHL } ; [>>] => { SyntaxKind :: SHR } ; [<<=] => { SyntaxKind :: SHLEQ } ; [>>=] => { SyntaxKind :: SHREQ } ; [error] => { SyntaxKind :: ERROR } ; [identifier] => { SyntaxKind :: IDENTIFIER } ; [string] => { SyntaxKind :: STRING } ; [url] => { SyntaxKind :: URL } ; [bad_string] => { SyntaxKind :: BAD_STRING } ; [bad_url] => { SyntaxKind :: BAD_URL } ; [unrestricted_hash] => { SyntaxKind :: UNRESTRICTED_HASH } ; [id_hash] => { SyntaxKind :: ID_HASH } ; [number] => { SyntaxKind :: NUMBER } ; [charset] => { SyntaxKind :: CHARSET } ; [whitespace] => { SyntaxKind :: WHITESPACE } ; [comment] => { SyntaxKind :: COMMENT } ; [unicode_range] => { SyntaxKind :: UNICODE_RANGE } ; [function] => { SyntaxKind :: FUNCTION } ; [cdo] => { SyntaxKind :: CDO } ; [cdc] => { SyntaxKind :: CDC } ; [@ unknown] => { SyntaxKind :: ATKW_UNKNOWN } ; [@ import] => { SyntaxKind :: ATKW_IMPORT } ; [@ namespace] => { SyntaxKind :: ATKW_NAMESPACE } ; [@ font_face] => { SyntaxKind :: ATKW_FONT_FACE } ; [@ viewport] => { SyntaxKind :: ATKW_VIEWPORT } ; [@ _ms_viewport] => { SyntaxKind :: ATKW__MS_VIEWPORT } ; [@ _o_viewport] => { SyntaxKind :: ATKW__O_VIEWPORT } ; [@ keyframes] => { SyntaxKind :: ATKW_KEYFRAMES } ; [@ _webkit_keyframes] => { SyntaxKind :: ATKW__WEBKIT_KEYFRAMES } ; [@ _moz_keyframes] => { SyntaxKind :: ATKW__MOZ_KEYFRAMES } ; [@ _o_keyframes] => { SyntaxKind :: ATKW__O_KEYFRAMES } ; [@ property] => { SyntaxKind :: ATKW_PROPERTY } ; [@ layer] => { SyntaxKind :: ATKW_LAYER } ; [@ supports] => { SyntaxKind :: ATKW_SUPPORTS } ; [@ media] => { SyntaxKind :: ATKW_MEDIA } ; [@ page] => { SyntaxKind :: ATKW_PAGE } ; [@ _moz_document] => { SyntaxKind :: ATKW__MOZ_DOCUMENT } ; [@ container] => { SyntaxKind :: ATKW_CONTAINER } ; [@ margin_at_rule] => { SyntaxKind :: ATKW_MARGIN_AT_RULE } ; [DIM_UNKNOWN] => { SyntaxKind :: DIM_UNKNOWN } ; [DIM_EM] => { SyntaxKind :: DIM_EM } ; [DIM_EX] => { SyntaxKind :: DIM_EX } ; [DIM_PX] => { SyntaxKind :: DIM_PX } ; [DIM_CM] => { SyntaxKind :: DIM_CM } ; [DIM_MM] => { SyntaxKind :: DIM_MM } ; [DIM_IN] => { SyntaxKind :: DIM_IN } ; [DIM_PT] => { SyntaxKind :: DIM_PT } ; [DIM_PC] => { SyntaxKind :: DIM_PC } ; [DIM_DEG] => { SyntaxKind :: DIM_DEG } ; [DIM_RAD] => { SyntaxKind :: DIM_RAD } ; [DIM_GRAD] => { SyntaxKind :: DIM_GRAD } ; [DIM_MS] => { SyntaxKind :: DIM_MS } ; [DIM_S] => { SyntaxKind :: DIM_S } ; [DIM_HZ] => { SyntaxKind :: DIM_HZ } ; [DIM_KHZ] => { SyntaxKind :: DIM_KHZ } ; [DIM_PERCENT] => { SyntaxKind :: DIM_PERCENT } ; [DIM_FR] => { SyntaxKind :: DIM_FR } ; [DIM_DPI] => { SyntaxKind :: DIM_DPI } ; [DIM_DPCM] => { SyntaxKind :: DIM_DPCM } ; [DIM_CQW] => { SyntaxKind :: DIM_CQW } ; [DIM_CQH] => { SyntaxKind :: DIM_CQH } ; [DIM_CQI] => { SyntaxKind :: DIM_CQI } ; [DIM_CQB] => { SyntaxKind :: DIM_CQB } ; [DIM_CQMIN] => { SyntaxKind :: DIM_CQMIN } ; [DIM_CQMAX] => { SyntaxKind :: DIM_CQMAX } ; [cxid_not] => { SyntaxKind :: CXID_NOT } ; [cxid_and] => { SyntaxKind :: CXID_AND } ; [cxid_or] => { SyntaxKind :: CXID_OR } ; [cxid_screen] => { SyntaxKind :: CXID_SCREEN } ; [cxid_only] => { SyntaxKind :: CXID_ONLY } ; [cxid_deep] => { SyntaxKind :: CXID_DEEP } ; [cxid_attrib_i] => { SyntaxKind :: CXID_ATTRIB_I } ; [cxid_attrib_s] => { SyntaxKind :: CXID_ATTRIB_S } ; [cxid_an_plus_b_syntax_an] => { SyntaxKind :: CXID_AN_PLUS_B_SYNTAX_AN } ; [cxid_of] => { SyntaxKind :: CXID_OF } ; [cxid_important] => { SyntaxKind :: CXID_IMPORTANT } ; [cxid_progid] => { SyntaxKind :: CXID_PROGID } ; [cxid_urlprefix] => { SyntaxKind :: CXID_URLPREFIX } ; [cxid_valid_custom_prop] => { SyntaxKind :: CXID_VALID_CUSTOM_PROP } ; [cxfunc_layer] => { SyntaxKind :: CXFUNC_LAYER } ; [cxfunc_supports] => { SyntaxKind :: CXFUNC_SUPPORTS } ; [cxfunc_style] => { SyntaxKind :: CXFUNC_STYLE } ; [cxfunc_url] => { SyntaxKind :: CXFUNC_URL } ; [cxhash_valid_hex] => { SyntaxKind :: CXHASH_VALID_HEX } ; [cxdim_an_plus_b] => { SyntaxKind :: CXDIM_AN_PLUS_B } ; }
+macro_rules ! T { [;] => { SyntaxKind :: SEMICOLON } ; [,] => { SyntaxKind :: COMMA } ; [!] => { SyntaxKind :: EXCLAMATION } ; ['('] => { SyntaxKind :: L_PAREN } ; [')'] => { SyntaxKind :: R_PAREN } ; ['{'] => { SyntaxKind :: L_CURLY } ; ['}'] => { SyntaxKind :: R_CURLY } ; ['['] => { SyntaxKind :: L_BRACK } ; [']'] => { SyntaxKind :: R_BRACK } ; [<] => { SyntaxKind :: L_ANGLE } ; [>] => { SyntaxKind :: R_ANGLE } ; [@] => { SyntaxKind :: AT } ; [#] => { SyntaxKind :: POUND } ; [~] => { SyntaxKind :: TILDE } ; [?] => { SyntaxKind :: QUESTION } ; [$] => { SyntaxKind :: DOLLAR } ; [&] => { SyntaxKind :: AMP } ; [|] => { SyntaxKind :: PIPE } ; [+] => { SyntaxKind :: PLUS } ; [*] => { SyntaxKind :: STAR } ; [/] => { SyntaxKind :: SLASH } ; [^] => { SyntaxKind :: CARET } ; [%] => { SyntaxKind :: PERCENT } ; [_] => { SyntaxKind :: UNDERSCORE } ; [.] => { SyntaxKind :: DOT } ; [..] => { SyntaxKind :: DOT2 } ; [...] => { SyntaxKind :: DOT3 } ; [..=] => { SyntaxKind :: DOT2EQ } ; [:] => { SyntaxKind :: COLON } ; [::] => { SyntaxKind :: COLON2 } ; [=] => { SyntaxKind :: EQ } ; [==] => { SyntaxKind :: EQ2 } ; [=>] => { SyntaxKind :: FAT_ARROW } ; [!=] => { SyntaxKind :: NEQ } ; [-] => { SyntaxKind :: MINUS } ; [->] => { SyntaxKind :: THIN_ARROW } ; [<=] => { SyntaxKind :: LTEQ } ; [>=] => { SyntaxKind :: GTEQ } ; [+=] => { SyntaxKind :: PLUSEQ } ; [-=] => { SyntaxKind :: MINUSEQ } ; [|=] => { SyntaxKind :: OPERATOR_DASHMATCH } ; [~=] => { SyntaxKind :: OPERATOR_INCLUDES } ; [^=] => { SyntaxKind :: OPERATOR_PREFIX } ; [DOLLAR =] => { SyntaxKind :: OPERATOR_SUFFIX } ; [*=] => { SyntaxKind :: OPERATOR_SUBSTRING } ; [%=] => { SyntaxKind :: PERCENTEQ } ; [&&] => { SyntaxKind :: AMP2 } ; [||] => { SyntaxKind :: PIPE2 } ; [<<] => { SyntaxKind :: SHL } ; [>>] => { SyntaxKind :: SHR } ; [<<=] => { SyntaxKind :: SHLEQ } ; [>>=] => { SyntaxKind :: SHREQ } ; [error] => { SyntaxKind :: ERROR } ; [identifier] => { SyntaxKind :: IDENTIFIER } ; [string] => { SyntaxKind :: STRING } ; [url] => { SyntaxKind :: URL } ; [bad_string] => { SyntaxKind :: BAD_STRING } ; [bad_url] => { SyntaxKind :: BAD_URL } ; [unrestricted_hash] => { SyntaxKind :: UNRESTRICTED_HASH } ; [id_hash] => { SyntaxKind :: ID_HASH } ; [number] => { SyntaxKind :: NUMBER } ; [charset] => { SyntaxKind :: CHARSET } ; [whitespace] => { SyntaxKind :: WHITESPACE } ; [comment] => { SyntaxKind :: COMMENT } ; [unicode_range] => { SyntaxKind :: UNICODE_RANGE } ; [function] => { SyntaxKind :: FUNCTION } ; [cdo] => { SyntaxKind :: CDO } ; [cdc] => { SyntaxKind :: CDC } ; [@ unknown] => { SyntaxKind :: ATKW_UNKNOWN } ; [@ import] => { SyntaxKind :: ATKW_IMPORT } ; [@ namespace] => { SyntaxKind :: ATKW_NAMESPACE } ; [@ font_face] => { SyntaxKind :: ATKW_FONT_FACE } ; [@ viewport] => { SyntaxKind :: ATKW_VIEWPORT } ; [@ _ms_viewport] => { SyntaxKind :: ATKW__MS_VIEWPORT } ; [@ _o_viewport] => { SyntaxKind :: ATKW__O_VIEWPORT } ; [@ keyframes] => { SyntaxKind :: ATKW_KEYFRAMES } ; [@ _webkit_keyframes] => { SyntaxKind :: ATKW__WEBKIT_KEYFRAMES } ; [@ _moz_keyframes] => { SyntaxKind :: ATKW__MOZ_KEYFRAMES } ; [@ _o_keyframes] => { SyntaxKind :: ATKW__O_KEYFRAMES } ; [@ property] => { SyntaxKind :: ATKW_PROPERTY } ; [@ layer] => { SyntaxKind :: ATKW_LAYER } ; [@ supports] => { SyntaxKind :: ATKW_SUPPORTS } ; [@ media] => { SyntaxKind :: ATKW_MEDIA } ; [@ page] => { SyntaxKind :: ATKW_PAGE } ; [@ _moz_document] => { SyntaxKind :: ATKW__MOZ_DOCUMENT } ; [@ container] => { SyntaxKind :: ATKW_CONTAINER } ; [@ scope] => { SyntaxKind :: ATKW_SCOPE } ; [@ starting_style] => { SyntaxKind :: ATKW_STARTING_STYLE } ; [@ view_transition] => { SyntaxKind :: ATKW_VIEW_TRANSITION } ; [@ position_try] => { SyntaxKind :: ATKW_POSITION_TRY } ; [@ counter_style] => { SyntaxKind :: ATKW_COUNTER_STYLE } ; [@ font_feature_values] => { SyntaxKind :: ATKW_FONT_FEATURE_VALUES } ; [@ font_palette_values] => { SyntaxKind :: ATKW_FONT_PALETTE_VALUES } ; [@ margin_at_rule] => { SyntaxKind :: ATKW_MARGIN_AT_RULE } ; [@ font_feature_value_type] => { SyntaxKind :: ATKW_FONT_FEATURE_VALUE_TYPE } ; [DIM_UNKNOWN] => { SyntaxKind :: DIM_UNKNOWN } ; [DIM_EM] => { SyntaxKind :: DIM_EM } ; [DIM_EX] => { SyntaxKind :: DIM_EX } ; [DIM_PX] => { SyntaxKind :: DIM_PX } ; [DIM_CM] => { SyntaxKind :: DIM_CM } ; [DIM_MM] => { SyntaxKind :: DIM_MM } ; [DIM_IN] => { SyntaxKind :: DIM_IN } ; [DIM_PT] => { SyntaxKind :: DIM_PT } ; [DIM_PC] => { SyntaxKind :: DIM_PC } ; [DIM_DEG] => { SyntaxKind :: DIM_DEG } ; [DIM_RAD] => { SyntaxKind :: DIM_RAD } ; [DIM_GRAD] => { SyntaxKind :: DIM_GRAD } ; [DIM_MS] => { SyntaxKind :: DIM_MS } ; [DIM_S] => { SyntaxKind :: DIM_S } ; [DIM_HZ] => { SyntaxKind :: DIM_HZ } ; [DIM_KHZ] => { SyntaxKind :: DIM_KHZ } ; [DIM_PERCENT] => { SyntaxKind :: DIM_PERCENT } ; [DIM_FR] => { SyntaxKind :: DIM_FR } ; [DIM_DPI] => { SyntaxKind :: DIM_DPI } ; [DIM_DPCM] => { SyntaxKind :: DIM_DPCM } ; [DIM_CQW] => { SyntaxKind :: DIM_CQW } ; [DIM_CQH] => { SyntaxKind :: DIM_CQH } ; [DIM_CQI] => { SyntaxKind :: DIM_CQI } ; [DIM_CQB] => { SyntaxKind :: DIM_CQB } ; [DIM_CQMIN] => { SyntaxKind :: DIM_CQMIN } ; [DIM_CQMAX] => { SyntaxKind :: DIM_CQMAX } ; [cxid_not] => { SyntaxKind :: CXID_NOT } ; [cxid_and] => { SyntaxKind :: CXID_AND } ; [cxid_or] => { SyntaxKind :: CXID_OR } ; [cxid_screen] => { SyntaxKind :: CXID_SCREEN } ; [cxid_only] => { SyntaxKind :: CXID_ONLY } ; [cxid_deep] => { SyntaxKind :: CXID_DEEP } ; [cxid_attrib_i] => { SyntaxKind :: CXID_ATTRIB_I } ; [cxid_attrib_s] => { SyntaxKind :: CXID_ATTRIB_S } ; [cxid_an_plus_b_syntax_an] => { SyntaxKind :: CXID_AN_PLUS_B_SYNTAX_AN } ; [cxid_of] => { SyntaxKind :: CXID_OF } ; [cxid_to] => { SyntaxKind :: CXID_TO } ; [cxid_important] => { SyntaxKind :: CXID_IMPORTANT } ; [cxid_progid] => { SyntaxKind :: CXID_PROGID } ; [cxid_urlprefix] => { SyntaxKind :: CXID_URLPREFIX } ; [cxid_valid_custom_prop] => { SyntaxKind :: CXID_VALID_CUSTOM_PROP } ; [cxfunc_layer] => { SyntaxKind :: CXFUNC_LAYER } ; [cxfunc_supports] => { SyntaxKind :: CXFUNC_SUPPORTS } ; [cxfunc_style] => { SyntaxKind :: CXFUNC_STYLE } ; [cxfunc_url] => { SyntaxKind :: CXFUNC_URL } ; [cxhash_valid_hex] => { SyntaxKind :: CXHASH_VALID_HEX } ; [cxdim_an_plus_b] => { SyntaxKind :: CXDIM_AN_PLUS_B } ; }
@@ -82,6 +82,7 @@ pub(crate) const SYNTAX_KINDS_SRC: SyntaxKindsSrc<'_> = SyntaxKindsSrc {
     ],
     // -ms-keyframes: https://github.com/CSSLint/csslint/issues/295
     // margin-at-rule: https://developer.mozilla.org/en-US/docs/Web/CSS/@page#margin_at-rules
+    // font-feature-value-type: https://developer.mozilla.org/en-US/docs/Web/CSS/@font-feature-values#feature_value_blocks
     at_keywords: "
         unknown
         import 
@@ -96,7 +97,15 @@ pub(crate) const SYNTAX_KINDS_SRC: SyntaxKindsSrc<'_> = SyntaxKindsSrc {
         page
         -moz-document
         container
-        margin-at-rule", 
+        scope
+        starting-style
+        view-transition
+        position-try
+        counter-style
+        font-feature-values
+        font-palette-values
+        margin-at-rule
+        font-feature-value-type", 
     dimensions: "unknown em ex px cm mm in pt pc deg rad grad ms s hz khz % fr dpi dpcm cqw cqh cqi cqb cqmin cqmax",
     tokens: &["error", "identifier", "string", "url", "bad_string", "bad_url", /*"ATKEYWORD",*/ "unrestricted_hash", "id_hash", "number", /*"DIMENSION",*/ "charset", "whitespace", "comment", "unicode_range", "function", "cdo", "cdc"],
     contextual_ids: &[
@@ -110,6 +119,7 @@ pub(crate) const SYNTAX_KINDS_SRC: SyntaxKindsSrc<'_> = SyntaxKindsSrc {
         "attrib_s",
         "an_plus_b_syntax_an",
         "of",
+        "to",
         "important",
         "progid",
         "urlprefix",
@@ -146,6 +156,16 @@ pub(crate) const SYNTAX_KINDS_SRC: SyntaxKindsSrc<'_> = SyntaxKindsSrc {
         KEYFRAME
         KEYFRAME_SELECTOR
         CONTAINER
+        SCOPE
+        SCOPE_START
+        SCOPE_END
+        STARTING_STYLE
+        VIEW_TRANSITION
+        POSITION_TRY
+        COUNTER_STYLE
+        FONT_FEATURE_VALUES
+        FONT_FEATURE_VALUE_BLOCK
+        FONT_PALETTE_VALUES
         PROPERTY_AT_RULE
         UNKNOWN_AT_RULE
         // --
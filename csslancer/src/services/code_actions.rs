@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, NumberOrString, TextEdit,
+    WorkspaceEdit,
+};
+use rowan::{TextRange, TextSize};
+
+use super::lint::{closest, descriptor_at_rule, descriptor_names, unprefixed};
+use super::lint_rules;
+use super::CssLancerServer;
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::interop::csslancer_to_client::text_range;
+use crate::row_parser::nodes_types::{SyntaxNode, SyntaxToken};
+use crate::row_parser::parse_error::ParseError;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+/// A fix: its title and the edits it makes, as byte ranges and their new text
+type Fix = (String, Vec<(TextRange, String)>);
+
+/// The innermost node of `kind` whose range starts at `offset`
+fn node_at(root: &SyntaxNode, offset: TextSize, kind: SyntaxKind) -> Option<SyntaxNode> {
+    root.token_at_offset(offset)
+        .right_biased()?
+        .parent_ancestors()
+        .find(|node| node.kind() == kind && node.text_range().start() == offset)
+}
+
+fn whitespace(token: Option<SyntaxToken>) -> Option<SyntaxToken> {
+    token.filter(|t| t.kind() == SyntaxKind::WHITESPACE)
+}
+
+/// Replaces an unknown property with the known property closest to it, or an unknown
+/// descriptor of an at-rule like `@counter-style` with its closest descriptor
+fn rename_property(server: &CssLancerServer, root: &SyntaxNode, offset: TextSize) -> Option<Fix> {
+    let property = node_at(root, offset, SyntaxKind::PROPERTY)?;
+    let name = property.text().to_string().to_lowercase();
+    let data = &server.css_data_manager;
+    let at_rule = property
+        .ancestors()
+        .find(|a| a.kind() == SyntaxKind::DECLARATIONS)
+        .and_then(|block| descriptor_at_rule(&block));
+    let suggestion = match &at_rule {
+        Some(at_rule) => closest(&name, descriptor_names(data, at_rule)),
+        None => closest(&name, data.properties().map(|p| p.name.clone())),
+    }?;
+    Some((
+        format!("Rename to '{suggestion}'"),
+        vec![(property.text_range(), suggestion)],
+    ))
+}
+
+/// Adds the standard property after a vendor specific one, with the same value
+fn add_standard_property(root: &SyntaxNode, offset: TextSize) -> Option<Fix> {
+    let property = node_at(root, offset, SyntaxKind::PROPERTY)?;
+    let name = property.text().to_string().to_lowercase();
+    let standard = unprefixed(&name)?;
+    let declaration = property.parent()?;
+    let value = declaration
+        .text()
+        .slice(property.text_range().end() - declaration.text_range().start()..);
+    let value = value.to_string();
+    let statement = declaration
+        .ancestors()
+        .find(|a| a.kind() == SyntaxKind::DECLARATION)?;
+
+    // on a line of its own when the vendor specific one is
+    let separator =
+        whitespace(statement.first_token()?.prev_token()).map_or(" ".to_owned(), |ws| {
+            match ws.text().rfind(['\n', '\r', '\x0c']) {
+                Some(newline) => ws.text()[newline..].to_owned(),
+                None => " ".to_owned(),
+            }
+        });
+    let after = statement.last_token()?.next_token();
+    let semicolon = match whitespace(after.clone()) {
+        Some(ws) => ws.next_token(),
+        None => after,
+    }
+    .filter(|t| t.kind() == SyntaxKind::SEMICOLON);
+    let edit = match semicolon {
+        Some(semicolon) => (
+            TextRange::empty(semicolon.text_range().end()),
+            format!("{separator}{standard}{};", value.trim_end()),
+        ),
+        None => (
+            TextRange::empty(statement.text_range().end()),
+            format!(";{separator}{standard}{}", value.trim_end()),
+        ),
+    };
+    Some((format!("Add standard property '{standard}'"), vec![edit]))
+}
+
+/// Removes an empty rule, with its line when it is alone on it
+fn remove_rule(root: &SyntaxNode, offset: TextSize) -> Option<Fix> {
+    let rule = node_at(root, offset, SyntaxKind::RULE_SET)?;
+    let mut range = rule.text_range();
+    if let Some(next) = whitespace(rule.last_token()?.next_token()) {
+        let text = next.text();
+        let end = text.find('\n').map_or(text.len(), |newline| newline + 1);
+        range = range.cover_offset(next.text_range().start() + TextSize::of(&text[..end]));
+        if let (Some(prev), true) = (
+            whitespace(rule.first_token()?.prev_token()),
+            text.contains('\n'),
+        ) {
+            if let Some(newline) = prev.text().rfind('\n') {
+                let indent = TextSize::of(&prev.text()[newline + 1..]);
+                range = range.cover_offset(prev.text_range().end() - indent);
+            }
+        }
+    }
+    Some(("Remove empty rule".to_owned(), vec![(range, String::new())]))
+}
+
+/// Inserts a semicolon after the last token before `offset`, where the parser only
+/// noticed it missing at the next token
+fn insert_semicolon(root: &SyntaxNode, offset: TextSize) -> Option<Fix> {
+    let mut token = root.token_at_offset(offset).left_biased();
+    while let Some(t) = token
+        .clone()
+        .filter(|t| t.kind().is_trivia() || t.text_range().end() > offset)
+    {
+        token = t.prev_token();
+    }
+    let end = token.map_or(offset, |t| t.text_range().end());
+    Some((
+        "Insert missing semicolon".to_owned(),
+        vec![(TextRange::empty(end), ";".to_owned())],
+    ))
+}
+
+impl CssLancerServer {
+    /// Quick fixes for those of `diagnostics` that have one, by the lint rule or parse error
+    /// id in their code: renaming an unknown property to the closest known one, adding
+    /// the standard property after a vendor specific one, removing an empty rule and
+    /// inserting a missing semicolon
+    pub fn get_code_actions(
+        &self,
+        src: &Source,
+        diagnostics: &[Diagnostic],
+    ) -> Vec<CodeActionOrCommand> {
+        let encoding = self.const_config().position_encoding;
+        let root = src.parse.tree().syntax;
+        let semicolon_expected = ParseError::SemiColonExpected.issue().name;
+        diagnostics
+            .iter()
+            .filter_map(|diagnostic| {
+                let Some(NumberOrString::String(code)) = &diagnostic.code else {
+                    return None;
+                };
+                let offset = position_to_offset(diagnostic.range.start, encoding, src);
+                let offset = TextSize::from(offset as u32);
+                let (title, edits) = match code.as_str() {
+                    code if code == lint_rules::UNKNOWN_PROPERTIES.id => {
+                        rename_property(self, &root, offset)
+                    }
+                    code if code == lint_rules::VENDOR_PREFIX.id => {
+                        add_standard_property(&root, offset)
+                    }
+                    code if code == lint_rules::EMPTY_RULES.id => remove_rule(&root, offset),
+                    code if code == semicolon_expected => insert_semicolon(&root, offset),
+                    _ => None,
+                }?;
+                let edits = edits
+                    .into_iter()
+                    .map(|(range, new_text)| {
+                        let range = text_range(range, src, encoding);
+                        TextEdit::new(range, new_text)
+                    })
+                    .collect();
+                Some(CodeActionOrCommand::CodeAction(CodeAction {
+                    title,
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit::new(HashMap::from([(
+                        src.url.clone(),
+                        edits,
+                    )]))),
+                    is_preferred: Some(true),
+                    ..Default::default()
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod code_actions_test {
+    use lsp_types::{CodeActionOrCommand, TextEdit, Url};
+
+    use crate::config::PositionEncoding;
+    use crate::css_language_types::LintSettings;
+    use crate::interop::client_to_csslancer::position_to_offset;
+    use crate::services::CssLancerServer;
+    use crate::workspace::source::Source;
+
+    /// Title of each fix for the diagnostics of `text`, and `text` after applying it
+    fn fixes(text: &str) -> Vec<(String, String)> {
+        let ls = CssLancerServer::new_test();
+        let url = Url::parse("test://foo/a.css").unwrap();
+        let src = Source::new(url.clone(), text, 0);
+        let diagnostics = ls.get_diagnostics(&src, &LintSettings::new());
+        ls.get_code_actions(&src, &diagnostics)
+            .into_iter()
+            .map(|action| {
+                let CodeActionOrCommand::CodeAction(action) = action else {
+                    panic!("expected a code action");
+                };
+                let edits: &[TextEdit] = &action.edit.unwrap().changes.unwrap()[&url];
+                let mut fixed = text.to_owned();
+                for edit in edits.iter().rev() {
+                    let start = position_to_offset(edit.range.start, PositionEncoding::Utf16, &src);
+                    let end = position_to_offset(edit.range.end, PositionEncoding::Utf16, &src);
+                    fixed.replace_range(start..end, &edit.new_text);
+                }
+                (action.title, fixed)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn misspelled_property() {
+        assert_eq!(
+            fixes(".a { colr: red; wxyzw: 1 }"),
+            vec![(
+                "Rename to 'color'".to_owned(),
+                ".a { color: red; wxyzw: 1 }".to_owned()
+            )]
+        );
+        assert_eq!(
+            fixes("@counter-style a { sytem: cyclic }"),
+            vec![(
+                "Rename to 'system'".to_owned(),
+                "@counter-style a { system: cyclic }".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn standard_property() {
+        assert_eq!(
+            fixes(".a {\n  -webkit-appearance: none !important;\n  color: red;\n}"),
+            vec![(
+                "Add standard property 'appearance'".to_owned(),
+                ".a {\n  -webkit-appearance: none !important;\n  appearance: none !important;\n  color: red;\n}".to_owned()
+            )]
+        );
+        assert_eq!(
+            fixes(".a { -webkit-appearance: none }")[0].1,
+            ".a { -webkit-appearance: none; appearance: none }"
+        );
+    }
+
+    #[test]
+    fn empty_rule() {
+        assert_eq!(
+            fixes(".a { color: red; }\n  .b {}\n.c { color: red; }"),
+            vec![(
+                "Remove empty rule".to_owned(),
+                ".a { color: red; }\n.c { color: red; }".to_owned()
+            )]
+        );
+        assert_eq!(fixes(".a {} .b { top: 0 }")[0].1, ".b { top: 0 }");
+    }
+
+    #[test]
+    fn missing_semicolon() {
+        assert_eq!(
+            fixes("@layer base\n.a { color: red; }"),
+            vec![(
+                "Insert missing semicolon".to_owned(),
+                "@layer base;\n.a { color: red; }".to_owned()
+            )]
+        );
+        assert_eq!(
+            fixes("@import 'a.css'\n.a { color: red; }"),
+            vec![(
+                "Insert missing semicolon".to_owned(),
+                "@import 'a.css';\n.a { color: red; }".to_owned()
+            )]
+        );
+    }
+}
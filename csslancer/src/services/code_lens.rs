@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use lsp_types::{CodeLens, Command, Location};
+use rowan::TextRange;
+
+use super::selector_printing::Specificity;
+use super::CssLancerServer;
+use crate::interop::csslancer_to_client::text_range;
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::{source::Source, Workspace};
+
+/// Client command that opens a references peek at a position, with a list of locations.
+/// The VS Code client converts its arguments and passes them on to
+/// `editor.action.showReferences`, which only takes VS Code's own objects
+const SHOW_REFERENCES_COMMAND: &str = "csslancer.showReferences";
+
+struct SelectorInfo {
+    /// The selector with its compounds and combinators separated by single spaces
+    text: String,
+    /// Simple selectors of the compound the selector matches elements by, without `*`
+    subject: Vec<String>,
+    specificity: Specificity,
+}
+
+struct RuleInfo<'a> {
+    source: &'a Source,
+    /// Range of the selectors of the rule
+    range: TextRange,
+    selectors: Vec<SelectorInfo>,
+    /// Lowercase property name and whether it is `!important`, of each declaration
+    declarations: Vec<(String, bool)>,
+}
+
+impl RuleInfo<'_> {
+    /// Whether a declaration of `self` may win over one of `other` for some elements
+    /// they both match: `self` targets a subset of the elements of `other`, and its
+    /// declaration is `!important` when the other is not, or has the same importance
+    /// and `self` has a higher specificity, or the same one further down in the same
+    /// stylesheet
+    fn may_override(&self, important: bool, other: &RuleInfo, other_important: bool) -> bool {
+        let later = self.source.url == other.source.url && self.range.start() > other.range.start();
+        self.selectors.iter().any(|selector| {
+            other.selectors.iter().any(|other_selector| {
+                let pseudo_elements = |s: &SelectorInfo| {
+                    s.subject
+                        .iter()
+                        .filter(|part| part.starts_with("::"))
+                        .cloned()
+                        .collect::<Vec<_>>()
+                };
+                let targets_subset = pseudo_elements(selector) == pseudo_elements(other_selector)
+                    && other_selector
+                        .subject
+                        .iter()
+                        .all(|part| selector.subject.contains(part));
+                let wins = match (important, other_important) {
+                    (true, false) => true,
+                    (false, true) => false,
+                    _ => {
+                        selector.specificity > other_selector.specificity
+                            || selector.specificity == other_selector.specificity && later
+                    }
+                };
+                targets_subset && wins
+            })
+        })
+    }
+}
+
+fn selector_info(server: &CssLancerServer, selector: &SyntaxNode) -> SelectorInfo {
+    let text = selector
+        .children()
+        .map(|c| c.text().to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut subject = selector
+        .children()
+        .filter(|c| c.kind() == SyntaxKind::SIMPLE_SELECTOR)
+        .last()
+        .map(|compound| {
+            compound
+                .children()
+                .map(|c| c.text().to_string())
+                .filter(|part| part != "*")
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    subject.sort();
+    subject.dedup();
+    SelectorInfo {
+        text,
+        subject,
+        specificity: server.css_data_manager.selector_specificity(selector),
+    }
+}
+
+fn rule_info<'a>(
+    server: &CssLancerServer,
+    source: &'a Source,
+    rule: &SyntaxNode,
+) -> Option<RuleInfo<'a>> {
+    let selectors = rule
+        .children()
+        .filter(|c| c.kind() == SyntaxKind::SELECTOR)
+        .collect::<Vec<_>>();
+    let range = selectors
+        .first()?
+        .text_range()
+        .cover(selectors.last()?.text_range());
+    let declarations = rule
+        .children()
+        .filter(|c| c.kind() == SyntaxKind::DECLARATIONS)
+        .flat_map(|block| block.children())
+        .filter(|c| c.kind() == SyntaxKind::DECLARATION)
+        .filter_map(|c| {
+            c.children()
+                .find(|c| c.kind() == SyntaxKind::DECLARATION_BASIC)
+        })
+        .filter_map(|declaration| {
+            let property = declaration
+                .children()
+                .find(|c| c.kind() == SyntaxKind::PROPERTY)?;
+            let important = declaration.children().any(|c| c.kind() == SyntaxKind::PRIO);
+            Some((property.text().to_string().to_lowercase(), important))
+        })
+        .collect();
+    Some(RuleInfo {
+        source,
+        range,
+        selectors: selectors.iter().map(|s| selector_info(server, s)).collect(),
+        declarations,
+    })
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("{count} {noun}"),
+        _ => format!("{count} {noun}s"),
+    }
+}
+
+impl CssLancerServer {
+    /// A lens above each rule of `src` with the number of other rules in the workspace
+    /// with one of its selectors, and the number of its declarations that rules targeting
+    /// the same elements may override: those with a higher specificity, or the same one
+    /// further down in the same stylesheet, with `!important` taken into account. The
+    /// command of the lens peeks at these rules.
+    pub fn get_code_lenses(&self, workspace: &Workspace, src: &Source) -> Vec<CodeLens> {
+        let encoding = self.const_config().position_encoding;
+        let mut sources = workspace.files.values().collect::<Vec<_>>();
+        sources.sort_by_key(|source| &source.url);
+        let rules = sources
+            .into_iter()
+            .flat_map(|source| {
+                let root = source.parse.tree().syntax;
+                root.descendants()
+                    .filter(|d| d.kind() == SyntaxKind::RULE_SET)
+                    .filter_map(|rule| rule_info(self, source, &rule))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // indices of the rules with each selector and of those declaring each property,
+        // so a rule is only compared with the rules it can be related to
+        let mut by_selector: HashMap<&str, Vec<usize>> = HashMap::new();
+        let mut by_property: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, rule) in rules.iter().enumerate() {
+            for selector in rule.selectors.iter().map(|s| s.text.as_str()).unique() {
+                by_selector.entry(selector).or_default().push(i);
+            }
+            for property in rule.declarations.iter().map(|(p, _)| p.as_str()).unique() {
+                by_property.entry(property).or_default().push(i);
+            }
+        }
+        let to_range = |range: TextRange, source: &Source| text_range(range, source, encoding);
+
+        rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.source.url == src.url)
+            .map(|(index, rule)| {
+                let same_selector = rule
+                    .selectors
+                    .iter()
+                    .flat_map(|selector| &by_selector[selector.text.as_str()])
+                    .copied()
+                    .filter(|&other| other != index)
+                    .sorted_unstable()
+                    .dedup()
+                    .map(|other| &rules[other])
+                    .collect::<Vec<_>>();
+                let mut overriding = Vec::new();
+                let mut overridden = 0;
+                for (property, important) in &rule.declarations {
+                    let by = by_property[property.as_str()]
+                        .iter()
+                        .filter(|&&other| other != index)
+                        .map(|&other| &rules[other])
+                        .filter(|other| {
+                            other.declarations.iter().any(|(p, i)| {
+                                p == property && other.may_override(*i, rule, *important)
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    if !by.is_empty() {
+                        overridden += 1;
+                    }
+                    overriding.extend(by);
+                }
+
+                let mut locations = Vec::new();
+                for other in same_selector.iter().chain(&overriding) {
+                    let location = Location::new(
+                        other.source.url.clone(),
+                        to_range(other.range, other.source),
+                    );
+                    if !locations.contains(&location) {
+                        locations.push(location);
+                    }
+                }
+                let range = to_range(rule.range, src);
+                let title = format!(
+                    "{} with this selector, {} overridden",
+                    plural(same_selector.len(), "other rule"),
+                    plural(overridden, "declaration")
+                );
+                CodeLens {
+                    range,
+                    command: Some(Command {
+                        title,
+                        command: SHOW_REFERENCES_COMMAND.to_owned(),
+                        arguments: Some(vec![
+                            serde_json::json!(src.url),
+                            serde_json::json!(range.start),
+                            serde_json::json!(locations),
+                        ]),
+                    }),
+                    data: None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod code_lens_test {
+    use lsp_types::{Location, Url};
+
+    use crate::services::CssLancerServer;
+    use crate::workspace::Workspace;
+
+    /// Line, title and the path and line of each peeked location of a lens
+    type Lens = (u32, String, Vec<(String, u32)>);
+
+    /// The lenses of `test://foo/a.css`
+    fn lenses(files: &[(&str, &str)]) -> Vec<Lens> {
+        let ls = CssLancerServer::new_test();
+        let workspace = Workspace::new_test(files);
+        let src = workspace
+            .get_document_ref(&Url::parse("test://foo/a.css").unwrap())
+            .unwrap();
+        ls.get_code_lenses(&workspace, src)
+            .into_iter()
+            .map(|lens| {
+                let command = lens.command.unwrap();
+                assert_eq!(command.command, "csslancer.showReferences");
+                let arguments = command.arguments.unwrap();
+                let locations: Vec<Location> =
+                    serde_json::from_value(arguments[2].clone()).unwrap();
+                let locations = locations
+                    .into_iter()
+                    .map(|l| (l.uri.path().to_owned(), l.range.start.line))
+                    .collect();
+                (lens.range.start.line, command.title, locations)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn usages_and_overrides() {
+        let files = [
+            (
+                "test://foo/a.css",
+                ".btn { color: red; margin: 0; }\n.btn.primary { color: blue; }\ndiv > p {}\n.btn { margin: 1px; }\n.btn::before { color: green; }\n.x { top: 0 !important; }",
+            ),
+            (
+                "test://foo/b.css",
+                ".btn { padding: 0; }\ndiv>p { color: red; }\n.x.y { top: 1px; }\n.z { color: black !important; }",
+            ),
+        ];
+        let lenses = lenses(&files);
+        assert_eq!(
+            lenses[0],
+            (
+                0,
+                "2 other rules with this selector, 2 declarations overridden".to_owned(),
+                vec![
+                    ("/a.css".to_owned(), 3),
+                    ("/b.css".to_owned(), 0),
+                    ("/a.css".to_owned(), 1),
+                ]
+            )
+        );
+        assert_eq!(
+            lenses[1].1,
+            "0 other rules with this selector, 0 declarations overridden"
+        );
+        assert_eq!(
+            lenses[2],
+            (
+                2,
+                "1 other rule with this selector, 0 declarations overridden".to_owned(),
+                vec![("/b.css".to_owned(), 1)]
+            )
+        );
+        assert_eq!(
+            lenses[3].1,
+            "2 other rules with this selector, 0 declarations overridden"
+        );
+        assert_eq!(
+            lenses[4].1,
+            "0 other rules with this selector, 0 declarations overridden"
+        );
+        assert_eq!(
+            lenses[5].1,
+            "0 other rules with this selector, 0 declarations overridden"
+        );
+    }
+}
@@ -0,0 +1,801 @@
+//! Colors of CSS Color 4, see https://drafts.csswg.org/css-color-4/
+//!
+//! Colors are kept in sRGB without clamping channels to its gamut, so colors of wider
+//! gamuts survive a round trip. Conversion matrices and transfer functions follow the
+//! sample code of the specification.
+
+use super::value_validation::{components, Component};
+use crate::data::facts::COLORS;
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+
+type Matrix = [[f64; 3]; 3];
+
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077204, 0.0415550574071756],
+    [0.05563007969699363, -0.2039769588889765, 1.0569715142428784],
+];
+
+const LINEAR_DISPLAY_P3_TO_XYZ: Matrix = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const LINEAR_A98_RGB_TO_XYZ: Matrix = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+/// To XYZ with a D50 white point
+const LINEAR_PROPHOTO_RGB_TO_XYZ: Matrix = [
+    [0.7977666449006423, 0.13518129740053308, 0.0313477341283922],
+    [0.2880748288194013, 0.711835234241873, 0.00008993693872564],
+    [0.0, 0.0, 0.8251046025104602],
+];
+
+const LINEAR_REC2020_TO_XYZ: Matrix = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+/// Bradford chromatic adaptation from a D50 to a D65 white point
+const D50_TO_D65: Matrix = [
+    [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+    [
+        -0.0283697093338637,
+        1.0099953980813041,
+        0.021041441191917323,
+    ],
+    [
+        0.012314014864481998,
+        -0.020507649298898964,
+        1.330365926242124,
+    ],
+];
+
+const D65_TO_D50: Matrix = [
+    [
+        1.0479297925449969,
+        0.022946870601609708,
+        -0.05019226628920527,
+    ],
+    [
+        0.029627808770055993,
+        0.99043442675388,
+        -0.017073799063418826,
+    ],
+    [
+        -0.009243040646204514,
+        0.015055191490298145,
+        0.7518742814281372,
+    ],
+];
+
+const D50_WHITE: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+/// CIE Lab constants, `κ` and `ε`
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+
+fn multiply(matrix: &Matrix, vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Applies `transfer` to the magnitude of every channel, keeping its sign
+fn transfer(channels: [f64; 3], transfer: impl Fn(f64) -> f64) -> [f64; 3] {
+    channels.map(|c| c.signum() * transfer(c.abs()))
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Spaces of the `color()` function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProphotoRgb,
+    Rec2020,
+    XyzD50,
+    XyzD65,
+}
+
+impl ColorSpace {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let space = match name.to_lowercase().as_str() {
+            "srgb" => Self::Srgb,
+            "srgb-linear" => Self::SrgbLinear,
+            "display-p3" => Self::DisplayP3,
+            "a98-rgb" => Self::A98Rgb,
+            "prophoto-rgb" => Self::ProphotoRgb,
+            "rec2020" => Self::Rec2020,
+            "xyz-d50" => Self::XyzD50,
+            "xyz" | "xyz-d65" => Self::XyzD65,
+            _ => return None,
+        };
+        Some(space)
+    }
+
+    /// XYZ with a D65 white point of `channels` in this space
+    fn to_xyz(self, channels: [f64; 3]) -> [f64; 3] {
+        match self {
+            Self::Srgb => multiply(&LINEAR_SRGB_TO_XYZ, transfer(channels, srgb_to_linear)),
+            Self::SrgbLinear => multiply(&LINEAR_SRGB_TO_XYZ, channels),
+            Self::DisplayP3 => multiply(
+                &LINEAR_DISPLAY_P3_TO_XYZ,
+                transfer(channels, srgb_to_linear),
+            ),
+            Self::A98Rgb => {
+                let linear = transfer(channels, |c| c.powf(563.0 / 256.0));
+                multiply(&LINEAR_A98_RGB_TO_XYZ, linear)
+            }
+            Self::ProphotoRgb => {
+                let linear = transfer(channels, |c| {
+                    if c <= 16.0 / 512.0 {
+                        c / 16.0
+                    } else {
+                        c.powf(1.8)
+                    }
+                });
+                multiply(&D50_TO_D65, multiply(&LINEAR_PROPHOTO_RGB_TO_XYZ, linear))
+            }
+            Self::Rec2020 => {
+                const ALPHA: f64 = 1.09929682680944;
+                const BETA: f64 = 0.018053968510807;
+                let linear = transfer(channels, |c| {
+                    if c < BETA * 4.5 {
+                        c / 4.5
+                    } else {
+                        ((c + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+                    }
+                });
+                multiply(&LINEAR_REC2020_TO_XYZ, linear)
+            }
+            Self::XyzD50 => multiply(&D50_TO_D65, channels),
+            Self::XyzD65 => channels,
+        }
+    }
+}
+
+/// Ways to write a color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    Named,
+    Hex,
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    /// `color(srgb …)`
+    Srgb,
+}
+
+impl Notation {
+    pub const ALL: [Notation; 10] = [
+        Self::Named,
+        Self::Hex,
+        Self::Rgb,
+        Self::Hsl,
+        Self::Hwb,
+        Self::Lab,
+        Self::Lch,
+        Self::Oklab,
+        Self::Oklch,
+        Self::Srgb,
+    ];
+}
+
+/// A color as sRGB channels from 0 to 1, which may be outside that range for colors out
+/// of the sRGB gamut, and an alpha from 0 to 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl Color {
+    pub fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        Self::new(red, green, blue, alpha)
+    }
+
+    fn channels(&self) -> [f64; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    /// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#')?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize, width: usize| {
+            let value = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).ok()?;
+            let value = if width == 1 { value * 17 } else { value };
+            Some(value as f64 / 255.0)
+        };
+        let width = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+        let alpha = match digits.len() / width {
+            4 => channel(3, width)?,
+            _ => 1.0,
+        };
+        Some(Self::new(
+            channel(0, width)?,
+            channel(1, width)?,
+            channel(2, width)?,
+            alpha,
+        ))
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        let (_, hex) = COLORS.iter().find(|(color, _)| *color == name)?;
+        Self::from_hex(hex)
+    }
+
+    /// `hue` in degrees, `saturation` and `lightness` from 0 to 1
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let f = |n: f64| {
+            let k = (n + hue / 30.0) % 12.0;
+            let a = saturation * lightness.min(1.0 - lightness);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Self::new(f(0.0), f(8.0), f(4.0), alpha)
+    }
+
+    /// `hue` in degrees, `whiteness` and `blackness` from 0 to 1
+    pub fn from_hwb(hue: f64, whiteness: f64, blackness: f64, alpha: f64) -> Self {
+        if whiteness + blackness >= 1.0 {
+            let gray = whiteness / (whiteness + blackness);
+            return Self::new(gray, gray, gray, alpha);
+        }
+        let pure = Self::from_hsl(hue, 1.0, 0.5, alpha);
+        let scale = |c: f64| c * (1.0 - whiteness - blackness) + whiteness;
+        Self::new(scale(pure.red), scale(pure.green), scale(pure.blue), alpha)
+    }
+
+    /// CIE Lab, with a D50 white point
+    pub fn from_lab(lightness: f64, a: f64, b: f64, alpha: f64) -> Self {
+        let f1 = (lightness + 16.0) / 116.0;
+        let f0 = a / 500.0 + f1;
+        let f2 = f1 - b / 200.0;
+        let inverse = |f: f64| {
+            if f.powi(3) > LAB_EPSILON {
+                f.powi(3)
+            } else {
+                (116.0 * f - 16.0) / LAB_KAPPA
+            }
+        };
+        let y = if lightness > LAB_KAPPA * LAB_EPSILON {
+            f1.powi(3)
+        } else {
+            lightness / LAB_KAPPA
+        };
+        let xyz = [
+            inverse(f0) * D50_WHITE[0],
+            y * D50_WHITE[1],
+            inverse(f2) * D50_WHITE[2],
+        ];
+        Self::from_xyz(multiply(&D50_TO_D65, xyz), alpha)
+    }
+
+    pub fn from_lch(lightness: f64, chroma: f64, hue: f64, alpha: f64) -> Self {
+        let (a, b) = from_polar(chroma, hue);
+        Self::from_lab(lightness, a, b, alpha)
+    }
+
+    pub fn from_oklab(lightness: f64, a: f64, b: f64, alpha: f64) -> Self {
+        let l = lightness + 0.3963377774 * a + 0.2158037573 * b;
+        let m = lightness - 0.1055613458 * a - 0.0638541728 * b;
+        let s = lightness - 0.0894841775 * a - 1.2914855480 * b;
+        let (l, m, s) = (l.powi(3), m.powi(3), s.powi(3));
+        let linear = [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ];
+        Self::from_channels(transfer(linear, linear_to_srgb), alpha)
+    }
+
+    pub fn from_oklch(lightness: f64, chroma: f64, hue: f64, alpha: f64) -> Self {
+        let (a, b) = from_polar(chroma, hue);
+        Self::from_oklab(lightness, a, b, alpha)
+    }
+
+    pub fn from_space(space: ColorSpace, channels: [f64; 3], alpha: f64) -> Self {
+        match space {
+            ColorSpace::Srgb => Self::from_channels(channels, alpha),
+            _ => Self::from_xyz(space.to_xyz(channels), alpha),
+        }
+    }
+
+    /// From XYZ with a D65 white point
+    fn from_xyz(xyz: [f64; 3], alpha: f64) -> Self {
+        let linear = multiply(&XYZ_TO_LINEAR_SRGB, xyz);
+        Self::from_channels(transfer(linear, linear_to_srgb), alpha)
+    }
+
+    fn to_linear(self) -> [f64; 3] {
+        transfer(self.channels(), srgb_to_linear)
+    }
+
+    /// Hue in degrees, 0 for grays
+    fn hue(&self) -> f64 {
+        let [r, g, b] = self.channels();
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        if delta <= f64::EPSILON {
+            return 0.0;
+        }
+        let hue = if max == r {
+            (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        hue * 60.0
+    }
+
+    /// Hue in degrees, saturation and lightness from 0 to 1
+    pub fn to_hsl(self) -> [f64; 3] {
+        let [r, g, b] = self.channels();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let saturation = if max - min <= f64::EPSILON || lightness <= 0.0 || lightness >= 1.0 {
+            0.0
+        } else {
+            (max - lightness) / lightness.min(1.0 - lightness)
+        };
+        [self.hue(), saturation, lightness]
+    }
+
+    /// Hue in degrees, whiteness and blackness from 0 to 1
+    pub fn to_hwb(self) -> [f64; 3] {
+        let [r, g, b] = self.channels();
+        [self.hue(), r.min(g).min(b), 1.0 - r.max(g).max(b)]
+    }
+
+    pub fn to_lab(self) -> [f64; 3] {
+        let xyz = multiply(&D65_TO_D50, multiply(&LINEAR_SRGB_TO_XYZ, self.to_linear()));
+        let f = |i: usize| {
+            let v = xyz[i] / D50_WHITE[i];
+            if v > LAB_EPSILON {
+                v.cbrt()
+            } else {
+                (LAB_KAPPA * v + 16.0) / 116.0
+            }
+        };
+        let (f0, f1, f2) = (f(0), f(1), f(2));
+        [116.0 * f1 - 16.0, 500.0 * (f0 - f1), 200.0 * (f1 - f2)]
+    }
+
+    pub fn to_lch(self) -> [f64; 3] {
+        let [lightness, a, b] = self.to_lab();
+        let (chroma, hue) = to_polar(a, b);
+        [lightness, chroma, hue]
+    }
+
+    pub fn to_oklab(self) -> [f64; 3] {
+        let [r, g, b] = self.to_linear();
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        [
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        ]
+    }
+
+    pub fn to_oklch(self) -> [f64; 3] {
+        let [lightness, a, b] = self.to_oklab();
+        let (chroma, hue) = to_polar(a, b);
+        [lightness, chroma, hue]
+    }
+
+    /// The sRGB channels as integers from 0 to 255, clamped to the sRGB gamut
+    fn to_bytes(self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// This color written in `notation`, `None` for a name when no named color matches
+    pub fn serialize(&self, notation: Notation) -> Option<String> {
+        let alpha = match self.alpha < 1.0 {
+            true => format!(" / {}", format_number(self.alpha, 2)),
+            false => String::new(),
+        };
+        let text = match notation {
+            Notation::Named => {
+                let hex = self.serialize(Notation::Hex)?;
+                let (name, _) = COLORS.iter().find(|(_, color)| *color == hex)?;
+                name.to_string()
+            }
+            Notation::Hex => {
+                let [r, g, b, a] = self.to_bytes();
+                match a {
+                    255 => format!("#{r:02x}{g:02x}{b:02x}"),
+                    _ => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+                }
+            }
+            Notation::Rgb => {
+                let [r, g, b, _] = self.to_bytes();
+                format!("rgb({r} {g} {b}{alpha})")
+            }
+            Notation::Hsl => {
+                let [h, s, l] = self.to_hsl();
+                format!(
+                    "hsl({} {}% {}%{alpha})",
+                    format_number(h, 2),
+                    format_number(s * 100.0, 2),
+                    format_number(l * 100.0, 2)
+                )
+            }
+            Notation::Hwb => {
+                let [h, w, b] = self.to_hwb();
+                format!(
+                    "hwb({} {}% {}%{alpha})",
+                    format_number(h, 2),
+                    format_number(w * 100.0, 2),
+                    format_number(b * 100.0, 2)
+                )
+            }
+            Notation::Lab => {
+                let [l, a, b] = self.to_lab();
+                format!(
+                    "lab({}% {} {}{alpha})",
+                    format_number(l, 2),
+                    format_number(a, 2),
+                    format_number(b, 2)
+                )
+            }
+            Notation::Lch => {
+                let [l, c, h] = self.to_lch();
+                format!(
+                    "lch({}% {} {}{alpha})",
+                    format_number(l, 2),
+                    format_number(c, 2),
+                    format_number(h, 2)
+                )
+            }
+            Notation::Oklab => {
+                let [l, a, b] = self.to_oklab();
+                format!(
+                    "oklab({}% {} {}{alpha})",
+                    format_number(l * 100.0, 2),
+                    format_number(a, 4),
+                    format_number(b, 4)
+                )
+            }
+            Notation::Oklch => {
+                let [l, c, h] = self.to_oklch();
+                format!(
+                    "oklch({}% {} {}{alpha})",
+                    format_number(l * 100.0, 2),
+                    format_number(c, 4),
+                    format_number(h, 2)
+                )
+            }
+            Notation::Srgb => {
+                let [r, g, b] = self.channels();
+                format!(
+                    "color(srgb {} {} {}{alpha})",
+                    format_number(r, 4),
+                    format_number(g, 4),
+                    format_number(b, 4)
+                )
+            }
+        };
+        Some(text)
+    }
+}
+
+fn from_polar(chroma: f64, hue: f64) -> (f64, f64) {
+    let hue = hue.to_radians();
+    (chroma * hue.cos(), chroma * hue.sin())
+}
+
+/// Chroma and hue in degrees of a and b, with a hue of 0 for grays
+fn to_polar(a: f64, b: f64) -> (f64, f64) {
+    let chroma = a.hypot(b);
+    if chroma < 1e-4 {
+        return (chroma, 0.0);
+    }
+    (chroma, b.atan2(a).to_degrees().rem_euclid(360.0))
+}
+
+/// `value` with at most `decimals` decimals and without trailing zeros
+fn format_number(value: f64, decimals: i32) -> String {
+    let factor = 10f64.powi(decimals);
+    let rounded = (value * factor).round() / factor;
+    // no `-0`
+    format!("{}", rounded + 0.0)
+}
+
+/// A channel that is a number, or a percentage of `percent_reference`; `none` is 0
+fn channel(component: &Component, percent_reference: f64) -> Option<f64> {
+    match component {
+        Component::Number { value, .. } => Some(*value),
+        Component::Dimension { value, unit } if unit == "%" => {
+            Some(value / 100.0 * percent_reference)
+        }
+        Component::Ident(ident) if ident.eq_ignore_ascii_case("none") => Some(0.0),
+        _ => None,
+    }
+}
+
+/// A hue in degrees
+fn hue(component: &Component) -> Option<f64> {
+    match component {
+        Component::Dimension { value, unit } => match unit.as_str() {
+            "deg" => Some(*value),
+            "rad" => Some(value.to_degrees()),
+            "grad" => Some(value * 0.9),
+            "turn" => Some(value * 360.0),
+            _ => None,
+        },
+        Component::Number { value, .. } => Some(*value),
+        Component::Ident(ident) if ident.eq_ignore_ascii_case("none") => Some(0.0),
+        _ => None,
+    }
+}
+
+/// The color of a color function with the given arguments, in the modern, space
+/// separated syntax or, for `rgb()` and `hsl()`, the legacy comma separated one
+fn parse_function(name: &str, arguments: &[Component]) -> Option<Color> {
+    let (channels, alpha) = if arguments.contains(&Component::Comma) {
+        if !matches!(name, "rgb" | "rgba" | "hsl" | "hsla") {
+            return None;
+        }
+        let parts = arguments
+            .split(|c| *c == Component::Comma)
+            .map(|part| match part {
+                [component] => Some(component),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        match parts.as_slice() {
+            [channels @ .., alpha] if parts.len() == 4 => (channels.to_vec(), Some(*alpha)),
+            _ => (parts, None),
+        }
+    } else {
+        let mut parts = arguments.split(|c| *c == Component::Slash);
+        let channels = parts.next()?.iter().collect::<Vec<_>>();
+        let alpha = match parts.next() {
+            Some([alpha]) => Some(alpha),
+            Some(_) => return None,
+            None => None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        (channels, alpha)
+    };
+    let alpha = match alpha {
+        Some(alpha) => channel(alpha, 1.0)?,
+        None => 1.0,
+    };
+
+    if name == "color" {
+        let [Component::Ident(space), c1, c2, c3] = channels.as_slice() else {
+            return None;
+        };
+        let space = ColorSpace::from_name(space)?;
+        let channels = [channel(c1, 1.0)?, channel(c2, 1.0)?, channel(c3, 1.0)?];
+        return Some(Color::from_space(space, channels, alpha));
+    }
+    let [c1, c2, c3] = channels.as_slice() else {
+        return None;
+    };
+    let color = match name {
+        "rgb" | "rgba" => Color::new(
+            channel(c1, 255.0)? / 255.0,
+            channel(c2, 255.0)? / 255.0,
+            channel(c3, 255.0)? / 255.0,
+            alpha,
+        ),
+        "hsl" | "hsla" => Color::from_hsl(
+            hue(c1)?,
+            channel(c2, 100.0)? / 100.0,
+            channel(c3, 100.0)? / 100.0,
+            alpha,
+        ),
+        "hwb" => Color::from_hwb(
+            hue(c1)?,
+            channel(c2, 100.0)? / 100.0,
+            channel(c3, 100.0)? / 100.0,
+            alpha,
+        ),
+        "lab" => Color::from_lab(
+            channel(c1, 100.0)?,
+            channel(c2, 125.0)?,
+            channel(c3, 125.0)?,
+            alpha,
+        ),
+        "lch" => Color::from_lch(channel(c1, 100.0)?, channel(c2, 150.0)?, hue(c3)?, alpha),
+        "oklab" => Color::from_oklab(
+            channel(c1, 1.0)?,
+            channel(c2, 0.4)?,
+            channel(c3, 0.4)?,
+            alpha,
+        ),
+        "oklch" => Color::from_oklch(channel(c1, 1.0)?, channel(c2, 0.4)?, hue(c3)?, alpha),
+        _ => return None,
+    };
+    Some(color)
+}
+
+/// The color `node` is written as: a hex color, a color function, or a term of a
+/// declaration value that is a named color
+pub fn color_of(node: &SyntaxNode) -> Option<Color> {
+    match node.kind() {
+        SyntaxKind::HEX_COLOR_VALUE => Color::from_hex(&node.text().to_string()),
+        SyntaxKind::FUNCTION_WITH_ARGS => match components(node)?.as_slice() {
+            [Component::Function { name, arguments }] => parse_function(name, arguments),
+            _ => None,
+        },
+        SyntaxKind::TERM => {
+            let token = node
+                .first_token()
+                .filter(|t| t.kind() == SyntaxKind::IDENTIFIER)?;
+            if node.children_with_tokens().count() != 1
+                || !node.ancestors().any(|a| {
+                    matches!(
+                        a.kind(),
+                        SyntaxKind::DECLARATION_BASIC | SyntaxKind::DECLARATION_CUSTOM_PROPERTY
+                    )
+                })
+            {
+                return None;
+            }
+            Color::from_name(token.text())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod color_test {
+    use super::{color_of, Color, Notation};
+    use crate::row_parser::nodes_gen::SourceFile;
+    use crate::row_parser::nodes_types::SyntaxNode;
+    use crate::row_parser::syntax_kind_gen::SyntaxKind;
+
+    fn parse(value: &str) -> Option<Color> {
+        let root: SyntaxNode = SourceFile::parse(&format!(".a {{ color: {value}; }}"))
+            .tree()
+            .syntax;
+        let term = root.descendants().find(|n| n.kind() == SyntaxKind::TERM)?;
+        color_of(&term).or_else(|| color_of(&term.first_child()?))
+    }
+
+    fn bytes(value: &str) -> Option<[u8; 4]> {
+        parse(value).map(Color::to_bytes)
+    }
+
+    #[test]
+    fn parsing() {
+        let red = Some([255, 0, 0, 255]);
+        for value in [
+            "red",
+            "Red",
+            "#f00",
+            "#ff0000ff",
+            "rgb(255, 0, 0)",
+            "rgba(100%, 0%, 0%, 1)",
+            "rgb(255 0 none / 100%)",
+            "hsl(0, 100%, 50%)",
+            "hsl(360deg 100 50)",
+            "hsl(1turn 100% 50%)",
+            "hwb(0 0% 0%)",
+            "lab(54.29% 80.8 69.89)",
+            "lch(54.29 106.84 40.85)",
+            "oklab(62.8% 0.2249 0.1258)",
+            "oklch(0.628 0.2577 29.23)",
+            "color(srgb 1 0 0)",
+            "color(srgb-linear 100% 0 0)",
+            "color(xyz 0.4124 0.2126 0.0193)",
+        ] {
+            assert_eq!(bytes(value), red, "{value}");
+        }
+        assert_eq!(bytes("#0f08"), Some([0, 255, 0, 136]));
+        assert_eq!(bytes("rgb(0 0 255 / 0.5)"), Some([0, 0, 255, 128]));
+        assert_eq!(bytes("hsl(120 100% 25%)"), Some([0, 128, 0, 255]));
+        assert_eq!(bytes("hwb(0 60% 60%)"), Some([128, 128, 128, 255]));
+        assert_eq!(bytes("color(display-p3 1 1 1)"), Some([255, 255, 255, 255]));
+        assert_eq!(bytes("color(rec2020 0 0 0)"), Some([0, 0, 0, 255]));
+
+        for value in [
+            "foo",
+            "transparent",
+            "rgb(1 2)",
+            "rgb(1, 2 3)",
+            "hsl(10px 1% 1%)",
+            "color(foo 1 2 3)",
+            "rgb(var(--x) 1 2)",
+            "lab(1, 2, 3)",
+        ] {
+            assert_eq!(parse(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn serialization() {
+        let coral = Color::from_name("coral").unwrap();
+        let serialized = Notation::ALL.map(|notation| coral.serialize(notation).unwrap());
+        assert_eq!(
+            serialized,
+            [
+                "coral",
+                "#ff7f50",
+                "rgb(255 127 80)",
+                "hsl(16.11 100% 65.69%)",
+                "hwb(16.11 31.37% 0%)",
+                "lab(67.98% 47.38 48.6)",
+                "lch(67.98% 67.88 45.73)",
+                "oklab(73.51% 0.1282 0.1085)",
+                "oklch(73.51% 0.168 40.25)",
+                "color(srgb 1 0.498 0.3137)",
+            ]
+        );
+        for text in &serialized {
+            assert_eq!(bytes(text), Some(coral.to_bytes()), "{text}");
+        }
+
+        let translucent = Color::new(0.0, 0.0, 0.0, 0.5);
+        assert_eq!(translucent.serialize(Notation::Named), None);
+        assert_eq!(
+            translucent.serialize(Notation::Hex).as_deref(),
+            Some("#00000080")
+        );
+        assert_eq!(
+            translucent.serialize(Notation::Hsl).as_deref(),
+            Some("hsl(0 0% 0% / 0.5)")
+        );
+    }
+}
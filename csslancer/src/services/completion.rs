@@ -0,0 +1,740 @@
+use super::CssLancerServer;
+
+use lsp_types::{
+    Command, CompletionItem, CompletionItemKind, CompletionItemTag, CompletionList,
+    CompletionResponse, CompletionTextEdit, Documentation, InsertTextFormat, Range, TextEdit,
+};
+use rowan::{TextRange, TextSize};
+use std::collections::HashSet;
+use tracing::trace;
+
+use crate::css_language_types::{CompletionSettings, EntryStatus, HoverSettings, PropertyData};
+use crate::data::entry::{get_entry_description, IEntry2};
+use crate::data::facts::{
+    FunctionFact, COLORS, COLOR_FUNCTIONS, COLOR_KEYWORDS, IMAGE_FUNCTIONS, TIMING_FUNCTIONS,
+    TIMING_KEYWORDS,
+};
+use crate::data::value_syntax::Term;
+use crate::interop::{client_to_csslancer, csslancer_to_client, LspPosition};
+use crate::row_parser::nodes_types::{SyntaxNode, SyntaxToken};
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+/// Keywords every property accepts, see https://drafts.csswg.org/css-cascade/#defaulting-keywords
+pub const CSS_WIDE_KEYWORDS: [(&str, &str); 5] = [
+    ("initial", "Represents the value specified as the property's initial value."),
+    ("inherit", "Represents the computed value of the property on the element's parent."),
+    ("unset", "Acts as either `inherit` or `initial`, depending on whether the property is inherited or not."),
+    ("revert", "Rolls back the cascade to the value of the previous cascade origin."),
+    ("revert-layer", "Rolls back the cascade to the value of the previous cascade layer."),
+];
+
+/// Sort text prefixes, ordering groups of completion items relative to each other
+pub mod sort_texts {
+    pub const ENUMS: &str = " ";
+    pub const NORMAL: &str = "d";
+    pub const VENDOR_PREFIXED: &str = "x";
+    pub const TERM: &str = "y";
+}
+
+const RETRIGGER_COMMAND: &str = "editor.action.triggerSuggest";
+
+/// How deep `<'property'>` references in a value syntax are followed for value proposals
+const MAX_SYNTAX_DEPTH: usize = 2;
+
+/// Completion items for a property value, deduplicated by label
+struct ValueProposals {
+    range: Range,
+    items: Vec<CompletionItem>,
+    seen: HashSet<String>,
+}
+
+impl ValueProposals {
+    #[allow(clippy::too_many_arguments)]
+    fn push(
+        &mut self,
+        label: &str,
+        new_text: String,
+        is_snippet: bool,
+        kind: CompletionItemKind,
+        documentation: Option<Documentation>,
+        sort_text: String,
+        status: Option<EntryStatus>,
+    ) {
+        if !self.seen.insert(label.to_owned()) {
+            return;
+        }
+        self.items.push(CompletionItem {
+            label: label.to_owned(),
+            kind: Some(kind),
+            documentation,
+            tags: deprecated_tag(status),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: self.range,
+                new_text,
+            })),
+            insert_text_format: Some(if is_snippet {
+                InsertTextFormat::SNIPPET
+            } else {
+                InsertTextFormat::PLAIN_TEXT
+            }),
+            sort_text: Some(sort_text),
+            ..Default::default()
+        });
+    }
+
+    fn push_functions(&mut self, functions: &[FunctionFact]) {
+        for function in functions {
+            self.push(
+                &format!("{}()", function.name),
+                function.insert_text.to_owned(),
+                true,
+                CompletionItemKind::FUNCTION,
+                Some(Documentation::String(function.description.to_owned())),
+                sort_texts::TERM.to_owned(),
+                None,
+            );
+        }
+    }
+}
+
+/// What is being completed at the cursor
+enum CompletionContext {
+    /// A property name, `range` covers the (partial) name. `has_colon` is set
+    /// when editing the name of an existing declaration
+    Property {
+        range: TextRange,
+        has_colon: bool,
+    },
+    /// A value of the property `property`
+    PropertyValue {
+        property: String,
+        range: TextRange,
+    },
+    AtRule {
+        range: TextRange,
+    },
+    /// A pseudo selector, `elements_only` is set when the user already typed `::`
+    Pseudo {
+        range: TextRange,
+        elements_only: bool,
+    },
+}
+
+impl CssLancerServer {
+    pub fn get_completion(
+        &self,
+        src: &Source,
+        position: LspPosition,
+        settings: &CompletionSettings,
+    ) -> anyhow::Result<Option<CompletionResponse>> {
+        let position_encoding = self.const_config().position_encoding;
+        let offset = client_to_csslancer::position_to_offset(position, position_encoding, src);
+        let offset = TextSize::new(offset.try_into()?);
+
+        let Some(context) = completion_context(&src.parse.tree().syntax, offset) else {
+            trace!("no completion context");
+            return Ok(None);
+        };
+
+        let to_lsp_range = |range: TextRange| Range {
+            start: csslancer_to_client::offset_to_position(
+                range.start().into(),
+                position_encoding,
+                src,
+            ),
+            end: csslancer_to_client::offset_to_position(
+                range.end().into(),
+                position_encoding,
+                src,
+            ),
+        };
+
+        let items = match context {
+            CompletionContext::Property { range, has_colon } => {
+                self.get_property_proposals(to_lsp_range(range), has_colon, settings)
+            }
+            CompletionContext::PropertyValue { property, range } => {
+                self.get_property_value_proposals(&property, to_lsp_range(range))
+            }
+            CompletionContext::AtRule { range } => self.get_at_rule_proposals(to_lsp_range(range)),
+            CompletionContext::Pseudo {
+                range,
+                elements_only,
+            } => self.get_pseudo_proposals(to_lsp_range(range), elements_only),
+        };
+
+        Ok(Some(CompletionResponse::List(CompletionList {
+            is_incomplete: false,
+            items,
+        })))
+    }
+
+    fn get_property_proposals(
+        &self,
+        range: Range,
+        has_colon: bool,
+        settings: &CompletionSettings,
+    ) -> Vec<CompletionItem> {
+        self.css_data_manager
+            .properties()
+            .map(|entry| {
+                let (new_text, retrigger, is_snippet) = if has_colon {
+                    (entry.name.clone(), false, false)
+                } else if settings.complete_property_with_semicolon {
+                    (entry.name.clone() + ": $0;", true, true)
+                } else {
+                    (entry.name.clone() + ": ", true, false)
+                };
+                CompletionItem {
+                    label: entry.name.clone(),
+                    kind: Some(CompletionItemKind::PROPERTY),
+                    documentation: self.entry_documentation(IEntry2::Prop(entry)),
+                    tags: deprecated_tag(entry.status),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit { range, new_text })),
+                    insert_text_format: Some(if is_snippet {
+                        InsertTextFormat::SNIPPET
+                    } else {
+                        InsertTextFormat::PLAIN_TEXT
+                    }),
+                    sort_text: Some(property_sort_text(entry)),
+                    command: (retrigger && settings.trigger_property_value_completion)
+                        .then(retrigger_command),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn get_property_value_proposals(&self, property: &str, range: Range) -> Vec<CompletionItem> {
+        let mut proposals = ValueProposals {
+            range,
+            items: Vec::new(),
+            seen: HashSet::new(),
+        };
+        if let Some(entry) = self.css_data_manager.get_property(&property.to_lowercase()) {
+            self.collect_value_proposals(entry, &mut proposals, 0);
+        }
+        for (keyword, description) in CSS_WIDE_KEYWORDS {
+            proposals.push(
+                keyword,
+                keyword.to_owned(),
+                false,
+                CompletionItemKind::KEYWORD,
+                Some(Documentation::String(description.to_owned())),
+                sort_texts::TERM.to_owned(),
+                None,
+            );
+        }
+        proposals.items
+    }
+
+    /// Proposals from the `values` of `entry`, then from the keywords, functions and
+    /// value types in its `syntax`. `<'property'>` references are followed `MAX_SYNTAX_DEPTH` deep.
+    fn collect_value_proposals(
+        &self,
+        entry: &PropertyData,
+        proposals: &mut ValueProposals,
+        depth: usize,
+    ) {
+        for value in entry.values.iter() {
+            let relevance = value.relevance.unwrap_or(50).clamp(0, 99);
+            let sort_text = format!("{}_{:x}", sort_texts::ENUMS, 255 - relevance);
+            let (new_text, is_snippet) = match value.name.strip_suffix("()") {
+                Some(function_name) => (format!("{function_name}($1)"), true),
+                None => (value.name.clone(), false),
+            };
+            let kind = if is_snippet {
+                CompletionItemKind::FUNCTION
+            } else {
+                CompletionItemKind::VALUE
+            };
+            proposals.push(
+                &value.name,
+                new_text,
+                is_snippet,
+                kind,
+                self.entry_documentation(IEntry2::Value(value)),
+                sort_text,
+                value.status,
+            );
+        }
+
+        let terms = entry
+            .syntax
+            .as_deref()
+            .and_then(|syntax| self.css_data_manager.get_syntax(syntax))
+            .map(Term::descendants)
+            .unwrap_or_default();
+        let keywords = terms.iter().filter_map(|term| match term {
+            Term::Keyword(keyword) => Some(keyword),
+            _ => None,
+        });
+        for keyword in keywords {
+            proposals.push(
+                keyword,
+                keyword.clone(),
+                false,
+                CompletionItemKind::VALUE,
+                None,
+                sort_texts::NORMAL.to_owned(),
+                None,
+            );
+        }
+        let functions = terms.iter().filter_map(|term| match term {
+            Term::Function { name, .. } => Some(name),
+            _ => None,
+        });
+        for function in functions {
+            proposals.push(
+                &format!("{function}()"),
+                format!("{function}($1)"),
+                true,
+                CompletionItemKind::FUNCTION,
+                None,
+                sort_texts::NORMAL.to_owned(),
+                None,
+            );
+        }
+
+        let restrictions = entry.restrictions.iter().flatten().map(String::as_str);
+        let types = terms.iter().filter_map(|term| match term {
+            Term::Type { name, .. } => Some(name.as_str()),
+            _ => None,
+        });
+        let value_types = restrictions.chain(types).collect::<Vec<_>>();
+        if value_types.contains(&"color") {
+            for (name, hex) in COLORS {
+                proposals.push(
+                    name,
+                    (*name).to_owned(),
+                    false,
+                    CompletionItemKind::COLOR,
+                    Some(Documentation::String((*hex).to_owned())),
+                    sort_texts::NORMAL.to_owned(),
+                    None,
+                );
+            }
+            for (name, description) in COLOR_KEYWORDS {
+                proposals.push(
+                    name,
+                    (*name).to_owned(),
+                    false,
+                    CompletionItemKind::VALUE,
+                    Some(Documentation::String((*description).to_owned())),
+                    sort_texts::NORMAL.to_owned(),
+                    None,
+                );
+            }
+            proposals.push_functions(COLOR_FUNCTIONS);
+        }
+        if value_types.contains(&"image") {
+            proposals.push_functions(IMAGE_FUNCTIONS);
+        } else if value_types.contains(&"url") {
+            proposals.push_functions(&IMAGE_FUNCTIONS[..1]);
+        }
+        if value_types
+            .iter()
+            .any(|t| *t == "easing-function" || t.ends_with("timing-function"))
+        {
+            for (name, description) in TIMING_KEYWORDS {
+                proposals.push(
+                    name,
+                    (*name).to_owned(),
+                    false,
+                    CompletionItemKind::VALUE,
+                    Some(Documentation::String((*description).to_owned())),
+                    sort_texts::NORMAL.to_owned(),
+                    None,
+                );
+            }
+            proposals.push_functions(TIMING_FUNCTIONS);
+        }
+
+        if depth < MAX_SYNTAX_DEPTH {
+            let properties = terms.iter().filter_map(|term| match term {
+                Term::Property(property) => Some(property),
+                _ => None,
+            });
+            for property in properties {
+                if let Some(entry) = self.css_data_manager.get_property(property) {
+                    self.collect_value_proposals(entry, proposals, depth + 1);
+                }
+            }
+        }
+    }
+
+    fn get_at_rule_proposals(&self, range: Range) -> Vec<CompletionItem> {
+        self.css_data_manager
+            .at_directives()
+            .map(|entry| CompletionItem {
+                label: entry.name.clone(),
+                kind: Some(CompletionItemKind::KEYWORD),
+                documentation: self.entry_documentation(IEntry2::AtDir(entry)),
+                tags: deprecated_tag(entry.status),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: entry.name.clone(),
+                })),
+                sort_text: Some(vendor_sort_text(entry.name.trim_start_matches('@')).to_owned()),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn get_pseudo_proposals(&self, range: Range, elements_only: bool) -> Vec<CompletionItem> {
+        let pseudo_classes = self
+            .css_data_manager
+            .pseudo_classes()
+            .filter(|_| !elements_only)
+            .map(|entry| (entry, IEntry2::PseuClass(entry)));
+        let pseudo_elements = self
+            .css_data_manager
+            .pseudo_elements()
+            .map(|entry| (entry, IEntry2::PseuEle(entry)));
+
+        pseudo_classes
+            .chain(pseudo_elements)
+            .map(|(entry, ientry)| {
+                let (new_text, is_snippet) = match entry.name.strip_suffix("()") {
+                    Some(function_name) => (format!("{function_name}($1)"), true),
+                    None => (entry.name.clone(), false),
+                };
+                CompletionItem {
+                    label: entry.name.clone(),
+                    kind: Some(CompletionItemKind::FUNCTION),
+                    documentation: self.entry_documentation(ientry),
+                    tags: deprecated_tag(entry.status),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit { range, new_text })),
+                    insert_text_format: Some(if is_snippet {
+                        InsertTextFormat::SNIPPET
+                    } else {
+                        InsertTextFormat::PLAIN_TEXT
+                    }),
+                    sort_text: Some(
+                        vendor_sort_text(entry.name.trim_start_matches(':')).to_owned(),
+                    ),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn entry_documentation(&self, entry: IEntry2) -> Option<Documentation> {
+        get_entry_description(
+            entry,
+            self.does_support_markdown(),
+            &Some(HoverSettings {
+                documentation: true,
+                references: true,
+            }),
+        )
+        .map(Documentation::MarkupContent)
+    }
+}
+
+fn retrigger_command() -> Command {
+    Command {
+        title: "Suggest".to_owned(),
+        command: RETRIGGER_COMMAND.to_owned(),
+        arguments: None,
+    }
+}
+
+fn deprecated_tag(status: Option<EntryStatus>) -> Option<Vec<CompletionItemTag>> {
+    (status == Some(EntryStatus::Obsolete)).then(|| vec![CompletionItemTag::DEPRECATED])
+}
+
+fn vendor_sort_text(name: &str) -> &'static str {
+    if name.starts_with('-') {
+        sort_texts::VENDOR_PREFIXED
+    } else {
+        sort_texts::NORMAL
+    }
+}
+
+/// Higher relevance sorts first, relevance is clamped to `0..=99`
+fn property_sort_text(entry: &PropertyData) -> String {
+    let relevance = entry.relevance.clamp(0, 99);
+    format!("{}_{:x}", vendor_sort_text(&entry.name), 255 - relevance)
+}
+
+/// Last non-trivia token that starts before `offset`
+fn token_before(root: &SyntaxNode, offset: TextSize) -> Option<SyntaxToken> {
+    let mut token = root.token_at_offset(offset).left_biased()?;
+    if token.text_range().start() >= offset {
+        token = token.prev_token()?;
+    }
+    while token.kind().is_trivia() {
+        token = token.prev_token()?;
+    }
+    Some(token)
+}
+
+/// Whether `node` is a block that holds declarations (as opposed to nested rules only)
+pub fn is_declaration_block(node: &SyntaxNode) -> bool {
+    node.kind() == SyntaxKind::DECLARATIONS
+        && node.parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                SyntaxKind::RULE_SET
+                    | SyntaxKind::FONT_FACE
+                    | SyntaxKind::PAGE
+                    | SyntaxKind::VIEW_PORT
+                    | SyntaxKind::PROPERTY_AT_RULE
+                    | SyntaxKind::KEYFRAME_SELECTOR
+                    | SyntaxKind::POSITION_TRY
+            )
+            // `@starting-style` only holds declarations when it is nested in a rule
+            || parent.kind() == SyntaxKind::STARTING_STYLE
+                && parent.ancestors().any(|a| a.kind() == SyntaxKind::DECLARATIONS)
+        })
+}
+
+fn completion_context(root: &SyntaxNode, offset: TextSize) -> Option<CompletionContext> {
+    let empty_range = TextRange::empty(offset);
+    let token = token_before(root, offset)?;
+    // whether the cursor is right at the end of (or inside) `token`
+    let touching = token.text_range().end() >= offset;
+
+    if token.kind().is_at_keyword() || token.kind() == SyntaxKind::AT {
+        return touching.then(|| CompletionContext::AtRule {
+            range: token.text_range(),
+        });
+    }
+
+    // a lone `:` in front of the declarations (`.a:| {}`) ends up in an error node
+    if touching && token.kind() == SyntaxKind::COLON {
+        let in_selector_error = token.parent().is_some_and(|error| {
+            error.kind() == SyntaxKind::ERROR
+                && error.parent().is_some_and(|p| {
+                    matches!(p.kind(), SyntaxKind::RULE_SET | SyntaxKind::SOURCE_FILE)
+                })
+        });
+        if in_selector_error {
+            return Some(CompletionContext::Pseudo {
+                range: token.text_range(),
+                elements_only: false,
+            });
+        }
+    }
+
+    for node in token.parent_ancestors() {
+        match node.kind() {
+            SyntaxKind::SELECTOR_PSEUDO => {
+                if !touching {
+                    return None;
+                }
+                return Some(CompletionContext::Pseudo {
+                    range: node.text_range(),
+                    elements_only: node.text().to_string().starts_with("::"),
+                });
+            }
+            SyntaxKind::PROPERTY => {
+                if !touching {
+                    return None;
+                }
+                let has_colon = node.parent().is_some_and(|decl| {
+                    decl.children_with_tokens()
+                        .any(|c| c.kind() == SyntaxKind::COLON)
+                });
+                return Some(CompletionContext::Property {
+                    range: node.text_range(),
+                    has_colon,
+                });
+            }
+            SyntaxKind::DECLARATION_BASIC => {
+                let property = node.children().find(|c| c.kind() == SyntaxKind::PROPERTY)?;
+                let range = if touching && token.kind() == SyntaxKind::IDENTIFIER {
+                    token.text_range()
+                } else {
+                    empty_range
+                };
+                return Some(CompletionContext::PropertyValue {
+                    property: property.text().to_string(),
+                    range,
+                });
+            }
+            SyntaxKind::DECLARATION_CUSTOM_PROPERTY => return None,
+            SyntaxKind::DECLARATIONS => {
+                // `{`, `;` and the `}` of a nested block all precede a new declaration
+                let at_declaration_start = match token.kind() {
+                    SyntaxKind::L_CURLY | SyntaxKind::SEMICOLON => {
+                        token.parent().as_ref() == Some(&node)
+                    }
+                    SyntaxKind::R_CURLY => token.parent().as_ref() != Some(&node),
+                    _ => false,
+                };
+                if at_declaration_start && is_declaration_block(&node) {
+                    return Some(CompletionContext::Property {
+                        range: empty_range,
+                        has_colon: false,
+                    });
+                }
+                return None;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod completion_test {
+    use lsp_types::{
+        CompletionItem, CompletionItemKind, CompletionItemTag, CompletionResponse,
+        CompletionTextEdit, Url,
+    };
+
+    use crate::{
+        config::PositionEncoding,
+        css_language_types::CompletionSettings,
+        interop::csslancer_to_client,
+        services::CssLancerServer,
+        workspace::source::Source,
+    };
+
+    fn completions(value: &str, settings: CompletionSettings) -> Vec<CompletionItem> {
+        let offset = value
+            .find('|')
+            .expect("internal error in test: `value` must contain '|' char");
+        let value = value[0..offset].to_owned() + &value[offset + 1..];
+
+        let ls = CssLancerServer::new_test();
+        let position_encoding = PositionEncoding::Utf16;
+        ls.client_supports_markdown.set(true).unwrap();
+
+        let source = Source::new(Url::parse("test://foo/bar.css").unwrap(), &value, 0);
+        let position = csslancer_to_client::offset_to_position(offset, position_encoding, &source);
+        match ls.get_completion(&source, position, &settings).unwrap() {
+            Some(CompletionResponse::List(list)) => list.items,
+            Some(CompletionResponse::Array(items)) => items,
+            None => Vec::new(),
+        }
+    }
+
+    fn default_settings() -> CompletionSettings {
+        CompletionSettings {
+            trigger_property_value_completion: true,
+            complete_property_with_semicolon: true,
+        }
+    }
+
+    fn find<'a>(items: &'a [CompletionItem], label: &str) -> Option<&'a CompletionItem> {
+        items.iter().find(|i| i.label == label)
+    }
+
+    fn new_text(item: &CompletionItem) -> &str {
+        match item.text_edit.as_ref().unwrap() {
+            CompletionTextEdit::Edit(edit) => &edit.new_text,
+            CompletionTextEdit::InsertAndReplace(edit) => &edit.new_text,
+        }
+    }
+
+    #[test]
+    fn properties() {
+        let items = completions(".a { col| }", default_settings());
+        let color = find(&items, "color").expect("color proposal");
+        assert_eq!(new_text(color), "color: $0;");
+        assert!(color.command.is_some());
+        assert!(find(&items, "display").is_some());
+
+        let items = completions(
+            ".a { color: red; | }",
+            CompletionSettings {
+                trigger_property_value_completion: false,
+                complete_property_with_semicolon: false,
+            },
+        );
+        let color = find(&items, "color").expect("color proposal");
+        assert_eq!(new_text(color), "color: ");
+        assert!(color.command.is_none());
+
+        let items = completions(".a { col|: red }", default_settings());
+        assert_eq!(new_text(find(&items, "color").unwrap()), "color");
+
+        assert!(completions(".a { color |}", default_settings()).is_empty());
+    }
+
+    #[test]
+    fn properties_in_at_rules() {
+        for value in [
+            "@position-try --top { | }",
+            "@position-try --top { top: 0; | }",
+            ".a { @starting-style { | } }",
+        ] {
+            let items = completions(value, default_settings());
+            assert!(find(&items, "color").is_some(), "no property proposals in `{value}`");
+        }
+        for value in ["@starting-style { | }", "@view-transition { | }"] {
+            let items = completions(value, default_settings());
+            assert!(
+                find(&items, "color").is_none(),
+                "property proposals in `{value}`"
+            );
+        }
+    }
+
+    #[test]
+    fn property_values() {
+        let items = completions(".a { display: | }", default_settings());
+        assert!(find(&items, "flex").is_some());
+        assert!(find(&items, "inherit").is_some());
+        assert!(find(&items, "color").is_none());
+
+        let items = completions(".a { display: bl| }", default_settings());
+        assert!(find(&items, "block").is_some());
+    }
+
+    #[test]
+    fn color_values() {
+        let items = completions(".a { color: | }", default_settings());
+        let red = find(&items, "red").expect("named color proposal");
+        assert_eq!(red.kind, Some(CompletionItemKind::COLOR));
+        assert!(find(&items, "currentcolor").is_some());
+        assert_eq!(
+            new_text(find(&items, "rgb()").unwrap()),
+            "rgb(${1:red} ${2:green} ${3:blue})"
+        );
+        assert!(find(&items, "oklch()").is_some());
+
+        // `<color>` reached through the syntax of a shorthand
+        let items = completions(".a { border: 1px solid | }", default_settings());
+        assert!(find(&items, "rebeccapurple").is_some());
+    }
+
+    #[test]
+    fn value_sorting() {
+        let items = completions(".a { display: | }", default_settings());
+        let flex = find(&items, "flex").unwrap();
+        let inherit = find(&items, "inherit").unwrap();
+        assert!(flex.sort_text < inherit.sort_text);
+
+        let items = completions(".a { grid| }", default_settings());
+        assert_eq!(
+            find(&items, "grid-gap").unwrap().tags,
+            Some(vec![CompletionItemTag::DEPRECATED])
+        );
+        assert_eq!(find(&items, "gap").unwrap().tags, None);
+    }
+
+    #[test]
+    fn at_rules() {
+        let items = completions("@|", default_settings());
+        assert!(find(&items, "@media").is_some());
+        let items = completions("@med|", default_settings());
+        assert_eq!(new_text(find(&items, "@media").unwrap()), "@media");
+    }
+
+    #[test]
+    fn pseudo_selectors() {
+        let items = completions(".a:| {}", default_settings());
+        assert!(find(&items, ":hover").is_some());
+        assert!(find(&items, "::after").is_some());
+        assert_eq!(new_text(find(&items, ":not()").unwrap()), ":not($1)");
+
+        let items = completions(".a::| {}", default_settings());
+        assert!(find(&items, ":hover").is_none());
+        assert!(find(&items, "::after").is_some());
+    }
+}
@@ -1,46 +1,102 @@
 use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
-use crate::{interop::csslancer_to_client::offset_to_position, row_parser::syntax_error::SyntaxError};
-use crate::workspace::FsError;
+use crate::css_language_types::LintSettings;
+use crate::{interop::csslancer_to_client::text_range, row_parser::syntax_error::SyntaxError};
+use crate::workspace::{source::Source, FsError, Workspace};
 use itertools::Itertools;
-use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range, Url};
-use rowan::TextSize;
-use tracing::trace;
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, DocumentDiagnosticReport, FullDocumentDiagnosticReport,
+    NumberOrString, PreviousResultId, RelatedFullDocumentDiagnosticReport,
+    RelatedUnchangedDocumentDiagnosticReport, UnchangedDocumentDiagnosticReport, Url,
+    WorkspaceDiagnosticReport, WorkspaceDocumentDiagnosticReport,
+    WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use rowan::{TextRange, TextSize};
+use tracing::{error, trace};
 
-use super::CssLancerServer;
+use super::{lint, CssLancerServer};
 
 pub type DiagnosticsMap = HashMap<Url, Vec<Diagnostic>>;
 
+/// Diagnostics of a single file, or only the id of the previous result when nothing changed
+enum Report {
+    Full { result_id: String, items: Vec<Diagnostic> },
+    Unchanged { result_id: String },
+}
+
 impl CssLancerServer {
-    #[tracing::instrument(skip(self))]
-    pub async fn publish_diags(&self, url: &Url) -> Result<(), FsError> {
-        let diags: Vec<Diagnostic> = {
-            let src = self.source_read(url).await?;
+    /// Whether the client pulls diagnostics (`textDocument/diagnostic`) instead of
+    /// waiting for them to be pushed (`textDocument/publishDiagnostics`)
+    pub fn supports_pull_diagnostics(&self) -> bool {
+        self.client_capabilities
+            .get()
+            .and_then(|capabilities| capabilities.text_document.as_ref())
+            .is_some_and(|text_document| text_document.diagnostic.is_some())
+    }
 
-            let errors = src.parse.errors();
+    fn supports_diagnostics_refresh(&self) -> bool {
+        self.client_capabilities
+            .get()
+            .and_then(|capabilities| capabilities.workspace.as_ref())
+            .and_then(|workspace| workspace.diagnostic.as_ref())
+            .and_then(|diagnostic| diagnostic.refresh_support)
+            .unwrap_or(false)
+    }
 
-            let to_diagnostic = |se: &SyntaxError| -> Diagnostic {
-                let (start, end) = (se.range().start().into(), se.range().end().into());
-                let range = Range::new(
-                    offset_to_position(start, self.const_config().position_encoding, &src),
-                    offset_to_position(end, self.const_config().position_encoding, &src),
-                );
-                Diagnostic::new(
-                    range,
-                    Some(DiagnosticSeverity::WARNING),
-                    Some(NumberOrString::String(se.to_string())),
+    /// Syntax errors, with the id of their parse error as code, and the problems of all
+    /// lint rules that are not ignored in `lint`, sorted by position
+    pub fn get_diagnostics(&self, src: &Source, lint: &LintSettings) -> Vec<Diagnostic> {
+        let position_encoding = self.const_config().position_encoding;
+        let to_range = |range: TextRange| text_range(range, src, position_encoding);
+
+        let syntax_errors = src.parse.errors().into_iter().map(|se: SyntaxError| {
+            let code = se
+                .parse_error()
+                .map(|err| NumberOrString::String(err.issue().name.to_owned()));
+            let diagnostic = Diagnostic::new(
+                to_range(se.range()),
+                Some(DiagnosticSeverity::ERROR),
+                code,
+                Some("csslancer".to_owned()),
+                se.to_string(),
+                None,
+                None,
+            );
+            (se.range().start(), diagnostic)
+        });
+
+        let lint_problems = lint::lint(&src.parse.tree().syntax, lint, &self.css_data_manager)
+            .into_iter()
+            .map(|problem| {
+                let diagnostic = Diagnostic::new(
+                    to_range(problem.range),
+                    problem.level.severity(),
+                    Some(NumberOrString::String(problem.rule.id.to_owned())),
                     Some("csslancer".to_owned()),
-                    se.to_string(),
+                    problem.message,
                     None,
                     None,
-                )
-            };
-
-            errors
-                .into_iter()
-                .sorted_unstable_by_key(|se| <TextSize as Into<u32>>::into(se.range().start()))
-                .map(|e| to_diagnostic(&e))
-                .collect()
+                );
+                (problem.range.start(), diagnostic)
+            });
+
+        syntax_errors
+            .chain(lint_problems)
+            .sorted_by_key(|(start, _)| <TextSize as Into<u32>>::into(*start))
+            .map(|(_, diagnostic)| diagnostic)
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn publish_diags(&self, url: &Url) -> Result<(), FsError> {
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+        let diags: Vec<Diagnostic> = {
+            let lint = &self.config.read().await.lint;
+            let src = self.source_read(url).await?;
+            self.get_diagnostics(&src, lint)
         };
         let ds = format!("{diags:?}");
         trace!(name: "MARKERS: ", diags_len = diags.len(), diags = ds );
@@ -50,4 +106,229 @@ impl CssLancerServer {
 
         Ok(())
     }
+
+    /// Brings the client's diagnostics up to date after files other than the edited one
+    /// changed: asks the client to pull again, or pushes those of all open documents
+    pub async fn refresh_diags(&self) {
+        if self.supports_pull_diagnostics() {
+            if !self.supports_diagnostics_refresh() {
+                return;
+            }
+            if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+                error!(%err, "could not refresh diagnostics");
+            }
+            return;
+        }
+
+        let open_files = self
+            .workspace_read()
+            .await
+            .open_files()
+            .cloned()
+            .collect::<Vec<_>>();
+        for url in open_files {
+            if let Err(err) = self.publish_diags(&url).await {
+                error!(%err, %url, "could not publish diagnostics");
+            }
+        }
+    }
+
+    pub fn get_document_diagnostic_report(
+        &self,
+        src: &Source,
+        lint: &LintSettings,
+        previous_result_id: Option<&str>,
+    ) -> DocumentDiagnosticReport {
+        match self.report(src, lint, previous_result_id) {
+            Report::Full { result_id, items } => {
+                DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+                    related_documents: None,
+                    full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                        result_id: Some(result_id),
+                        items,
+                    },
+                })
+            }
+            Report::Unchanged { result_id } => {
+                DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                    related_documents: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                })
+            }
+        }
+    }
+
+    /// Reports of all files in `workspace`, sorted by url
+    pub fn get_workspace_diagnostic_report(
+        &self,
+        workspace: &Workspace,
+        lint: &LintSettings,
+        previous_result_ids: &[PreviousResultId],
+    ) -> WorkspaceDiagnosticReport {
+        let previous_result_ids = previous_result_ids
+            .iter()
+            .map(|previous| (&previous.uri, previous.value.as_str()))
+            .collect::<HashMap<_, _>>();
+
+        let items = workspace
+            .files
+            .values()
+            .sorted_unstable_by(|a, b| a.url.cmp(&b.url))
+            .map(|src| {
+                let uri = src.url.clone();
+                let version = workspace.is_open(&uri).then_some(i64::from(src.version));
+                match self.report(src, lint, previous_result_ids.get(&uri).copied()) {
+                    Report::Full { result_id, items } => {
+                        WorkspaceDocumentDiagnosticReport::Full(
+                            WorkspaceFullDocumentDiagnosticReport {
+                                uri,
+                                version,
+                                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                                    result_id: Some(result_id),
+                                    items,
+                                },
+                            },
+                        )
+                    }
+                    Report::Unchanged { result_id } => {
+                        WorkspaceDocumentDiagnosticReport::Unchanged(
+                            WorkspaceUnchangedDocumentDiagnosticReport {
+                                uri,
+                                version,
+                                unchanged_document_diagnostic_report:
+                                    UnchangedDocumentDiagnosticReport { result_id },
+                            },
+                        )
+                    }
+                }
+            })
+            .collect();
+        WorkspaceDiagnosticReport { items }
+    }
+
+    /// The result id is a hash of the diagnostics themselves, so it stays the same across
+    /// edits and re-indexing that do not change them
+    fn report(
+        &self,
+        src: &Source,
+        lint: &LintSettings,
+        previous_result_id: Option<&str>,
+    ) -> Report {
+        let items = self.get_diagnostics(src, lint);
+        let mut hasher = DefaultHasher::new();
+        serde_json::to_string(&items)
+            .expect("diagnostics should be representable as JSON")
+            .hash(&mut hasher);
+        let result_id = format!("{:016x}", hasher.finish());
+
+        if previous_result_id == Some(result_id.as_str()) {
+            Report::Unchanged { result_id }
+        } else {
+            Report::Full { result_id, items }
+        }
+    }
+}
+
+#[cfg(test)]
+mod css_validation_test {
+    use lsp_types::{
+        DiagnosticSeverity, DocumentDiagnosticReport, NumberOrString, PreviousResultId, Url,
+        WorkspaceDocumentDiagnosticReport,
+    };
+
+    use crate::{
+        css_language_types::LintSettings,
+        services::CssLancerServer,
+        workspace::{source::Source, Workspace},
+    };
+
+    #[test]
+    fn document_report() {
+        let ls = CssLancerServer::new_test();
+        let lint = LintSettings::new();
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let broken = Source::new(url.clone(), ".a { color: red; ", 0);
+
+        let DocumentDiagnosticReport::Full(full) = ls.get_document_diagnostic_report(&broken, &lint, None)
+        else {
+            panic!("first report should be full");
+        };
+        let full = full.full_document_diagnostic_report;
+        assert!(!full.items.is_empty());
+        let result_id = full.result_id.unwrap();
+
+        let again = ls.get_document_diagnostic_report(&broken, &lint, Some(&result_id));
+        assert!(matches!(again, DocumentDiagnosticReport::Unchanged(unchanged)
+            if unchanged.unchanged_document_diagnostic_report.result_id == result_id));
+
+        let fixed = Source::new(url, ".a { color: red; }", 1);
+        let DocumentDiagnosticReport::Full(full) =
+            ls.get_document_diagnostic_report(&fixed, &lint, Some(&result_id))
+        else {
+            panic!("report of changed diagnostics should be full");
+        };
+        assert!(full.full_document_diagnostic_report.items.is_empty());
+    }
+
+    #[test]
+    fn workspace_report() {
+        let ls = CssLancerServer::new_test();
+        let lint = LintSettings::new();
+        let mut workspace = Workspace::new(Vec::new());
+        let open = Url::parse("test://foo/a.css").unwrap();
+        let on_disk = Url::parse("test://foo/b.css").unwrap();
+        workspace.open(open.clone(), Source::new(open.clone(), ".a {", 3));
+        workspace.register(Source::new(on_disk.clone(), ".b {}", 0));
+
+        let report = ls.get_workspace_diagnostic_report(&workspace, &lint, &[]);
+        let previous_result_ids = report
+            .items
+            .iter()
+            .map(|item| match item {
+                WorkspaceDocumentDiagnosticReport::Full(full) => {
+                    assert_eq!(
+                        full.version,
+                        if full.uri == open { Some(3) } else { None }
+                    );
+                    PreviousResultId {
+                        uri: full.uri.clone(),
+                        value: full
+                            .full_document_diagnostic_report
+                            .result_id
+                            .clone()
+                            .unwrap(),
+                    }
+                }
+                WorkspaceDocumentDiagnosticReport::Unchanged(_) => {
+                    panic!("first report should be full")
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(previous_result_ids.len(), 2);
+
+        let report = ls.get_workspace_diagnostic_report(&workspace, &lint, &previous_result_ids);
+        assert!(report
+            .items
+            .iter()
+            .all(|item| matches!(item, WorkspaceDocumentDiagnosticReport::Unchanged(_))));
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let ls = CssLancerServer::new_test();
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let src = Source::new(url, "@layer base\n.a { color: red; }", 0);
+        let errors = ls
+            .get_diagnostics(&src, &LintSettings::new())
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity == Some(DiagnosticSeverity::ERROR))
+            .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].code,
+            Some(NumberOrString::String("css-semicolonexpected".to_owned()))
+        );
+    }
 }
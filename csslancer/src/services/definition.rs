@@ -0,0 +1,405 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use lsp_types::{GotoDefinitionResponse, Location, LocationLink, Position, Url};
+use rowan::{TextRange, TextSize, WalkEvent};
+
+use super::document_links::{link_reference, resolve_reference};
+use super::CssLancerServer;
+use crate::css_language_types::{AliasSettings, FileStat, FileSystemProvider, FileType};
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::interop::csslancer_to_client::text_range;
+use crate::row_parser::nodes_types::{SyntaxNode, SyntaxToken};
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::{source::Source, Workspace};
+
+/// What a name refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Class,
+    Id,
+    CustomProperty,
+    Keyframes,
+    Layer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    pub name: String,
+}
+
+/// A name in a stylesheet that refers to a symbol
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+    pub symbol: Symbol,
+    /// Range of the name
+    pub range: TextRange,
+    /// The node that defines the symbol, when this occurrence is a definition
+    pub definition: Option<SyntaxNode>,
+}
+
+/// The occurrence of a symbol whose name contains `token`: a class or id selector, a
+/// custom property, the name of `@keyframes` or an animation referring to it, or a layer
+/// name
+pub fn occurrence(token: &SyntaxToken) -> Option<Occurrence> {
+    let parent = token.parent()?;
+    if token.kind() == SyntaxKind::ID_HASH && parent.kind() == SyntaxKind::SELECTOR_IDENTIFIER {
+        let range = token.text_range();
+        return Some(Occurrence {
+            symbol: Symbol {
+                kind: SymbolKind::Id,
+                name: token.text()[1..].to_owned(),
+            },
+            range: TextRange::new(range.start() + TextSize::from(1), range.end()),
+            definition: None,
+        });
+    }
+    if token.kind() != SyntaxKind::IDENTIFIER {
+        return None;
+    }
+    let name = token.text();
+    if name.starts_with("--") {
+        let definition = parent
+            .parent()
+            .filter(|_| parent.kind() == SyntaxKind::PROPERTY)
+            .filter(|declaration| declaration.kind() == SyntaxKind::DECLARATION_CUSTOM_PROPERTY);
+        return Some(Occurrence {
+            symbol: Symbol {
+                kind: SymbolKind::CustomProperty,
+                name: name.to_owned(),
+            },
+            range: token.text_range(),
+            definition,
+        });
+    }
+    match parent.kind() {
+        SyntaxKind::SELECTOR_CLASS => Some(Occurrence {
+            symbol: Symbol {
+                kind: SymbolKind::Class,
+                name: name.to_owned(),
+            },
+            range: token.text_range(),
+            definition: None,
+        }),
+        SyntaxKind::KEYFRAME => Some(Occurrence {
+            symbol: Symbol {
+                kind: SymbolKind::Keyframes,
+                name: name.to_owned(),
+            },
+            range: token.text_range(),
+            definition: Some(parent),
+        }),
+        SyntaxKind::LAYER_NAME => Some(Occurrence {
+            symbol: Symbol {
+                kind: SymbolKind::Layer,
+                name: parent.text().to_string(),
+            },
+            range: parent.text_range(),
+            definition: Some(parent),
+        }),
+        SyntaxKind::TERM if is_animation_value(&parent) => Some(Occurrence {
+            symbol: Symbol {
+                kind: SymbolKind::Keyframes,
+                name: name.to_owned(),
+            },
+            range: token.text_range(),
+            definition: None,
+        }),
+        _ => None,
+    }
+}
+
+/// Whether `term` is a part of the value of `animation` or `animation-name`
+fn is_animation_value(term: &SyntaxNode) -> bool {
+    let Some(declaration) = term
+        .ancestors()
+        .find(|a| a.kind() == SyntaxKind::DECLARATION_BASIC)
+    else {
+        return false;
+    };
+    // not within a function like `steps()`
+    if term
+        .ancestors()
+        .take_while(|a| a != &declaration)
+        .any(|a| a.kind() == SyntaxKind::FUNCTION_WITH_ARGS)
+    {
+        return false;
+    }
+    let property = declaration
+        .children()
+        .find(|c| c.kind() == SyntaxKind::PROPERTY)
+        .map(|p| p.text().to_string().to_lowercase())
+        .unwrap_or_default();
+    let property = match property.strip_prefix('-') {
+        Some(prefixed) => prefixed
+            .split_once('-')
+            .map_or(property.as_str(), |(_, p)| p),
+        None => property.as_str(),
+    };
+    matches!(property, "animation" | "animation-name")
+}
+
+/// The occurrence of a symbol at `offset`, preferring the one that ends there
+pub fn occurrence_at(root: &SyntaxNode, offset: TextSize) -> Option<Occurrence> {
+    let tokens = root.token_at_offset(offset);
+    let (left, right) = match tokens {
+        rowan::TokenAtOffset::None => return None,
+        rowan::TokenAtOffset::Single(token) => (Some(token), None),
+        rowan::TokenAtOffset::Between(left, right) => (Some(left), Some(right)),
+    };
+    left.and_then(|t| occurrence(&t))
+        .or_else(|| right.and_then(|t| occurrence(&t)))
+}
+
+/// The stylesheets of a workspace as a file system, in which folders are the ones they
+/// are in. Nothing else exists, not even the `package.json` of a package.
+struct WorkspaceFs<'a>(&'a Workspace);
+
+#[async_trait]
+impl FileSystemProvider for WorkspaceFs<'_> {
+    async fn stat(&self, uri: &Url) -> Option<FileStat> {
+        let files = &self.0.files;
+        let typ = if files.contains_key(uri) {
+            FileType::File
+        } else if uri.path().ends_with('/')
+            && files
+                .keys()
+                .any(|url| url.as_str().starts_with(uri.as_str()))
+        {
+            FileType::Directory
+        } else {
+            return None;
+        };
+        Some(FileStat {
+            typ,
+            ctime: 0,
+            mtime: 0,
+            size: 0,
+        })
+    }
+
+    async fn read_directory(&self, _uri: &Url) -> Vec<(String, FileType)> {
+        Vec::new()
+    }
+
+    async fn get_content(&self, uri: &Url) -> Option<String> {
+        self.0.files.get(uri).map(|src| src.text().to_string())
+    }
+}
+
+/// The url an `@import` rule in `document` refers to, resolved like its document link
+/// but among the stylesheets of `workspace`
+pub fn import_target(
+    workspace: &Workspace,
+    import: &SyntaxNode,
+    document: &Url,
+    alias: &AliasSettings,
+) -> Option<Url> {
+    let (reference, _) = link_reference(import)?;
+    let fs = WorkspaceFs(workspace);
+    // the workspace answers right away, so this never waits
+    futures::executor::block_on(resolve_reference(
+        &reference,
+        document,
+        true,
+        workspace.roots(),
+        alias,
+        &fs,
+    ))
+}
+
+/// Calls `visit` with every token of `src` and, at each `@import`, of the stylesheet it
+/// imports if the workspace has it, so in the order the browser would see them. Every
+/// stylesheet is visited once.
+pub fn walk_imports<'a>(
+    workspace: &'a Workspace,
+    src: &'a Source,
+    alias: &AliasSettings,
+    visit: &mut impl FnMut(&'a Source, SyntaxToken),
+) {
+    let mut visited = HashSet::new();
+    walk(workspace, src, alias, &mut visited, visit);
+}
+
+fn walk<'a>(
+    workspace: &'a Workspace,
+    src: &'a Source,
+    alias: &AliasSettings,
+    visited: &mut HashSet<&'a Url>,
+    visit: &mut impl FnMut(&'a Source, SyntaxToken),
+) {
+    if !visited.insert(&src.url) {
+        return;
+    }
+    for event in src.parse.tree().syntax.preorder_with_tokens() {
+        match event {
+            WalkEvent::Enter(rowan::NodeOrToken::Token(token)) => visit(src, token),
+            WalkEvent::Leave(rowan::NodeOrToken::Node(node))
+                if node.kind() == SyntaxKind::IMPORT =>
+            {
+                let imported = import_target(workspace, &node, &src.url, alias)
+                    .and_then(|url| workspace.get_document_ref(&url).ok());
+                if let Some(imported) = imported {
+                    walk(workspace, imported, alias, visited, visit);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl CssLancerServer {
+    fn supports_definition_links(&self) -> bool {
+        self.client_capabilities
+            .get()
+            .and_then(|capabilities| capabilities.text_document.as_ref())
+            .and_then(|text_document| text_document.definition.as_ref())
+            .and_then(|definition| definition.link_support)
+            .unwrap_or(false)
+    }
+
+    /// Where the symbol at `position` is defined in `src` or the stylesheets it imports:
+    /// every declaration of a custom property, every `@keyframes` of an animation name,
+    /// and the first declaration of a layer
+    pub fn get_definition(
+        &self,
+        workspace: &Workspace,
+        src: &Source,
+        position: Position,
+        alias: &AliasSettings,
+    ) -> Option<GotoDefinitionResponse> {
+        let encoding = self.const_config().position_encoding;
+        let offset = position_to_offset(position, encoding, src);
+        let origin = occurrence_at(&src.parse.tree().syntax, TextSize::from(offset as u32))?;
+
+        let mut definitions = Vec::new();
+        walk_imports(workspace, src, alias, &mut |source, token| {
+            let Some(found) = occurrence(&token) else {
+                return;
+            };
+            if let (Some(definition), true) = (found.definition, found.symbol == origin.symbol) {
+                definitions.push((source, definition, found.range));
+            }
+        });
+        if origin.symbol.kind == SymbolKind::Layer {
+            definitions.truncate(1);
+        }
+        if definitions.is_empty() {
+            return None;
+        }
+
+        let to_range = |range: TextRange, source: &Source| text_range(range, source, encoding);
+        if self.supports_definition_links() {
+            let origin_range = to_range(origin.range, src);
+            let links = definitions
+                .into_iter()
+                .map(|(source, definition, name)| LocationLink {
+                    origin_selection_range: Some(origin_range),
+                    target_uri: source.url.clone(),
+                    target_range: to_range(definition.text_range(), source),
+                    target_selection_range: to_range(name, source),
+                })
+                .collect();
+            return Some(GotoDefinitionResponse::Link(links));
+        }
+        let locations = definitions
+            .into_iter()
+            .map(|(source, _, name)| Location::new(source.url.clone(), to_range(name, source)))
+            .collect();
+        Some(GotoDefinitionResponse::Array(locations))
+    }
+}
+
+#[cfg(test)]
+mod definition_test {
+    use lsp_types::{GotoDefinitionResponse, Location, Position, Range, Url};
+
+    use crate::css_language_types::AliasSettings;
+    use crate::services::CssLancerServer;
+    use crate::workspace::{source::Source, Workspace};
+
+    const FILES: &[(&str, &str)] = &[
+        (
+            "test://foo/main.css",
+            "@import \"theme.css\";\n@layer base;\n.a { color: var(--x); animation: 1s fade; }\n@layer base { .b {} }",
+        ),
+        (
+            "test://foo/theme.css",
+            "@import url(main.css);\n:root { --x: red; }\n@keyframes fade {}\n@layer base;",
+        ),
+        ("test://foo/other.css", ".c { --x: blue; }"),
+    ];
+
+    fn definition(workspace: &Workspace, position: Position) -> Option<Vec<Location>> {
+        let alias = AliasSettings::from([("@".to_owned(), "./styles".to_owned())]);
+        let url = Url::parse("test://foo/main.css").unwrap();
+        let src = workspace.get_document_ref(&url).unwrap();
+        match CssLancerServer::new_test().get_definition(workspace, src, position, &alias)? {
+            GotoDefinitionResponse::Array(locations) => Some(locations),
+            response => panic!("expected locations, got {response:?}"),
+        }
+    }
+
+    fn location(url: &str, line: u32, start: u32, end: u32) -> Location {
+        Location::new(
+            Url::parse(url).unwrap(),
+            Range::new(Position::new(line, start), Position::new(line, end)),
+        )
+    }
+
+    #[test]
+    fn custom_properties() {
+        let workspace = Workspace::new_test(FILES);
+        assert_eq!(
+            definition(&workspace, Position::new(2, 18)),
+            Some(vec![location("test://foo/theme.css", 1, 8, 11)])
+        );
+    }
+
+    #[test]
+    fn keyframes() {
+        let workspace = Workspace::new_test(FILES);
+        assert_eq!(
+            definition(&workspace, Position::new(2, 37)),
+            Some(vec![location("test://foo/theme.css", 2, 11, 15)])
+        );
+        assert_eq!(definition(&workspace, Position::new(2, 33)), None);
+    }
+
+    #[test]
+    fn layers() {
+        let workspace = Workspace::new_test(FILES);
+        // the declaration in the imported stylesheet comes first
+        assert_eq!(
+            definition(&workspace, Position::new(3, 9)),
+            Some(vec![location("test://foo/theme.css", 3, 7, 11)])
+        );
+    }
+
+    #[test]
+    fn aliased_and_package_imports() {
+        let mut workspace = Workspace::new(vec![Url::parse("test://foo/").unwrap()]);
+        let files = [
+            (
+                "test://foo/main.css",
+                "@import \"@/theme.css\";\n@import \"pkg/fade.css\";\n.a { color: var(--x); animation: 1s fade; }",
+            ),
+            ("test://foo/styles/theme.css", ":root { --x: red; }"),
+            ("test://foo/node_modules/pkg/fade.css", "@keyframes fade {}"),
+        ];
+        for (url, text) in files {
+            let url = Url::parse(url).unwrap();
+            workspace.register(Source::new(url.clone(), text, 0));
+        }
+        assert_eq!(
+            definition(&workspace, Position::new(2, 18)),
+            Some(vec![location("test://foo/styles/theme.css", 0, 8, 11)])
+        );
+        let package = "test://foo/node_modules/pkg/fade.css";
+        assert_eq!(
+            definition(&workspace, Position::new(2, 37)),
+            Some(vec![location(package, 0, 11, 15)])
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use lsp_types::{ColorInformation, ColorPresentation, Range, TextEdit};
+
+use super::color::{color_of, Color, Notation};
+use super::CssLancerServer;
+use crate::interop::csslancer_to_client::text_range;
+use crate::workspace::source::Source;
+
+impl CssLancerServer {
+    /// Every color written in `src`, with colors out of the sRGB gamut clipped to it
+    pub fn get_document_colors(&self, src: &Source) -> Vec<ColorInformation> {
+        let encoding = self.const_config().position_encoding;
+        src.parse
+            .tree()
+            .syntax
+            .descendants()
+            .filter_map(|node| {
+                let color = color_of(&node)?;
+                let range = node.text_range();
+                Some(ColorInformation {
+                    range: text_range(range, src, encoding),
+                    color: lsp_types::Color {
+                        red: color.red.clamp(0.0, 1.0) as f32,
+                        green: color.green.clamp(0.0, 1.0) as f32,
+                        blue: color.blue.clamp(0.0, 1.0) as f32,
+                        alpha: color.alpha as f32,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// `color` written in every notation, replacing the text at `range`
+    pub fn get_color_presentations(
+        &self,
+        color: lsp_types::Color,
+        range: Range,
+    ) -> Vec<ColorPresentation> {
+        let color = Color::new(
+            color.red.into(),
+            color.green.into(),
+            color.blue.into(),
+            color.alpha.into(),
+        );
+        Notation::ALL
+            .into_iter()
+            .filter_map(|notation| color.serialize(notation))
+            .map(|label| ColorPresentation {
+                text_edit: Some(TextEdit::new(range, label.clone())),
+                label,
+                additional_text_edits: None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod document_colors_test {
+    use lsp_types::{Position, Range, Url};
+
+    use crate::services::CssLancerServer;
+    use crate::workspace::source::Source;
+
+    #[test]
+    fn document_colors() {
+        let src = Source::new(
+            Url::parse("test://foo/a.css").unwrap(),
+            ".a { color: #f00; border: 1px solid Blue; background: color-mix(in srgb, hsl(120 100% 25%) 50%, white); --c: rgb(0 0 0 / 50%); width: 1px }",
+            0,
+        );
+        let colors = CssLancerServer::new_test()
+            .get_document_colors(&src)
+            .into_iter()
+            .map(|c| {
+                (
+                    c.range.start.character,
+                    c.range.end.character,
+                    [c.color.red, c.color.green, c.color.blue, c.color.alpha]
+                        .map(|c| (c * 255.0).round() as u8),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            colors,
+            vec![
+                (12, 16, [255, 0, 0, 255]),
+                (36, 40, [0, 0, 255, 255]),
+                (73, 90, [0, 128, 0, 255]),
+                (96, 101, [255, 255, 255, 255]),
+                (109, 125, [0, 0, 0, 128]),
+            ]
+        );
+    }
+
+    #[test]
+    fn color_presentations() {
+        let range = Range::new(Position::new(0, 12), Position::new(0, 16));
+        let color = lsp_types::Color {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        let presentations = CssLancerServer::new_test().get_color_presentations(color, range);
+        let labels = presentations
+            .iter()
+            .map(|p| p.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            &labels[..4],
+            ["red", "#ff0000", "rgb(255 0 0)", "hsl(0 100% 50%)"]
+        );
+        assert_eq!(labels.len(), 10);
+        assert!(presentations
+            .iter()
+            .all(|p| p.text_edit.as_ref().is_some_and(|edit| edit.range == range)));
+    }
+}
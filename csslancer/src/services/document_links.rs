@@ -0,0 +1,378 @@
+use lsp_types::{DocumentLink, Range, Url};
+use rowan::{NodeOrToken, TextRange, TextSize};
+
+use super::CssLancerServer;
+use crate::css_language_types::{AliasSettings, FileSystemProvider, FileType};
+use crate::interop::csslancer_to_client::text_range;
+use crate::row_parser::nodes_types::{SyntaxNode, SyntaxToken};
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+/// A path written in a document, to link to what it points to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkReference {
+    pub reference: String,
+    pub range: Range,
+    /// Whether the path is the one of an `@import`, which may name a package
+    pub is_import: bool,
+}
+
+/// The path referred to by an `@import` or a `url()`, without quotes, and its range
+pub fn link_reference(node: &SyntaxNode) -> Option<(String, TextRange)> {
+    match node.kind() {
+        SyntaxKind::IMPORT => node.children_with_tokens().find_map(|child| match child {
+            NodeOrToken::Token(token) if token.kind() == SyntaxKind::STRING => {
+                string_reference(&token)
+            }
+            NodeOrToken::Node(node) if node.kind() == SyntaxKind::URI_LITERAL => {
+                link_reference(&node)
+            }
+            _ => None,
+        }),
+        SyntaxKind::URI_LITERAL => {
+            let token = node
+                .children_with_tokens()
+                .filter_map(NodeOrToken::into_token)
+                .find(|t| matches!(t.kind(), SyntaxKind::STRING | SyntaxKind::URL))?;
+            if token.kind() == SyntaxKind::STRING {
+                return string_reference(&token);
+            }
+            // `url(` and `)` around the unquoted path
+            let inner = token.text().get(4..)?.strip_suffix(')')?;
+            let reference = inner.trim();
+            let start = token.text_range().start()
+                + TextSize::from(4)
+                + TextSize::of(&inner[..inner.len() - inner.trim_start().len()]);
+            Some((
+                reference.to_owned(),
+                TextRange::at(start, TextSize::of(reference)),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn string_reference(token: &SyntaxToken) -> Option<(String, TextRange)> {
+    let text = token.text();
+    let quote = text.chars().next()?;
+    let inner = text[1..].strip_suffix(quote).unwrap_or(&text[1..]);
+    let start = token.text_range().start() + TextSize::from(1);
+    Some((inner.to_owned(), TextRange::at(start, TextSize::of(inner))))
+}
+
+/// `url` with a trailing slash, so that relative references resolve inside it
+fn as_directory(url: &Url) -> Url {
+    let mut url = url.clone();
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+    url
+}
+
+/// The stylesheet of the package `package` that bundlers pick when importing it by name:
+/// its `style` field, its `main` field if that is a stylesheet, else `index.css`
+async fn package_stylesheet(package: &Url, fs: &dyn FileSystemProvider) -> Option<Url> {
+    let manifest = match fs.get_content(&package.join("package.json").ok()?).await {
+        Some(content) => serde_json::from_str::<serde_json::Value>(&content).ok(),
+        None => None,
+    };
+    let field = |name: &str| {
+        manifest
+            .as_ref()?
+            .get(name)?
+            .as_str()
+            .map(ToOwned::to_owned)
+    };
+    let entry = field("style")
+        .or_else(|| field("main").filter(|main| main.ends_with(".css")))
+        .unwrap_or_else(|| "index.css".to_owned());
+    package.join(&entry).ok()
+}
+
+/// Resolves `specifier`, like `pkg/theme.css` or `@scope/pkg`, to a package in the
+/// `node_modules` folder of the directory of `document` or of its closest ancestor that
+/// has the package
+async fn resolve_module(
+    specifier: &str,
+    document: &Url,
+    fs: &dyn FileSystemProvider,
+) -> Option<Url> {
+    let segments = if specifier.starts_with('@') { 3 } else { 2 };
+    let mut parts = specifier.splitn(segments, '/');
+    let name = match segments {
+        3 => format!("{}/{}", parts.next()?, parts.next()?),
+        _ => parts.next()?.to_owned(),
+    };
+    let subpath = parts.next().filter(|subpath| !subpath.is_empty());
+
+    let mut directory = document.join(".").ok()?;
+    loop {
+        let package = directory.join(&format!("node_modules/{name}/")).ok()?;
+        let stat = fs.stat(&package).await;
+        if stat.is_some_and(|stat| stat.typ == FileType::Directory) {
+            return match subpath {
+                Some(subpath) => package.join(subpath).ok(),
+                None => package_stylesheet(&package, fs).await,
+            };
+        }
+        let parent = directory.join("..").ok()?;
+        if parent == directory {
+            return None;
+        }
+        directory = parent;
+    }
+}
+
+/// Where `reference`, written in `document`, points to. Absolute URLs are kept as they
+/// are, except `data:` ones. References starting with an alias resolve against the
+/// workspace root of `document`, and ones starting with `~` or, for imports, bare
+/// specifiers that do not name a file next to `document`, to packages in `node_modules`.
+/// Other references are relative to `document`.
+pub async fn resolve_reference(
+    reference: &str,
+    document: &Url,
+    is_import: bool,
+    roots: &[Url],
+    alias: &AliasSettings,
+    fs: &dyn FileSystemProvider,
+) -> Option<Url> {
+    if reference.is_empty() || reference.starts_with('#') {
+        return None;
+    }
+    if let Ok(url) = Url::parse(reference) {
+        return (url.scheme() != "data").then_some(url);
+    }
+
+    let mut aliases = alias.iter().collect::<Vec<_>>();
+    aliases.sort_unstable_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+    for (prefix, path) in aliases {
+        let Some(rest) = reference.strip_prefix(prefix.as_str()) else {
+            continue;
+        };
+        if !rest.is_empty() && !rest.starts_with('/') {
+            continue;
+        }
+        let base = roots
+            .iter()
+            .map(as_directory)
+            .find(|root| document.as_str().starts_with(root.as_str()))
+            .unwrap_or_else(|| document.clone());
+        let target = format!("{}{rest}", path.trim_end_matches('/'));
+        return base.join(target.trim_start_matches("./")).ok();
+    }
+
+    if let Some(specifier) = reference.strip_prefix('~') {
+        return resolve_module(specifier, document, fs).await;
+    }
+    let relative = document.join(reference).ok()?;
+    let is_bare = !reference.starts_with(['.', '/']);
+    if is_import && is_bare && fs.stat(&relative).await.is_none() {
+        if let Some(module) = resolve_module(reference, document, fs).await {
+            return Some(module);
+        }
+    }
+    Some(relative)
+}
+
+/// Links to where every reference of `references` in `document` points to
+pub async fn resolve_links(
+    references: Vec<LinkReference>,
+    document: &Url,
+    roots: &[Url],
+    alias: &AliasSettings,
+    fs: &dyn FileSystemProvider,
+) -> Vec<DocumentLink> {
+    let mut links = Vec::new();
+    for LinkReference {
+        reference,
+        range,
+        is_import,
+    } in references
+    {
+        let target = resolve_reference(&reference, document, is_import, roots, alias, fs).await;
+        if let Some(target) = target {
+            links.push(DocumentLink {
+                range,
+                target: Some(target),
+                tooltip: None,
+                data: None,
+            });
+        }
+    }
+    links
+}
+
+impl CssLancerServer {
+    /// The paths of the `@import` rules and `url()` values of `src`
+    pub fn get_link_references(&self, src: &Source) -> Vec<LinkReference> {
+        let encoding = self.const_config().position_encoding;
+        src.parse
+            .tree()
+            .syntax
+            .descendants()
+            .filter(|node| match node.kind() {
+                SyntaxKind::IMPORT => true,
+                SyntaxKind::URI_LITERAL => node
+                    .parent()
+                    .is_none_or(|parent| parent.kind() != SyntaxKind::IMPORT),
+                _ => false,
+            })
+            .filter_map(|node| {
+                let (reference, range) = link_reference(&node)?;
+                Some(LinkReference {
+                    reference,
+                    range: text_range(range, src, encoding),
+                    is_import: node.kind() == SyntaxKind::IMPORT,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod document_links_test {
+    use std::collections::HashMap;
+
+    use async_trait::async_trait;
+    use lsp_types::Url;
+
+    use super::{resolve_links, resolve_reference};
+    use crate::css_language_types::{AliasSettings, FileStat, FileSystemProvider, FileType};
+    use crate::services::CssLancerServer;
+    use crate::workspace::source::Source;
+
+    /// Files by URL, with the directories they are in
+    struct MemoryFs(HashMap<&'static str, &'static str>);
+
+    #[async_trait]
+    impl FileSystemProvider for MemoryFs {
+        async fn stat(&self, uri: &Url) -> Option<FileStat> {
+            let uri = uri.as_str();
+            let typ = if self.0.contains_key(uri) {
+                FileType::File
+            } else if uri.ends_with('/') && self.0.keys().any(|file| file.starts_with(uri)) {
+                FileType::Directory
+            } else {
+                return None;
+            };
+            Some(FileStat {
+                typ,
+                ctime: 0,
+                mtime: 0,
+                size: 0,
+            })
+        }
+
+        async fn read_directory(&self, _uri: &Url) -> Vec<(String, FileType)> {
+            Vec::new()
+        }
+
+        async fn get_content(&self, uri: &Url) -> Option<String> {
+            self.0.get(uri.as_str()).map(|content| content.to_string())
+        }
+    }
+
+    fn fs() -> MemoryFs {
+        MemoryFs(HashMap::from([
+            ("file:///p/src/local/x.css", ""),
+            (
+                "file:///p/node_modules/pkg/package.json",
+                r#"{"style": "dist/pkg.css"}"#,
+            ),
+            ("file:///p/node_modules/pkg/dist/pkg.css", ""),
+            ("file:///p/node_modules/@scope/ui/index.css", ""),
+        ]))
+    }
+
+    async fn resolve(reference: &str, is_import: bool) -> Option<String> {
+        let document = Url::parse("file:///p/src/a/main.css").unwrap();
+        let roots = [Url::parse("file:///p").unwrap()];
+        let alias = AliasSettings::from([("@".to_owned(), "./src".to_owned())]);
+        let target =
+            resolve_reference(reference, &document, is_import, &roots, &alias, &fs()).await;
+        target.map(String::from)
+    }
+
+    #[tokio::test]
+    async fn resolution() {
+        let cases = [
+            ("b.css", true, Some("file:///p/src/a/b.css")),
+            ("../local/x.css", true, Some("file:///p/src/local/x.css")),
+            ("/img/x.png", false, Some("file:///img/x.png")),
+            (
+                "https://example.com/x.css",
+                true,
+                Some("https://example.com/x.css"),
+            ),
+            ("data:image/png;base64,AAAA", false, None),
+            ("#filter", false, None),
+            ("@/local/x.css", true, Some("file:///p/src/local/x.css")),
+            (
+                "@scope/ui",
+                true,
+                Some("file:///p/node_modules/@scope/ui/index.css"),
+            ),
+            ("pkg", true, Some("file:///p/node_modules/pkg/dist/pkg.css")),
+            (
+                "pkg/theme.css",
+                true,
+                Some("file:///p/node_modules/pkg/theme.css"),
+            ),
+            (
+                "pkg/theme.css",
+                false,
+                Some("file:///p/src/a/pkg/theme.css"),
+            ),
+            (
+                "~pkg/theme.css",
+                false,
+                Some("file:///p/node_modules/pkg/theme.css"),
+            ),
+            ("missing", true, Some("file:///p/src/a/missing")),
+        ];
+        for (reference, is_import, expected) in cases {
+            assert_eq!(
+                resolve(reference, is_import).await.as_deref(),
+                expected,
+                "{reference}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn links() {
+        let ls = CssLancerServer::new_test();
+        let url = Url::parse("file:///p/src/a/main.css").unwrap();
+        let src = Source::new(
+            url.clone(),
+            "@import 'pkg';\n@import url( b.css ) layer(x);\n.a { background: url(\"i.png\"), url(#f); }",
+            0,
+        );
+        let references = ls.get_link_references(&src);
+        let links = resolve_links(references, &url, &[], &AliasSettings::new(), &fs())
+            .await
+            .into_iter()
+            .map(|link| {
+                (
+                    link.range.start.line,
+                    link.range.start.character,
+                    link.range.end.character,
+                    link.target.unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            links,
+            vec![
+                (
+                    0,
+                    9,
+                    12,
+                    "file:///p/node_modules/pkg/dist/pkg.css".to_owned()
+                ),
+                (1, 13, 18, "file:///p/src/a/b.css".to_owned()),
+                (2, 22, 27, "file:///p/src/a/i.png".to_owned()),
+            ]
+        );
+    }
+}
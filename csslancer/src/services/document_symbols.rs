@@ -0,0 +1,277 @@
+use super::CssLancerServer;
+
+use lsp_types::{DocumentSymbol, Range, SymbolKind};
+use rowan::TextRange;
+
+use crate::interop::csslancer_to_client;
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+impl CssLancerServer {
+    /// Outline of `src`: rule sets, conditional group rules, keyframes, font faces and
+    /// custom property declarations, nested the way they are in the stylesheet
+    pub fn get_document_symbols(&self, src: &Source) -> Vec<DocumentSymbol> {
+        let position_encoding = self.const_config().position_encoding;
+        let to_lsp_range =
+            |range: TextRange| csslancer_to_client::text_range(range, src, position_encoding);
+        symbols_in(&src.parse.tree().syntax, &to_lsp_range)
+    }
+}
+
+/// Symbols for the statements directly inside `node`, a `SOURCE_FILE` or `DECLARATIONS`
+fn symbols_in(
+    node: &SyntaxNode,
+    to_lsp_range: &impl Fn(TextRange) -> Range,
+) -> Vec<DocumentSymbol> {
+    node.children()
+        .filter_map(|child| symbol(&child, to_lsp_range))
+        .collect()
+}
+
+fn symbol(node: &SyntaxNode, to_lsp_range: &impl Fn(TextRange) -> Range) -> Option<DocumentSymbol> {
+    let (name, kind, selection_range, detail) = match node.kind() {
+        SyntaxKind::RULE_SET => {
+            let selectors = node
+                .children()
+                .filter(|c| c.kind() == SyntaxKind::SELECTOR)
+                .collect::<Vec<_>>();
+            let name = selectors
+                .iter()
+                .map(|s| collapse_whitespace(&s.text().to_string()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let selection_range = TextRange::new(
+                selectors.first()?.text_range().start(),
+                selectors.last()?.text_range().end(),
+            );
+            (name, SymbolKind::CLASS, selection_range, None)
+        }
+        SyntaxKind::MEDIA
+        | SyntaxKind::SUPPORTS
+        | SyntaxKind::CONTAINER
+        | SyntaxKind::SCOPE
+        | SyntaxKind::STARTING_STYLE
+        | SyntaxKind::LAYER => {
+            // `@layer a, b;` only declares layer order, it has no block to outline
+            declarations(node)?;
+            let header = header_range(node);
+            (
+                header_text(node, header),
+                SymbolKind::NAMESPACE,
+                header,
+                None,
+            )
+        }
+        SyntaxKind::KEYFRAME
+        | SyntaxKind::KEYFRAME_SELECTOR
+        | SyntaxKind::FONT_FACE
+        | SyntaxKind::VIEW_TRANSITION
+        | SyntaxKind::POSITION_TRY
+        | SyntaxKind::COUNTER_STYLE
+        | SyntaxKind::FONT_FEATURE_VALUES
+        | SyntaxKind::FONT_FEATURE_VALUE_BLOCK
+        | SyntaxKind::FONT_PALETTE_VALUES => {
+            let header = header_range(node);
+            let detail = matches!(
+                node.kind(),
+                SyntaxKind::FONT_FACE | SyntaxKind::FONT_PALETTE_VALUES
+            )
+            .then(|| font_family(node))
+            .flatten();
+            (header_text(node, header), SymbolKind::CLASS, header, detail)
+        }
+        SyntaxKind::DECLARATION => {
+            let custom_property = node
+                .children()
+                .find(|c| c.kind() == SyntaxKind::DECLARATION_CUSTOM_PROPERTY)?;
+            let property = custom_property
+                .children()
+                .find(|c| c.kind() == SyntaxKind::PROPERTY)?;
+            let value = custom_property
+                .children()
+                .find(|c| c.kind() != SyntaxKind::PROPERTY)
+                .map(|v| collapse_whitespace(&v.text().to_string()));
+            (
+                property.text().to_string(),
+                SymbolKind::VARIABLE,
+                property.text_range(),
+                value,
+            )
+        }
+        _ => return None,
+    };
+
+    let children = declarations(node).map(|d| symbols_in(&d, to_lsp_range));
+
+    #[allow(deprecated)] // `DocumentSymbol::deprecated` is deprecated in favour of `tags`
+    Some(DocumentSymbol {
+        name,
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: to_lsp_range(node.text_range()),
+        selection_range: to_lsp_range(selection_range),
+        children,
+    })
+}
+
+fn declarations(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.children()
+        .find(|c| c.kind() == SyntaxKind::DECLARATIONS)
+}
+
+/// Everything in front of the block of `node` (e.g. `@media screen`), without trailing trivia
+fn header_range(node: &SyntaxNode) -> TextRange {
+    let end = node
+        .children_with_tokens()
+        .take_while(|c| c.kind() != SyntaxKind::DECLARATIONS)
+        .filter(|c| !c.kind().is_trivia())
+        .last()
+        .map_or(node.text_range().start(), |c| c.text_range().end());
+    TextRange::new(node.text_range().start(), end)
+}
+
+fn header_text(node: &SyntaxNode, header: TextRange) -> String {
+    let text = node
+        .text()
+        .slice(header - node.text_range().start())
+        .to_string();
+    collapse_whitespace(&text)
+}
+
+/// The value of the `font-family` descriptor in a `@font-face` or `@font-palette-values`
+/// block
+fn font_family(font_face: &SyntaxNode) -> Option<String> {
+    declarations(font_face)?
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::DECLARATION_BASIC)
+        .find(|d| {
+            d.children()
+                .any(|c| c.kind() == SyntaxKind::PROPERTY && c.text() == "font-family")
+        })?
+        .children()
+        .find(|c| c.kind() == SyntaxKind::EXPRESSION)
+        .map(|e| collapse_whitespace(&e.text().to_string()))
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod document_symbols_test {
+    use lsp_types::{DocumentSymbol, SymbolKind, Url};
+
+    use crate::{services::CssLancerServer, workspace::source::Source};
+
+    fn symbols(value: &str) -> Vec<DocumentSymbol> {
+        let ls = CssLancerServer::new_test();
+        let source = Source::new(Url::parse("test://foo/bar.css").unwrap(), value, 0);
+        ls.get_document_symbols(&source)
+    }
+
+    fn outline(symbols: &[DocumentSymbol]) -> Vec<(String, SymbolKind, usize)> {
+        symbols
+            .iter()
+            .map(|s| {
+                (
+                    s.name.clone(),
+                    s.kind,
+                    s.children.as_ref().map_or(0, Vec::len),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rule_sets() {
+        let symbols = symbols(".a,\n  .b > c:hover { color: red; --main: blue; .nested { x: y } }");
+        assert_eq!(
+            outline(&symbols),
+            vec![(".a, .b > c:hover".to_owned(), SymbolKind::CLASS, 2)]
+        );
+        let children = symbols[0].children.as_ref().unwrap();
+        assert_eq!(
+            outline(children),
+            vec![
+                ("--main".to_owned(), SymbolKind::VARIABLE, 0),
+                (".nested".to_owned(), SymbolKind::CLASS, 0),
+            ]
+        );
+        assert_eq!(children[0].detail.as_deref(), Some("blue"));
+        assert_eq!(symbols[0].selection_range.end.line, 1);
+    }
+
+    #[test]
+    fn at_rules() {
+        let symbols = symbols(
+            "@media screen and (max-width: 3px) { .c {} }
+            @supports (display: grid) { .d {} }
+            @container sidebar (min-width: 3px) { .e {} }
+            @scope (.card) to (.content) { img {} }
+            @layer base, theme;
+            @layer base { .f {} }
+            @keyframes spin { from { a: b } 50% { a: c } }
+            @font-face { font-family: \"Example Font\"; }
+            @starting-style { .g {} }
+            @view-transition { navigation: auto; }
+            @position-try --top { top: 0; }
+            @counter-style thumbs { system: cyclic; }
+            @font-feature-values Font One { @styleset { nice-style: 12; } }
+            @font-palette-values --x { font-family: Bixa; }",
+        );
+        assert_eq!(
+            outline(&symbols),
+            vec![
+                (
+                    "@media screen and (max-width: 3px)".to_owned(),
+                    SymbolKind::NAMESPACE,
+                    1
+                ),
+                (
+                    "@supports (display: grid)".to_owned(),
+                    SymbolKind::NAMESPACE,
+                    1
+                ),
+                (
+                    "@container sidebar (min-width: 3px)".to_owned(),
+                    SymbolKind::NAMESPACE,
+                    1
+                ),
+                (
+                    "@scope (.card) to (.content)".to_owned(),
+                    SymbolKind::NAMESPACE,
+                    1
+                ),
+                ("@layer base".to_owned(), SymbolKind::NAMESPACE, 1),
+                ("@keyframes spin".to_owned(), SymbolKind::CLASS, 2),
+                ("@font-face".to_owned(), SymbolKind::CLASS, 0),
+                ("@starting-style".to_owned(), SymbolKind::NAMESPACE, 1),
+                ("@view-transition".to_owned(), SymbolKind::CLASS, 0),
+                ("@position-try --top".to_owned(), SymbolKind::CLASS, 0),
+                ("@counter-style thumbs".to_owned(), SymbolKind::CLASS, 0),
+                (
+                    "@font-feature-values Font One".to_owned(),
+                    SymbolKind::CLASS,
+                    1
+                ),
+                ("@font-palette-values --x".to_owned(), SymbolKind::CLASS, 0),
+            ]
+        );
+        assert_eq!(
+            outline(symbols[5].children.as_ref().unwrap()),
+            vec![
+                ("from".to_owned(), SymbolKind::CLASS, 0),
+                ("50%".to_owned(), SymbolKind::CLASS, 0),
+            ]
+        );
+        assert_eq!(symbols[6].detail.as_deref(), Some("\"Example Font\""));
+        assert_eq!(
+            outline(symbols[11].children.as_ref().unwrap()),
+            vec![("@styleset".to_owned(), SymbolKind::CLASS, 0)]
+        );
+        assert_eq!(symbols[12].detail.as_deref(), Some("Bixa"));
+    }
+}
@@ -0,0 +1,149 @@
+use lsp_types::{FoldingRange, FoldingRangeKind};
+use rowan::TextSize;
+
+use super::CssLancerServer;
+use crate::interop::csslancer_to_client::offset_to_position;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+/// Whether `comment` is a `/* #region */` or a `/* #endregion */` marker, `Some(true)`
+/// for the start of a region
+fn region_marker(comment: &str) -> Option<bool> {
+    let text = comment.strip_prefix("/*")?.trim_start();
+    let is_marker = |keyword: &str| {
+        text.strip_prefix(keyword)
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '-'))
+    };
+    if is_marker("#region") {
+        Some(true)
+    } else if is_marker("#endregion") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+impl CssLancerServer {
+    /// Folds every `{}` block, up to the line before its closing brace when that starts
+    /// a line, every comment spanning several lines and every region between
+    /// `/* #region */` and `/* #endregion */` markers, ordered by start line
+    pub fn get_folding_ranges(&self, src: &Source) -> Vec<FoldingRange> {
+        let encoding = self.const_config().position_encoding;
+        let line = |offset: TextSize| offset_to_position(offset.into(), encoding, src).line;
+        let fold = |start_line, end_line, kind| FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        };
+
+        let mut ranges = Vec::new();
+        let mut blocks = Vec::new();
+        let mut regions = Vec::new();
+        let root = src.parse.tree().syntax;
+        for token in root
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+        {
+            let range = token.text_range();
+            match token.kind() {
+                SyntaxKind::L_CURLY => blocks.push(line(range.start())),
+                SyntaxKind::R_CURLY => {
+                    let Some(start_line) = blocks.pop() else {
+                        continue;
+                    };
+                    let mut end_line = line(range.start());
+                    let starts_line = token
+                        .prev_token()
+                        .filter(|prev| prev.kind() == SyntaxKind::WHITESPACE)
+                        .is_some_and(|prev| prev.text().contains(['\n', '\r', '\x0c']));
+                    if starts_line {
+                        end_line -= 1;
+                    }
+                    if end_line > start_line {
+                        ranges.push(fold(start_line, end_line, None));
+                    }
+                }
+                SyntaxKind::COMMENT => match region_marker(token.text()) {
+                    Some(true) => regions.push(line(range.start())),
+                    Some(false) => {
+                        if let Some(start_line) = regions.pop() {
+                            let end_line = line(range.start());
+                            ranges.push(fold(start_line, end_line, Some(FoldingRangeKind::Region)));
+                        }
+                    }
+                    None => {
+                        let (start_line, end_line) = (line(range.start()), line(range.end()));
+                        if end_line > start_line {
+                            ranges.push(fold(
+                                start_line,
+                                end_line,
+                                Some(FoldingRangeKind::Comment),
+                            ));
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+        ranges.sort_by_key(|range| range.start_line);
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod folding_ranges_test {
+    use lsp_types::{FoldingRangeKind, Url};
+
+    use crate::services::CssLancerServer;
+    use crate::workspace::source::Source;
+
+    fn folding_ranges(text: &str) -> Vec<(u32, u32, Option<FoldingRangeKind>)> {
+        let ls = CssLancerServer::new_test();
+        let src = Source::new(Url::parse("test://foo/a.css").unwrap(), text, 0);
+        ls.get_folding_ranges(&src)
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect()
+    }
+
+    #[test]
+    fn blocks() {
+        let text = "\
+.a {
+  color: red;
+}
+@media screen {
+  .b {
+    & .c { color: blue;
+      margin: 0 } }
+}
+.d { color: red; }";
+        assert_eq!(
+            folding_ranges(text),
+            vec![(0, 1, None), (3, 6, None), (4, 6, None), (5, 6, None)]
+        );
+    }
+
+    #[test]
+    fn comments_and_regions() {
+        let text = "\
+/* #region buttons */
+/*
+ * Buttons
+ */
+.btn {}
+/* #region-like */
+/* #endregion */
+/* one line */";
+        assert_eq!(
+            folding_ranges(text),
+            vec![
+                (0, 6, Some(FoldingRangeKind::Region)),
+                (1, 3, Some(FoldingRangeKind::Comment)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,755 @@
+use lsp_types::{FormattingOptions, Position, Range, TextEdit};
+use rowan::{NodeOrToken, TextRange, TextSize};
+
+use super::CssLancerServer;
+use crate::css_language_types::{BraceStyle, CSSFormatConfiguration};
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::interop::csslancer_to_client::offset_to_position;
+use crate::row_parser::nodes_types::{SyntaxNode, SyntaxToken};
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+impl CssLancerServer {
+    /// Edits that pretty-print all of `src`, or `None` when it has syntax errors, since
+    /// moving text the parser did not understand could change its meaning
+    pub fn get_formatting(
+        &self,
+        src: &Source,
+        options: &FormattingOptions,
+        config: &CSSFormatConfiguration,
+    ) -> Option<Vec<TextEdit>> {
+        if !src.parse.errors().is_empty() {
+            return None;
+        }
+        let text = src.text().to_string();
+        let formatted = format(&src.parse.tree().syntax, &configuration(config, options));
+        if formatted == text {
+            return Some(Vec::new());
+        }
+        let end = offset_to_position(text.len(), self.const_config().position_encoding, src);
+        Some(vec![TextEdit::new(
+            Range::new(Position::new(0, 0), end),
+            formatted,
+        )])
+    }
+
+    /// Edits that format the rules, at-rules and declarations overlapping `range`
+    pub fn get_range_formatting(
+        &self,
+        src: &Source,
+        range: Range,
+        options: &FormattingOptions,
+        config: &CSSFormatConfiguration,
+    ) -> Option<Vec<TextEdit>> {
+        let encoding = self.const_config().position_encoding;
+        let start = position_to_offset(range.start, encoding, src);
+        let end = position_to_offset(range.end, encoding, src);
+        let range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+        self.get_statement_formatting(src, range, options, config)
+    }
+
+    /// Edits that format the rule or at-rule closed by a typed `}`, or the declaration or
+    /// statement ended by a typed `;`
+    pub fn get_on_type_formatting(
+        &self,
+        src: &Source,
+        position: Position,
+        ch: &str,
+        options: &FormattingOptions,
+        config: &CSSFormatConfiguration,
+    ) -> Option<Vec<TextEdit>> {
+        let offset = position_to_offset(position, self.const_config().position_encoding, src);
+        let root = src.parse.tree().syntax;
+        let typed = root
+            .token_at_offset(TextSize::from(offset as u32))
+            .left_biased()
+            .filter(|token| token.text() == ch)?;
+        let range = match typed.kind() {
+            SyntaxKind::R_CURLY => typed.parent()?.parent()?.text_range(),
+            SyntaxKind::SEMICOLON => typed.text_range(),
+            _ => return None,
+        };
+        self.get_statement_formatting(src, range, options, config)
+    }
+
+    /// Minimal edits to the whitespace in and before the statements that overlap `range`,
+    /// or `None` when those have syntax errors
+    fn get_statement_formatting(
+        &self,
+        src: &Source,
+        range: TextRange,
+        options: &FormattingOptions,
+        config: &CSSFormatConfiguration,
+    ) -> Option<Vec<TextEdit>> {
+        let root = src.parse.tree().syntax;
+        let statements = statements_range(&root, range)?;
+        if src
+            .parse
+            .errors()
+            .iter()
+            .any(|error| error.range().intersect(statements).is_some())
+        {
+            return None;
+        }
+        let formatted = format(&root, &configuration(config, options));
+        let encoding = self.const_config().position_encoding;
+        let edits = whitespace_edits(&root, &formatted)
+            .into_iter()
+            .filter(|(gap, _)| statements.start() <= gap.end() && gap.end() <= statements.end())
+            .map(|(gap, new_text)| {
+                let start = offset_to_position(gap.start().into(), encoding, src);
+                let end = offset_to_position(gap.end().into(), encoding, src);
+                TextEdit::new(Range::new(start, end), new_text)
+            })
+            .collect();
+        Some(edits)
+    }
+}
+
+/// `config` with the options that the client sends along with each request on top.
+/// Formatted text never ends in more than one newline, so `trimFinalNewlines` always
+/// holds, and `trimTrailingWhitespace` wins over `indent_empty_lines`.
+pub fn configuration(
+    config: &CSSFormatConfiguration,
+    options: &FormattingOptions,
+) -> CSSFormatConfiguration {
+    CSSFormatConfiguration {
+        tab_size: options.tab_size,
+        insert_spaces: options.insert_spaces,
+        insert_final_new_line: options
+            .insert_final_newline
+            .unwrap_or(config.insert_final_new_line),
+        indent_empty_lines: config.indent_empty_lines
+            && options.trim_trailing_whitespace != Some(true),
+        ..config.clone()
+    }
+}
+
+/// Pretty-prints the tree of `root`. Only whitespace between tokens changes: every token,
+/// comments included, is kept, and the values of custom properties are kept as written.
+/// Lines end like the first line of `root`, and a final line break is kept.
+pub fn format(root: &SyntaxNode, config: &CSSFormatConfiguration) -> String {
+    let text = root.text().to_string();
+    let eol = match text.find('\n') {
+        Some(i) if text[..i].ends_with('\r') => "\r\n",
+        _ => "\n",
+    };
+    let tokens = root
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .collect::<Vec<_>>();
+    let whitespace_after = |i: usize| {
+        tokens
+            .get(i + 1)
+            .filter(|t| t.kind() == SyntaxKind::WHITESPACE)
+            .map_or("", |t| t.text())
+    };
+
+    let mut formatter = Formatter::new(config, eol, text.ends_with('\n'));
+    let mut whitespace = "";
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        if token.kind() == SyntaxKind::WHITESPACE {
+            whitespace = token.text();
+            i += 1;
+            continue;
+        }
+        formatter.token(token, whitespace, whitespace_after(i));
+        whitespace = "";
+        i += 1;
+
+        if token.kind() == SyntaxKind::COLON {
+            let Some(declaration) = token
+                .parent()
+                .filter(|p| p.kind() == SyntaxKind::DECLARATION_CUSTOM_PROPERTY)
+            else {
+                continue;
+            };
+            let end = declaration.text_range().end();
+            let value_start =
+                usize::from(token.text_range().end() - declaration.text_range().start());
+            formatter.verbatim(declaration.text().to_string()[value_start..].trim());
+            while tokens.get(i).is_some_and(|t| t.text_range().end() <= end) {
+                i += 1;
+            }
+        }
+    }
+    formatter.finish()
+}
+
+/// The range of the statements overlapping `range` that are children of the innermost
+/// block, or the stylesheet, that contains all of `range`
+fn statements_range(root: &SyntaxNode, range: TextRange) -> Option<TextRange> {
+    let covering = match root.covering_element(range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let container = covering.ancestors().find(|node| match node.kind() {
+        SyntaxKind::SOURCE_FILE => true,
+        SyntaxKind::DECLARATIONS => {
+            let brace = |token: Option<SyntaxToken>, kind| {
+                token.filter(|t| t.kind() == kind).map(|t| t.text_range())
+            };
+            let open = brace(node.first_token(), SyntaxKind::L_CURLY);
+            let close = brace(node.last_token(), SyntaxKind::R_CURLY);
+            open.is_some_and(|open| open.end() <= range.start())
+                && close.is_none_or(|close| range.end() <= close.start())
+        }
+        _ => false,
+    })?;
+    let mut statements = container
+        .children_with_tokens()
+        .filter(|child| {
+            !matches!(
+                child.kind(),
+                SyntaxKind::WHITESPACE | SyntaxKind::L_CURLY | SyntaxKind::R_CURLY
+            )
+        })
+        .map(|child| child.text_range())
+        .filter(|child| child.intersect(range).is_some());
+    let first = statements.next()?;
+    Some(statements.fold(first, |covered, child| covered.cover(child)))
+}
+
+/// The whitespace between the tokens of `root` that differs in `formatted`, its
+/// formatted text, with the range it takes up in `root` and what replaces it
+fn whitespace_edits(root: &SyntaxNode, formatted: &str) -> Vec<(TextRange, String)> {
+    let text = root.text().to_string();
+    let mut edits = Vec::new();
+    let mut gap_start = root.text_range().start();
+    let mut rest = formatted;
+    let mut push_edit = |gap: TextRange, new: &str| {
+        if text[gap] != *new {
+            edits.push((gap, new.to_owned()));
+        }
+    };
+    for token in root
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|t| t.kind() != SyntaxKind::WHITESPACE)
+    {
+        let after_gap = rest.trim_start_matches([' ', '\t', '\r', '\n']);
+        let Some(after_token) = after_gap.strip_prefix(token.text()) else {
+            // the formatter changed more than whitespace, which it never does
+            return Vec::new();
+        };
+        push_edit(
+            TextRange::new(gap_start, token.text_range().start()),
+            &rest[..rest.len() - after_gap.len()],
+        );
+        gap_start = token.text_range().end();
+        rest = after_token;
+    }
+    push_edit(TextRange::new(gap_start, root.text_range().end()), rest);
+    edits
+}
+
+/// What is written between the previous token and the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Separator {
+    Nothing,
+    Space,
+    Newline { blank_lines: usize },
+}
+
+struct Formatter<'a> {
+    config: &'a CSSFormatConfiguration,
+    /// The line break written, `"\n"` or `"\r\n"`
+    eol: &'static str,
+    /// Whether the source ends with a line break, which is kept
+    final_newline: bool,
+    out: String,
+    indent: usize,
+    column: usize,
+    /// Indentation of the line being written
+    line_indent: usize,
+    /// The last written token
+    prev: Option<SyntaxToken>,
+    /// Set when the next token starts a new statement, with the least number of blank
+    /// lines before it
+    boundary: Option<usize>,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(config: &'a CSSFormatConfiguration, eol: &'static str, final_newline: bool) -> Self {
+        Self {
+            config,
+            eol,
+            final_newline,
+            out: String::new(),
+            indent: 0,
+            column: 0,
+            line_indent: 0,
+            prev: None,
+            boundary: Some(0),
+        }
+    }
+
+    /// Writes `token`, which had `before` and `after` as whitespace in the source
+    fn token(&mut self, token: &SyntaxToken, before: &str, after: &str) {
+        let kind = token.kind();
+        if kind == SyntaxKind::R_CURLY {
+            self.indent = self.indent.saturating_sub(1);
+        }
+        let separator = self.separator(token, before);
+        self.write(separator, token.text());
+
+        self.boundary = match kind {
+            SyntaxKind::L_CURLY => {
+                self.indent += 1;
+                Some(0)
+            }
+            SyntaxKind::R_CURLY => Some(usize::from(self.config.newline_between_rules)),
+            SyntaxKind::SEMICOLON => Some(0),
+            // a comment after a statement on the same line stays there
+            SyntaxKind::COMMENT if self.boundary.is_some() && separator == Separator::Space => {
+                self.boundary
+            }
+            SyntaxKind::COMMENT if self.boundary.is_some() => after.contains('\n').then_some(0),
+            _ => None,
+        };
+        self.prev = Some(token.clone());
+    }
+
+    /// Writes the value of a custom property after its colon as is
+    fn verbatim(&mut self, text: &str) {
+        if !text.is_empty() {
+            self.write(Separator::Space, text);
+        }
+    }
+
+    fn separator(&self, token: &SyntaxToken, before: &str) -> Separator {
+        let Some(prev) = &self.prev else {
+            return Separator::Nothing;
+        };
+        let kind = token.kind();
+        match kind {
+            SyntaxKind::R_CURLY if prev.kind() == SyntaxKind::L_CURLY => return Separator::Nothing,
+            SyntaxKind::R_CURLY => return Separator::Newline { blank_lines: 0 },
+            SyntaxKind::L_CURLY => {
+                return match self.config.brace_style {
+                    BraceStyle::Collapse => Separator::Space,
+                    BraceStyle::Expand => Separator::Newline { blank_lines: 0 },
+                }
+            }
+            _ => {}
+        }
+        if let Some(blank_lines) = self.boundary {
+            if kind == SyntaxKind::COMMENT && !before.contains('\n') {
+                return Separator::Space;
+            }
+            return Separator::Newline {
+                blank_lines: blank_lines.max(self.preserved_blank_lines(before)),
+            };
+        }
+
+        let parent_kind = |token: &SyntaxToken| token.parent().map(|p| p.kind());
+        if prev.kind() == SyntaxKind::COMMA && parent_kind(prev) == Some(SyntaxKind::RULE_SET) {
+            return if self.config.newline_between_selectors {
+                Separator::Newline { blank_lines: 0 }
+            } else {
+                Separator::Space
+            };
+        }
+        if matches!(
+            kind,
+            SyntaxKind::COMMA | SyntaxKind::SEMICOLON | SyntaxKind::R_PAREN
+        ) || is_declaration_colon(token)
+            || prev.text().ends_with('(')
+            || (prev.kind() == SyntaxKind::EXCLAMATION
+                && parent_kind(prev) == Some(SyntaxKind::PRIO))
+        {
+            return Separator::Nothing;
+        }
+        if prev.kind() == SyntaxKind::COMMA
+            || is_declaration_colon(prev)
+            || (kind == SyntaxKind::EXCLAMATION && parent_kind(token) == Some(SyntaxKind::PRIO))
+        {
+            return Separator::Space;
+        }
+        if self.config.space_around_selector_seperator
+            && (is_combinator_edge(token, true) || is_combinator_edge(prev, false))
+        {
+            return Separator::Space;
+        }
+        if before.is_empty() {
+            Separator::Nothing
+        } else {
+            Separator::Space
+        }
+    }
+
+    /// Blank lines in `whitespace` that are kept
+    fn preserved_blank_lines(&self, whitespace: &str) -> usize {
+        if !self.config.preserve_new_lines {
+            return 0;
+        }
+        let line_breaks = whitespace.matches('\n').count();
+        let line_breaks = match self.config.max_preserve_new_lines {
+            Some(max) => line_breaks.min(max as usize),
+            None => line_breaks,
+        };
+        line_breaks.saturating_sub(1)
+    }
+
+    fn write(&mut self, separator: Separator, text: &str) {
+        match separator {
+            Separator::Nothing => {}
+            Separator::Space => {
+                let width = text.lines().next().map_or(0, |line| line.chars().count());
+                let wrap = self.config.wrap_line_length as usize;
+                if wrap > 0
+                    && self.column + 1 + width > wrap
+                    && self.column > self.indent_width(self.line_indent + 1)
+                {
+                    self.newline(0, self.indent + 1);
+                } else {
+                    self.out.push(' ');
+                    self.column += 1;
+                }
+            }
+            Separator::Newline { blank_lines } => self.newline(blank_lines, self.indent),
+        }
+        self.out.push_str(text);
+        match text.rfind('\n') {
+            Some(i) => self.column = text[i + 1..].chars().count(),
+            None => self.column += text.chars().count(),
+        }
+    }
+
+    fn newline(&mut self, blank_lines: usize, indent: usize) {
+        let indentation = if self.config.insert_spaces {
+            " ".repeat(self.config.tab_size as usize * indent)
+        } else {
+            "\t".repeat(indent)
+        };
+        self.out.push_str(self.eol);
+        for _ in 0..blank_lines {
+            if self.config.indent_empty_lines {
+                self.out.push_str(&indentation);
+            }
+            self.out.push_str(self.eol);
+        }
+        self.out.push_str(&indentation);
+        self.column = self.indent_width(indent);
+        self.line_indent = indent;
+    }
+
+    fn indent_width(&self, indent: usize) -> usize {
+        self.config.tab_size as usize * indent
+    }
+
+    fn finish(mut self) -> String {
+        self.out.truncate(self.out.trim_end().len());
+        if (self.config.insert_final_new_line || self.final_newline) && !self.out.is_empty() {
+            self.out.push_str(self.eol);
+        }
+        self.out
+    }
+}
+
+/// The colon between the name and value of a declaration or media feature
+fn is_declaration_colon(token: &SyntaxToken) -> bool {
+    token.kind() == SyntaxKind::COLON
+        && token.parent().is_some_and(|p| {
+            matches!(
+                p.kind(),
+                SyntaxKind::DECLARATION_BASIC
+                    | SyntaxKind::DECLARATION_CUSTOM_PROPERTY
+                    | SyntaxKind::MEDIA_FEATURE
+            )
+        })
+}
+
+/// Whether `token` is the first, or with `first` unset the last, token of a `>`, `+`,
+/// `~` or `>>>` selector combinator
+fn is_combinator_edge(token: &SyntaxToken, first: bool) -> bool {
+    token.parent().is_some_and(|combinator| {
+        matches!(
+            combinator.kind(),
+            SyntaxKind::SELECTOR_COMBINATOR_PARENT
+                | SyntaxKind::SELECTOR_COMBINATOR_SIBLING
+                | SyntaxKind::SELECTOR_COMBINATOR_ALL_SIBLINGS
+                | SyntaxKind::SELECTOR_COMBINATOR_SHADOW_PIERCING_DESCENDANT
+        ) && if first {
+            combinator.first_token().as_ref() == Some(token)
+        } else {
+            combinator.last_token().as_ref() == Some(token)
+        }
+    })
+}
+
+#[cfg(test)]
+mod formatting_test {
+    use lsp_types::{FormattingOptions, Position, Range, TextEdit, Url};
+
+    use super::format;
+    use crate::config::PositionEncoding;
+    use crate::css_language_types::{BraceStyle, CSSFormatConfiguration};
+    use crate::interop::client_to_csslancer::position_to_offset;
+    use crate::row_parser::nodes_gen::SourceFile;
+    use crate::services::CssLancerServer;
+    use crate::workspace::source::Source;
+
+    const STYLESHEET: &str = "/* head */
+@import url(\"a.css\") screen;
+
+
+a>b , c:hover::before, :is(d,e) {color:red!important;--x:  1px  foo(a,b) ;background:url(x.png) , rgba(0,0,0,.5);} /* trailing */
+@media screen and (min-width:100px){.a{margin:0 auto}}
+.b{ & .c{}
+/* own line */
+top : 0 }";
+
+    fn formatted(text: &str, config: &CSSFormatConfiguration) -> String {
+        format(&SourceFile::parse(text).tree().syntax, config)
+    }
+
+    fn apply(src: &Source, edits: &[TextEdit]) -> String {
+        let mut text = src.text().to_string();
+        for edit in edits.iter().rev() {
+            let start = position_to_offset(edit.range.start, PositionEncoding::Utf16, src);
+            let end = position_to_offset(edit.range.end, PositionEncoding::Utf16, src);
+            text.replace_range(start..end, &edit.new_text);
+        }
+        text
+    }
+
+    #[test]
+    fn default_configuration() {
+        assert_eq!(
+            formatted(STYLESHEET, &CSSFormatConfiguration::default()),
+            "/* head */
+@import url(\"a.css\") screen;
+
+
+a>b,
+c:hover::before,
+:is(d, e) {
+    color: red !important;
+    --x: 1px  foo(a,b);
+    background: url(x.png), rgba(0, 0, 0, .5);
+} /* trailing */
+
+@media screen and (min-width: 100px) {
+    .a {
+        margin: 0 auto
+    }
+}
+
+.b {
+    & .c {}
+
+    /* own line */
+    top: 0
+}"
+        );
+    }
+
+    #[test]
+    fn options() {
+        let config = CSSFormatConfiguration {
+            tab_size: 2,
+            insert_spaces: false,
+            insert_final_new_line: true,
+            newline_between_selectors: false,
+            newline_between_rules: false,
+            space_around_selector_seperator: true,
+            brace_style: BraceStyle::Expand,
+            preserve_new_lines: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            formatted(STYLESHEET, &config),
+            "/* head */
+@import url(\"a.css\") screen;
+a > b, c:hover::before, :is(d, e)
+{
+\tcolor: red !important;
+\t--x: 1px  foo(a,b);
+\tbackground: url(x.png), rgba(0, 0, 0, .5);
+} /* trailing */
+@media screen and (min-width: 100px)
+{
+\t.a
+\t{
+\t\tmargin: 0 auto
+\t}
+}
+.b
+{
+\t& .c
+\t{}
+\t/* own line */
+\ttop: 0
+}
+"
+        );
+    }
+
+    #[test]
+    fn blank_lines() {
+        let text = ".a {\n    top: 0;\n\n\n\n    left: 0\n}";
+        let config = CSSFormatConfiguration {
+            max_preserve_new_lines: Some(3),
+            indent_empty_lines: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            formatted(text, &config),
+            ".a {\n    top: 0;\n    \n    \n    left: 0\n}"
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let text = ".a { transition: opacity 1s ease-in, color 2s linear, transform 3s; }";
+        let config = CSSFormatConfiguration {
+            wrap_line_length: 40,
+            ..Default::default()
+        };
+        assert_eq!(
+            formatted(text, &config),
+            ".a {
+    transition: opacity 1s ease-in,
+        color 2s linear, transform 3s;
+}"
+        );
+    }
+
+    #[test]
+    fn idempotent() {
+        let configs = [
+            CSSFormatConfiguration::default(),
+            CSSFormatConfiguration {
+                brace_style: BraceStyle::Expand,
+                newline_between_selectors: false,
+                space_around_selector_seperator: true,
+                wrap_line_length: 20,
+                indent_empty_lines: true,
+                ..Default::default()
+            },
+        ];
+        for config in &configs {
+            let once = formatted(STYLESHEET, config);
+            assert_eq!(formatted(&once, config), once);
+        }
+    }
+
+    #[test]
+    fn crlf() {
+        let config = CSSFormatConfiguration::default();
+        let once = formatted(&STYLESHEET.replace('\n', "\r\n"), &config);
+        assert_eq!(once, formatted(STYLESHEET, &config).replace('\n', "\r\n"));
+        assert_eq!(formatted(&once, &config), once);
+
+        let ls = CssLancerServer::new_test();
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let src = Source::new(url, ".a {\r\n    top: 0\r\n}\r\n", 0);
+        let options = FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        };
+        assert_eq!(ls.get_formatting(&src, &options, &config), Some(Vec::new()));
+    }
+
+    #[test]
+    fn edits() {
+        let ls = CssLancerServer::new_test();
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let options = FormattingOptions {
+            tab_size: 2,
+            insert_spaces: true,
+            insert_final_newline: Some(true),
+            ..Default::default()
+        };
+        let config = CSSFormatConfiguration::default();
+
+        let src = Source::new(url.clone(), ".a{top:0}\n.b{}", 0);
+        let edits = ls.get_formatting(&src, &options, &config).unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.end, lsp_types::Position::new(1, 4));
+        assert_eq!(edits[0].new_text, ".a {\n  top: 0\n}\n\n.b {}\n");
+
+        let src = Source::new(url.clone(), ".a {\n  top: 0\n}\n", 0);
+        assert_eq!(ls.get_formatting(&src, &options, &config), Some(Vec::new()));
+
+        let src = Source::new(url, ".a { top: }", 0);
+        assert_eq!(ls.get_formatting(&src, &options, &config), None);
+    }
+
+    #[test]
+    fn range_edits() {
+        let ls = CssLancerServer::new_test();
+        let options = FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        };
+        let config = CSSFormatConfiguration::default();
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let src = Source::new(url, ".a{top:0}\n.b{left:0;right : 0;\n  bottom:0}", 0);
+
+        let second_rule = Range::new(Position::new(1, 0), Position::new(2, 11));
+        let edits = ls
+            .get_range_formatting(&src, second_rule, &options, &config)
+            .unwrap();
+        assert!(edits.iter().all(|edit| edit.range.end.line >= 1));
+        assert_eq!(
+            apply(&src, &edits),
+            ".a{top:0}\n\n.b {\n    left: 0;\n    right: 0;\n    bottom: 0\n}"
+        );
+
+        let in_value = Range::new(Position::new(1, 17), Position::new(1, 18));
+        let edits = ls
+            .get_range_formatting(&src, in_value, &options, &config)
+            .unwrap();
+        assert_eq!(
+            apply(&src, &edits),
+            ".a{top:0}\n.b{left:0;\n    right: 0;\n  bottom:0}"
+        );
+    }
+
+    #[test]
+    fn on_type_edits() {
+        let ls = CssLancerServer::new_test();
+        let options = FormattingOptions {
+            tab_size: 2,
+            insert_spaces: true,
+            ..Default::default()
+        };
+        let config = CSSFormatConfiguration::default();
+        let url = Url::parse("test://foo/bar.css").unwrap();
+
+        let src = Source::new(url.clone(), ".a {\n  top:0;\n  .b{left:0}\n  right:0}", 0);
+        let after_semicolon = Position::new(1, 8);
+        let edits = ls
+            .get_on_type_formatting(&src, after_semicolon, ";", &options, &config)
+            .unwrap();
+        assert_eq!(
+            apply(&src, &edits),
+            ".a {\n  top: 0;\n  .b{left:0}\n  right:0}"
+        );
+
+        let after_inner_brace = Position::new(2, 12);
+        let edits = ls
+            .get_on_type_formatting(&src, after_inner_brace, "}", &options, &config)
+            .unwrap();
+        assert_eq!(
+            apply(&src, &edits),
+            ".a {\n  top:0;\n  .b {\n    left: 0\n  }\n  right:0}"
+        );
+
+        assert_eq!(
+            ls.get_on_type_formatting(&src, after_inner_brace, ";", &options, &config),
+            None
+        );
+        let broken = Source::new(url, ".a { top: ; }", 0);
+        assert_eq!(
+            ls.get_on_type_formatting(&broken, Position::new(0, 11), ";", &options, &config),
+            None
+        );
+    }
+}
@@ -185,7 +185,7 @@ impl CssLancerServer {
         }
     }
 
-    fn does_support_markdown(&self) -> bool {
+    pub(crate) fn does_support_markdown(&self) -> bool {
         return *self.client_supports_markdown.get_or_init(
             || self.client_capabilities.get()
             .map_or(false, |c| c.text_document.as_ref()
@@ -208,22 +208,18 @@ mod hover_test {
     use lsp_types::{LanguageString, Url};
     use tower_lsp::lsp_types::{Hover, HoverContents, MarkedString, MarkupContent, MarkupKind};
 
-    use crate::{config::{ConstConfig, PositionEncoding}, css_language_types::HoverSettings, interop::csslancer_to_client, services::CssLancerServer, workspace::source::Source};
+    use crate::{config::PositionEncoding, css_language_types::HoverSettings, interop::csslancer_to_client, services::CssLancerServer, workspace::source::Source};
 
     fn assert_hover(value: &str, expected: Hover, language_id: &str, hover_settings: Option<HoverSettings>) {
         println!("assert_hover({}, {:?}, {}, {:?}", value, expected, language_id, hover_settings);
         let offset = value.find('|').expect("internal error in test: `value` must contain '|' char");
         let value = value[0..offset].to_owned() + &value[offset + 1..];
-        let ls = if language_id == "css" {CssLancerServer::new_dud()}
+        let ls = if language_id == "css" {CssLancerServer::new_test()}
             else if language_id == "less" {todo!()} 
             else if language_id == "scss" {todo!()} 
             else {panic!("internal error in test: `language_id` : `{language_id}` not recognized")};
 
         let position_encoding = PositionEncoding::Utf16;
-        ls.const_config.set(ConstConfig {
-            position_encoding,
-            supports_semantic_tokens_dynamic_registration: false,
-        }).unwrap();
         ls.client_supports_markdown.set(true).unwrap();
 
         let source = Source::new(Url::parse("test://foo/bar.${language_id}").unwrap(), &value, 0);
@@ -0,0 +1,329 @@
+use std::cell::OnceCell;
+use std::collections::HashSet;
+
+use lsp_types::{InlayHint, InlayHintLabel, InlayHintLabelPart, InlayHintTooltip, Location, Range};
+use rowan::TextSize;
+use tracing::error;
+
+use super::definition::{occurrence, walk_imports, SymbolKind};
+use super::CssLancerServer;
+use crate::config::InlayHintsSettings;
+use crate::css_language_types::AliasSettings;
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::interop::csslancer_to_client::{offset_to_position, text_range};
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::{source::Source, Workspace};
+
+/// Values longer than this many characters are cut off in hints
+const MAX_VALUE_LENGTH: usize = 40;
+
+/// Selectors of rules whose custom properties every element inherits
+const GLOBAL_SELECTORS: &[&str] = &[":root", "html", "*", ":host"];
+
+/// The custom property a `var()` function refers to
+fn var_name(function: &SyntaxNode) -> Option<String> {
+    let name = function.first_token()?;
+    if !name.text().eq_ignore_ascii_case("var(") {
+        return None;
+    }
+    let argument = function
+        .children()
+        .find(|c| c.kind() == SyntaxKind::FUNCTION_ARGUMENT)?;
+    let property = argument.first_token()?;
+    (property.kind() == SyntaxKind::IDENTIFIER && property.text().starts_with("--"))
+        .then(|| property.text().to_owned())
+}
+
+fn custom_property_name(declaration: &SyntaxNode) -> Option<String> {
+    declaration
+        .children()
+        .find(|c| c.kind() == SyntaxKind::PROPERTY)
+        .map(|property| property.text().to_string())
+}
+
+/// The value of a custom property declaration on one line, cut off when it is long
+fn custom_property_value(declaration: &SyntaxNode) -> String {
+    let value = declaration
+        .children_with_tokens()
+        .skip_while(|c| c.kind() != SyntaxKind::COLON)
+        .skip(1)
+        .map(|c| c.to_string())
+        .collect::<String>();
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if value.chars().count() <= MAX_VALUE_LENGTH {
+        return value;
+    }
+    value
+        .chars()
+        .take(MAX_VALUE_LENGTH - 1)
+        .chain(['…'])
+        .collect()
+}
+
+/// The last declaration of the custom property `name` in the innermost block around
+/// `node` that declares it
+fn enclosing_definition(node: &SyntaxNode, name: &str) -> Option<SyntaxNode> {
+    node.ancestors()
+        .filter(|a| a.kind() == SyntaxKind::DECLARATIONS)
+        .find_map(|block| {
+            block
+                .children()
+                .filter(|c| c.kind() == SyntaxKind::DECLARATION)
+                .filter_map(|c| {
+                    c.children()
+                        .find(|c| c.kind() == SyntaxKind::DECLARATION_CUSTOM_PROPERTY)
+                })
+                .filter(|d| custom_property_name(d).as_deref() == Some(name))
+                .last()
+        })
+}
+
+/// Whether `definition` is in a rule that matches every element or the root element
+fn is_global(definition: &SyntaxNode) -> bool {
+    let Some(rule) = definition
+        .ancestors()
+        .find(|a| a.kind() == SyntaxKind::RULE_SET)
+    else {
+        return false;
+    };
+    rule.children()
+        .filter(|c| c.kind() == SyntaxKind::SELECTOR)
+        .any(|selector| {
+            let selector = selector.text().to_string().to_lowercase();
+            GLOBAL_SELECTORS.contains(&selector.as_str())
+        })
+}
+
+/// The last of `definitions` of the custom property `name` in a global rule, else the
+/// last of them
+fn nearest<'a>(
+    definitions: &[(&'a Source, String, SyntaxNode)],
+    name: &str,
+) -> Option<(&'a Source, SyntaxNode)> {
+    let mut matching = definitions.iter().filter(|(_, n, _)| n == name);
+    matching
+        .clone()
+        .rfind(|(_, _, definition)| is_global(definition))
+        .or_else(|| matching.next_back())
+        .map(|(source, _, definition)| (*source, definition.clone()))
+}
+
+impl CssLancerServer {
+    fn supports_inlay_hint_refresh(&self) -> bool {
+        self.client_capabilities
+            .get()
+            .and_then(|capabilities| capabilities.workspace.as_ref())
+            .and_then(|workspace| workspace.inlay_hint.as_ref())
+            .and_then(|inlay_hint| inlay_hint.refresh_support)
+            .unwrap_or(false)
+    }
+
+    /// Asks the client to request the inlay hints again, e.g. after their settings changed
+    pub async fn refresh_inlay_hints(&self) {
+        if !self.supports_inlay_hint_refresh() {
+            return;
+        }
+        if let Err(err) = self.client.inlay_hint_refresh().await {
+            error!(%err, "could not refresh inlay hints");
+        }
+    }
+
+    /// Hints in `range` of `src`: after each `var()` the value of the nearest
+    /// declaration of its custom property, and after each selector of a rule its
+    /// specificity, as enabled in `settings`. The nearest declaration is the last one in
+    /// the innermost block around the `var()` that has one, else the last one in `src`
+    /// and the stylesheets it imports, in the order the browser sees them, else in the
+    /// other stylesheets of the workspace. Outside the blocks around the `var()`,
+    /// declarations in rules like `:root` that every element inherits from win.
+    pub fn get_inlay_hints(
+        &self,
+        workspace: &Workspace,
+        src: &Source,
+        range: Range,
+        settings: &InlayHintsSettings,
+        alias: &AliasSettings,
+    ) -> Vec<InlayHint> {
+        let encoding = self.const_config().position_encoding;
+        let start = TextSize::from(position_to_offset(range.start, encoding, src) as u32);
+        let end = TextSize::from(position_to_offset(range.end, encoding, src) as u32);
+
+        let mut visible = Vec::new();
+        let mut visited = HashSet::new();
+        if settings.variable_values {
+            walk_imports(workspace, src, alias, &mut |source, token| {
+                visited.insert(&source.url);
+                let Some(found) = occurrence(&token) else {
+                    return;
+                };
+                if let (SymbolKind::CustomProperty, Some(definition)) =
+                    (found.symbol.kind, found.definition)
+                {
+                    visible.push((source, found.symbol.name, definition));
+                }
+            });
+        }
+        // the declarations in the other stylesheets, only collected once a `var()` is
+        // not resolved otherwise
+        let elsewhere = OnceCell::new();
+        let collect_elsewhere = || {
+            let mut others = workspace
+                .files
+                .values()
+                .filter(|source| !visited.contains(&source.url))
+                .collect::<Vec<_>>();
+            others.sort_by_key(|source| &source.url);
+            others
+                .into_iter()
+                .flat_map(|source| {
+                    let root = source.parse.tree().syntax;
+                    root.descendants()
+                        .filter(|d| d.kind() == SyntaxKind::DECLARATION_CUSTOM_PROPERTY)
+                        .filter_map(|d| Some((source, custom_property_name(&d)?, d)))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let definition_of = |function: &SyntaxNode, name: &str| {
+            enclosing_definition(function, name)
+                .map(|definition| (src, definition))
+                .or_else(|| nearest(&visible, name))
+                .or_else(|| nearest(elsewhere.get_or_init(collect_elsewhere), name))
+        };
+
+        let hint = |offset: TextSize, label, tooltip: &str| InlayHint {
+            position: offset_to_position(offset.into(), encoding, src),
+            label,
+            kind: None,
+            text_edits: None,
+            tooltip: Some(InlayHintTooltip::String(tooltip.to_owned())),
+            padding_left: Some(true),
+            padding_right: None,
+            data: None,
+        };
+        let mut hints = Vec::new();
+        for node in src.parse.tree().syntax.descendants() {
+            let offset = node.text_range().end();
+            if offset < start || end < offset {
+                continue;
+            }
+            match node.kind() {
+                SyntaxKind::FUNCTION_WITH_ARGS if settings.variable_values => {
+                    let Some(name) = var_name(&node) else {
+                        continue;
+                    };
+                    let Some((source, definition)) = definition_of(&node, &name) else {
+                        continue;
+                    };
+                    let value = custom_property_value(&definition);
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let name_range = definition.first_child().unwrap_or(definition).text_range();
+                    let location =
+                        Location::new(source.url.clone(), text_range(name_range, source, encoding));
+                    let label = InlayHintLabel::LabelParts(vec![InlayHintLabelPart {
+                        value,
+                        location: Some(location),
+                        ..Default::default()
+                    }]);
+                    hints.push(hint(offset, label, &format!("Value of {name}")));
+                }
+                SyntaxKind::SELECTOR if settings.selector_specificity => {
+                    if node.parent().map(|p| p.kind()) != Some(SyntaxKind::RULE_SET) {
+                        continue;
+                    }
+                    let specificity = self.css_data_manager.selector_specificity(&node);
+                    let label = InlayHintLabel::String(format!(
+                        "({}, {}, {})",
+                        specificity.id, specificity.attr, specificity.tag
+                    ));
+                    hints.push(hint(offset, label, "Selector specificity"));
+                }
+                _ => {}
+            }
+        }
+        hints
+    }
+}
+
+#[cfg(test)]
+mod inlay_hints_test {
+    use lsp_types::{InlayHintLabel, Position, Range, Url};
+
+    use crate::config::{InlayHintsSettings, PositionEncoding};
+    use crate::css_language_types::AliasSettings;
+    use crate::interop::csslancer_to_client::offset_to_position;
+    use crate::services::CssLancerServer;
+    use crate::workspace::Workspace;
+
+    /// The position and label of each hint in `test://foo/a.css` of a workspace with
+    /// `files`
+    fn hints(files: &[(&str, &str)], settings: InlayHintsSettings) -> Vec<(u32, u32, String)> {
+        let ls = CssLancerServer::new_test();
+        let workspace = Workspace::new_test(files);
+        let src = workspace
+            .get_document_ref(&Url::parse("test://foo/a.css").unwrap())
+            .unwrap();
+        let end = offset_to_position(src.text().len().into(), PositionEncoding::Utf16, src);
+        let range = Range::new(Position::new(0, 0), end);
+        ls.get_inlay_hints(&workspace, src, range, &settings, &AliasSettings::new())
+            .into_iter()
+            .map(|hint| {
+                let label = match hint.label {
+                    InlayHintLabel::String(label) => label,
+                    InlayHintLabel::LabelParts(parts) => {
+                        parts.into_iter().map(|part| part.value).collect()
+                    }
+                };
+                (hint.position.line, hint.position.character, label)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn variable_values() {
+        let files = [
+            (
+                "test://foo/a.css",
+                "@import 'b.css';\n:root { --gap: 1px  2px; }\n.a { --gap: 3px; margin: var(--gap); }\n.b { margin: var(--gap) var(--brand) var(--other) var(--none, 0); }",
+            ),
+            ("test://foo/b.css", ":root { --brand: #ff0000; --gap: 9px; }"),
+            ("test://foo/c.css", ":root { --other:\n  1px\n  solid; --brand: blue; }"),
+        ];
+        let settings = InlayHintsSettings {
+            selector_specificity: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            hints(&files, settings),
+            vec![
+                (2, 35, "3px".to_owned()),
+                (3, 23, "1px 2px".to_owned()),
+                (3, 36, "#ff0000".to_owned()),
+                (3, 49, "1px solid".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn selector_specificity() {
+        let files = [(
+            "test://foo/a.css",
+            ".a, #b > p:hover { color: var(--x); }\n@media screen { div { & .c {} } }",
+        )];
+        let settings = InlayHintsSettings {
+            variable_values: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            hints(&files, settings),
+            vec![
+                (0, 2, "(0, 1, 0)".to_owned()),
+                (0, 16, "(1, 1, 1)".to_owned()),
+                (1, 19, "(0, 0, 1)".to_owned()),
+                (1, 26, "(0, 1, 0)".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,656 @@
+use std::collections::HashMap;
+
+use rowan::TextRange;
+
+use super::lint_rules::{self, Level, LintConfiguration, Rule};
+use super::value_validation::{check_property_values, split_dimension};
+use crate::css_language_types::LintSettings;
+use crate::data::data_manager::CssDataManager;
+use crate::data::facts::{
+    AT_RULES_MISSING_IN_DATA, DESCRIPTORS_MISSING_IN_DATA, LEGACY_PSEUDO_ELEMENTS, LENGTH_UNITS,
+    PSEUDO_SELECTORS_MISSING_IN_DATA,
+};
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+
+#[derive(Debug)]
+pub struct LintProblem {
+    pub rule: &'static Rule,
+    pub level: Level,
+    pub range: TextRange,
+    pub message: String,
+}
+
+/// Collects the problems found by the checks, dropping those of ignored rules
+pub struct Linter<'a> {
+    configuration: LintConfiguration<'a>,
+    pub data: &'a CssDataManager,
+    problems: Vec<LintProblem>,
+}
+
+impl Linter<'_> {
+    pub fn is_enabled(&self, rule: &Rule) -> bool {
+        self.configuration.get_level(rule) != Level::Ignore
+    }
+
+    pub fn add(&mut self, rule: &'static Rule, range: TextRange) {
+        self.add_with_message(rule, range, rule.message.to_owned());
+    }
+
+    pub fn add_with_message(&mut self, rule: &'static Rule, range: TextRange, message: String) {
+        let level = self.configuration.get_level(rule);
+        if level == Level::Ignore {
+            return;
+        }
+        self.problems.push(LintProblem {
+            rule,
+            level,
+            range,
+            message,
+        });
+    }
+}
+
+/// A check is called on every node of the tree and reports what it finds to the linter
+pub type Check = fn(&mut Linter, &SyntaxNode);
+
+const CHECKS: &[Check] = &[
+    check_empty_rules,
+    check_duplicate_properties,
+    check_import_statement,
+    check_universal_selector,
+    check_zero_units,
+    check_font_face_properties,
+    check_important,
+    check_float,
+    check_id_selector,
+    check_unknown_properties,
+    check_vendor_prefix,
+    check_unknown_at_rules,
+    check_unknown_pseudo_selectors,
+    check_property_values,
+];
+
+/// Problems found in the tree of `root` by all rules that are not ignored in `settings`,
+/// in the order of the nodes they were found on
+pub fn lint(root: &SyntaxNode, settings: &LintSettings, data: &CssDataManager) -> Vec<LintProblem> {
+    let mut linter = Linter {
+        configuration: LintConfiguration::new(settings),
+        data,
+        problems: Vec::new(),
+    };
+    for node in root.descendants() {
+        for check in CHECKS {
+            check(&mut linter, &node);
+        }
+    }
+    linter.problems
+}
+
+/// Declarations directly in `block`, with their lowercase property name
+fn declarations(block: &SyntaxNode) -> impl Iterator<Item = (String, SyntaxNode)> {
+    block
+        .children()
+        .filter(|c| c.kind() == SyntaxKind::DECLARATION)
+        .filter_map(|declaration| {
+            let property = declaration
+                .descendants()
+                .find(|d| d.kind() == SyntaxKind::PROPERTY)?;
+            Some((property.text().to_string().to_lowercase(), property))
+        })
+}
+
+fn check_empty_rules(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::RULE_SET || !linter.is_enabled(&lint_rules::EMPTY_RULES) {
+        return;
+    }
+    let Some(block) = node
+        .children()
+        .find(|c| c.kind() == SyntaxKind::DECLARATIONS)
+    else {
+        return;
+    };
+    if block.children().next().is_none() {
+        linter.add(&lint_rules::EMPTY_RULES, node.text_range());
+    }
+}
+
+fn check_duplicate_properties(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::DECLARATIONS
+        || !linter.is_enabled(&lint_rules::DUPLICATE_PROPERTIES)
+    {
+        return;
+    }
+    let mut properties = HashMap::<String, Vec<TextRange>>::new();
+    for (name, property) in declarations(node) {
+        properties
+            .entry(name)
+            .or_default()
+            .push(property.text_range());
+    }
+    let mut duplicates = properties
+        .into_values()
+        .filter(|ranges| ranges.len() > 1)
+        .flatten()
+        .collect::<Vec<_>>();
+    duplicates.sort_by_key(|range| range.start());
+    for range in duplicates {
+        linter.add(&lint_rules::DUPLICATE_PROPERTIES, range);
+    }
+}
+
+fn check_import_statement(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() == SyntaxKind::IMPORT {
+        linter.add(&lint_rules::IMPORT_STATEMENT, node.text_range());
+    }
+}
+
+/// Only a universal selector in the rightmost compound selector, which the browser
+/// matches against every element, is slow
+fn check_universal_selector(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::SELECTOR {
+        return;
+    }
+    let Some(last) = node
+        .children()
+        .filter(|c| c.kind() == SyntaxKind::SIMPLE_SELECTOR)
+        .last()
+    else {
+        return;
+    };
+    let universal = last
+        .children()
+        .filter(|c| c.kind() == SyntaxKind::SELECTOR_ELEMENT_NAME)
+        .flat_map(|c| c.children_with_tokens())
+        .find(|c| c.kind() == SyntaxKind::STAR);
+    if let Some(universal) = universal {
+        linter.add(&lint_rules::UNIVERSAL_SELECTOR, universal.text_range());
+    }
+}
+
+fn check_zero_units(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::NUMERIC_VALUE {
+        return;
+    }
+    let Some(dimension) = node
+        .children_with_tokens()
+        .filter_map(|c| c.into_token())
+        .find(|t| t.kind().is_dimension())
+    else {
+        return;
+    };
+    let text = dimension.text();
+    let (number, unit) = split_dimension(text);
+    let is_zero = number.parse::<f64>().is_ok_and(|n| n == 0.0);
+    if is_zero && LENGTH_UNITS.contains(&unit.to_lowercase().as_str()) {
+        linter.add(&lint_rules::ZERO_UNITS, dimension.text_range());
+    }
+}
+
+fn check_font_face_properties(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::FONT_FACE {
+        return;
+    }
+    let Some(block) = node
+        .children()
+        .find(|c| c.kind() == SyntaxKind::DECLARATIONS)
+    else {
+        return;
+    };
+    let (mut has_src, mut has_font_family) = (false, false);
+    for (name, _) in declarations(&block) {
+        has_src |= name == "src";
+        has_font_family |= name == "font-family";
+    }
+    if has_src && has_font_family {
+        return;
+    }
+    let keyword = node
+        .first_token()
+        .map_or(node.text_range(), |t| t.text_range());
+    linter.add(&lint_rules::FONT_FACE_PROPERTIES, keyword);
+}
+
+fn check_important(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() == SyntaxKind::PRIO {
+        linter.add(&lint_rules::IMPORTANT, node.text_range());
+    }
+}
+
+fn check_float(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() == SyntaxKind::PROPERTY && node.text().to_string().to_lowercase() == "float" {
+        linter.add(&lint_rules::FLOAT, node.text_range());
+    }
+}
+
+fn check_id_selector(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() == SyntaxKind::SELECTOR_IDENTIFIER {
+        linter.add(&lint_rules::ID_SELECTOR, node.text_range());
+    }
+}
+
+/// Names starting with `-`, like `-webkit-appearance`, are vendor specific and may be
+/// unknown to the data sets
+fn is_vendor_specific(name: &str) -> bool {
+    name.starts_with('-')
+}
+
+fn unknown_message(kind: &str, name: &str, candidates: impl Iterator<Item = String>) -> String {
+    match closest(name, candidates) {
+        Some(suggestion) => format!("Unknown {kind}: '{name}'. Did you mean '{suggestion}'?"),
+        None => format!("Unknown {kind}: '{name}'"),
+    }
+}
+
+/// The candidate with the smallest edit distance to `name`, if it is close enough to be
+/// a likely misspelling
+pub fn closest(name: &str, candidates: impl Iterator<Item = String>) -> Option<String> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(name, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Optimal string alignment distance: the number of insertions, deletions, substitutions
+/// and transpositions of adjacent characters that turn `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.chars().collect::<Vec<_>>(), b.chars().collect::<Vec<_>>());
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    rows[a.len()][b.len()]
+}
+
+fn check_unknown_properties(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::DECLARATION_BASIC
+        || !linter.is_enabled(&lint_rules::UNKNOWN_PROPERTIES)
+    {
+        return;
+    }
+    let Some(property) = node.children().find(|c| c.kind() == SyntaxKind::PROPERTY) else {
+        return;
+    };
+    let name = property.text().to_string().to_lowercase();
+    if name.is_empty() || is_vendor_specific(&name) {
+        return;
+    }
+    let block = node
+        .ancestors()
+        .find(|a| a.kind() == SyntaxKind::DECLARATIONS);
+    // the names in feature value blocks like `@swash` are chosen by the author
+    if block
+        .as_ref()
+        .and_then(|block| block.parent())
+        .is_some_and(|parent| parent.kind() == SyntaxKind::FONT_FEATURE_VALUE_BLOCK)
+    {
+        return;
+    }
+    let message = match block.as_ref().and_then(descriptor_at_rule) {
+        Some(at_rule) => {
+            if descriptor_names(linter.data, &at_rule).any(|d| d == name) {
+                return;
+            }
+            let candidates = descriptor_names(linter.data, &at_rule);
+            unknown_message(&format!("{at_rule} descriptor"), &name, candidates)
+        }
+        None => {
+            if linter.data.is_known_property(&name) {
+                return;
+            }
+            let candidates = linter.data.properties().map(|p| p.name.clone());
+            unknown_message("property", &name, candidates)
+        }
+    };
+    linter.add_with_message(
+        &lint_rules::UNKNOWN_PROPERTIES,
+        property.text_range(),
+        message,
+    );
+}
+
+/// The at-rule, like `@counter-style`, whose declarations in `block` are its own
+/// descriptors rather than properties
+pub fn descriptor_at_rule(block: &SyntaxNode) -> Option<String> {
+    let rule = block.parent()?;
+    if !matches!(
+        rule.kind(),
+        SyntaxKind::COUNTER_STYLE
+            | SyntaxKind::FONT_FEATURE_VALUES
+            | SyntaxKind::FONT_PALETTE_VALUES
+            | SyntaxKind::VIEW_TRANSITION
+    ) {
+        return None;
+    }
+    Some(rule.first_token()?.text().to_lowercase())
+}
+
+/// The names of the descriptors of `at_rule`
+pub fn descriptor_names<'a>(
+    data: &'a CssDataManager,
+    at_rule: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    let missing = DESCRIPTORS_MISSING_IN_DATA
+        .iter()
+        .filter(move |(rule, _, _)| *rule == at_rule)
+        .map(|(_, name, _)| (*name).to_owned());
+    data.descriptors(at_rule)
+        .map(|d| d.name.clone())
+        .chain(missing)
+}
+
+/// The standard property a vendor specific property stands for, like `appearance` for
+/// `-webkit-appearance`
+pub fn unprefixed(name: &str) -> Option<&str> {
+    let (vendor, standard) = name.strip_prefix('-')?.split_once('-')?;
+    (!vendor.is_empty() && !standard.is_empty()).then_some(standard)
+}
+
+fn check_vendor_prefix(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::DECLARATIONS || !linter.is_enabled(&lint_rules::VENDOR_PREFIX)
+    {
+        return;
+    }
+    let properties = declarations(node).collect::<Vec<_>>();
+    for (name, property) in &properties {
+        let Some(standard) = unprefixed(name) else {
+            continue;
+        };
+        if !linter.data.is_known_property(standard)
+            || properties.iter().any(|(other, _)| other == standard)
+        {
+            continue;
+        }
+        let message = format!("Also define the standard property '{standard}' for compatibility");
+        linter.add_with_message(&lint_rules::VENDOR_PREFIX, property.text_range(), message);
+    }
+}
+
+fn check_unknown_at_rules(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::UNKNOWN_AT_RULE
+        || !linter.is_enabled(&lint_rules::UNKNOWN_AT_RULES)
+    {
+        return;
+    }
+    let Some(keyword) = node.first_token() else {
+        return;
+    };
+    let name = keyword.text().to_lowercase();
+    if is_vendor_specific(name.trim_start_matches('@'))
+        || linter.data.get_at_directive(&name).is_some()
+        || AT_RULES_MISSING_IN_DATA.contains(&name.as_str())
+    {
+        return;
+    }
+    let candidates = linter
+        .data
+        .at_directives()
+        .map(|d| d.name.clone())
+        .chain(AT_RULES_MISSING_IN_DATA.iter().map(|name| name.to_string()));
+    let message = unknown_message("at rule", &name, candidates);
+    linter.add_with_message(&lint_rules::UNKNOWN_AT_RULES, keyword.text_range(), message);
+}
+
+fn check_unknown_pseudo_selectors(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::SELECTOR_PSEUDO
+        || !linter.is_enabled(&lint_rules::UNKNOWN_PSEUDO_SELECTORS)
+    {
+        return;
+    }
+    let tokens = node
+        .children_with_tokens()
+        .filter_map(|c| c.into_token())
+        .collect::<Vec<_>>();
+    let colons = tokens
+        .iter()
+        .take_while(|t| t.kind() == SyntaxKind::COLON)
+        .count();
+    let Some(name_token) = tokens
+        .get(colons)
+        .filter(|t| matches!(t.kind(), SyntaxKind::IDENTIFIER | SyntaxKind::FUNCTION))
+    else {
+        return;
+    };
+    let name = name_token.text().trim_end_matches('(').to_lowercase();
+    if is_vendor_specific(&name) {
+        return;
+    }
+
+    let data = linter.data;
+    let is_element = colons == 2;
+    let prefix = if is_element { "::" } else { ":" };
+    let known = |prefix: &str| {
+        let full_name = format!("{prefix}{name}");
+        let functional_name = format!("{full_name}()");
+        let found = if prefix == "::" {
+            data.get_pseudo_element(&full_name).is_some()
+                || data.get_pseudo_element(&functional_name).is_some()
+        } else {
+            data.get_pseudo_class(&full_name).is_some()
+                || data.get_pseudo_class(&functional_name).is_some()
+        };
+        found || PSEUDO_SELECTORS_MISSING_IN_DATA.contains(&full_name.as_str())
+    };
+    if known(prefix) || (!is_element && LEGACY_PSEUDO_ELEMENTS.contains(&name.as_str())) {
+        return;
+    }
+
+    let data_names = if is_element {
+        data.pseudo_elements()
+            .map(|p| p.name.clone())
+            .collect::<Vec<_>>()
+    } else {
+        data.pseudo_classes()
+            .map(|p| p.name.clone())
+            .collect::<Vec<_>>()
+    };
+    let candidates = data_names
+        .into_iter()
+        .chain(
+            PSEUDO_SELECTORS_MISSING_IN_DATA
+                .iter()
+                .map(|name| name.to_string()),
+        )
+        .filter(|candidate| {
+            candidate.starts_with(prefix) && !candidate[prefix.len()..].starts_with(':')
+        })
+        .map(|candidate| candidate.trim_end_matches("()").to_owned());
+    let message = unknown_message(
+        if is_element {
+            "pseudo-element"
+        } else {
+            "pseudo-class"
+        },
+        &format!("{prefix}{name}"),
+        candidates,
+    );
+    let range = TextRange::new(node.text_range().start(), name_token.text_range().end());
+    linter.add_with_message(&lint_rules::UNKNOWN_PSEUDO_SELECTORS, range, message);
+}
+
+#[cfg(test)]
+mod lint_test {
+    use std::collections::HashSet;
+
+    use super::{closest, edit_distance, lint, LintProblem};
+    use crate::css_language_types::LintSettings;
+    use crate::data::data_manager::CssDataManager;
+    use crate::row_parser::nodes_gen::SourceFile;
+    use crate::services::lint_rules::ALL_RULES;
+
+    fn lint_all(text: &str) -> Vec<LintProblem> {
+        let settings = ALL_RULES
+            .iter()
+            .map(|rule| (rule.id.to_owned(), "warning".to_owned()))
+            .collect::<LintSettings>();
+        let root = SourceFile::parse(text).tree().syntax;
+        lint(&root, &settings, &CssDataManager::new(true, None))
+    }
+
+    /// Rule id and linted text of every problem found in `text` with all rules at `warning`
+    fn problems(text: &str) -> Vec<(&'static str, String)> {
+        lint_all(text)
+            .into_iter()
+            .map(|p| (p.rule.id, text[p.range].to_owned()))
+            .collect()
+    }
+
+    fn messages(text: &str) -> Vec<String> {
+        lint_all(text).into_iter().map(|p| p.message).collect()
+    }
+
+    #[test]
+    fn unique_rule_ids() {
+        let ids = ALL_RULES.iter().map(|rule| rule.id).collect::<HashSet<_>>();
+        assert_eq!(ids.len(), ALL_RULES.len());
+    }
+
+    #[test]
+    fn default_levels() {
+        let data = CssDataManager::new(true, None);
+        let root = SourceFile::parse(".a {} #b { float: left }").tree().syntax;
+        let problems = lint(&root, &LintSettings::new(), &data);
+        let ids = problems.iter().map(|p| p.rule.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["emptyRules"]);
+
+        let settings = LintSettings::from([
+            ("emptyRules".to_owned(), "ignore".to_owned()),
+            ("float".to_owned(), "error".to_owned()),
+            ("idSelector".to_owned(), "bogus".to_owned()),
+        ]);
+        let problems = lint(&root, &settings, &data);
+        let ids = problems.iter().map(|p| p.rule.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["float"]);
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(problems(".a {}"), vec![("emptyRules", ".a {}".to_owned())]);
+        assert_eq!(
+            problems(".a { color: red; top: 0; COLOR: blue }"),
+            vec![
+                ("duplicateProperties", "color".to_owned()),
+                ("duplicateProperties", "COLOR".to_owned()),
+            ]
+        );
+        assert_eq!(
+            problems("@import url(\"a.css\");"),
+            vec![("importStatement", "@import url(\"a.css\")".to_owned())]
+        );
+        assert_eq!(
+            problems("* .a, .a > * { top: 1px }"),
+            vec![("universalSelector", "*".to_owned())]
+        );
+        assert_eq!(
+            problems(".a { top: 0.0em; left: 0%; animation-delay: 0s; height: 1px; bottom: 0px }"),
+            vec![
+                ("zeroUnits", "0.0em".to_owned()),
+                ("zeroUnits", "0px".to_owned())
+            ]
+        );
+        assert_eq!(
+            problems("@font-face { font-family: x; }"),
+            vec![("fontFaceProperties", "@font-face".to_owned())]
+        );
+        assert_eq!(
+            problems("#a { float: left !important }"),
+            vec![
+                ("idSelector", "#a".to_owned()),
+                ("float", "float".to_owned()),
+                ("important", "!important".to_owned()),
+            ]
+        );
+        assert_eq!(
+            problems(".a { FLOAT: left }"),
+            vec![("float", "FLOAT".to_owned())]
+        );
+    }
+
+    #[test]
+    fn vendor_prefix() {
+        assert_eq!(
+            problems(".a { -webkit-appearance: none; -moz-foo: 1 } .b { -moz-appearance: none; appearance: none }"),
+            vec![("vendorPrefix", "-webkit-appearance".to_owned())]
+        );
+        assert_eq!(
+            messages(".a { -webkit-appearance: none }"),
+            vec!["Also define the standard property 'appearance' for compatibility"]
+        );
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("color", "color"), 0);
+        assert_eq!(edit_distance("colr", "color"), 1);
+        assert_eq!(edit_distance("widht", "width"), 1);
+        assert_eq!(edit_distance("", "top"), 3);
+        let candidates = || ["width", "height", "top"].into_iter().map(str::to_owned);
+        assert_eq!(closest("hieght", candidates()), Some("height".to_owned()));
+        assert_eq!(closest("margin", candidates()), None);
+    }
+
+    #[test]
+    fn unknown_names() {
+        assert_eq!(
+            messages(".a { colr: red; -webkit-foo: 1; --my-color: red; COLOR: red }"),
+            vec!["Unknown property: 'colr'. Did you mean 'color'?"]
+        );
+        assert_eq!(
+            messages("@mdia screen {} @-moz-foo {} @scope (.a) {} @zzzzzzzz;"),
+            vec![
+                "Unknown at rule: '@mdia'. Did you mean '@media'?",
+                "Unknown at rule: '@zzzzzzzz'",
+            ]
+        );
+        assert_eq!(
+            problems(
+                "a:hovr, a::befor, a:before, a:-moz-foo, a:nth-child(2), a::part(x) { top: 1px }"
+            ),
+            vec![
+                ("unknownPseudoSelectors", ":hovr".to_owned()),
+                ("unknownPseudoSelectors", "::befor".to_owned()),
+            ]
+        );
+        assert_eq!(
+            messages("a:hovr, a::befor { top: 1px }"),
+            vec![
+                "Unknown pseudo-class: ':hovr'. Did you mean ':hover'?",
+                "Unknown pseudo-element: '::befor'. Did you mean '::before'?",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_descriptors() {
+        assert_eq!(
+            messages("@counter-style a { sytem: cyclic; symbols: a; color: red }"),
+            vec![
+                "Unknown @counter-style descriptor: 'sytem'. Did you mean 'system'?",
+                "Unknown @counter-style descriptor: 'color'",
+            ]
+        );
+        assert_eq!(
+            messages(
+                "@font-palette-values --x { font-family: a; base-palete: 1 }
+                @font-feature-values a { font-display: swap; @swash { fancy: 1 } }"
+            ),
+            vec!["Unknown @font-palette-values descriptor: 'base-palete'. Did you mean 'base-palette'?"]
+        );
+        assert_eq!(
+            messages("@view-transition { navigation: auto; types: slide; color: red }"),
+            vec!["Unknown @view-transition descriptor: 'color'"]
+        );
+    }
+}
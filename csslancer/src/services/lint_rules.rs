@@ -0,0 +1,138 @@
+use lsp_types::DiagnosticSeverity;
+
+use crate::css_language_types::LintSettings;
+
+/// How a rule's problems are reported, as set per rule id in the `lint` settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Ignore,
+    Warning,
+    Error,
+}
+
+impl Level {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ignore" => Some(Self::Ignore),
+            "warning" => Some(Self::Warning),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    pub fn severity(self) -> Option<DiagnosticSeverity> {
+        match self {
+            Self::Ignore => None,
+            Self::Warning => Some(DiagnosticSeverity::WARNING),
+            Self::Error => Some(DiagnosticSeverity::ERROR),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub id: &'static str,
+    pub message: &'static str,
+    pub default_level: Level,
+}
+
+impl Rule {
+    const fn new(id: &'static str, message: &'static str, default_level: Level) -> Self {
+        Self {
+            id,
+            message,
+            default_level,
+        }
+    }
+}
+
+pub const EMPTY_RULES: Rule = Rule::new("emptyRules", "Do not use empty rulesets", Level::Warning);
+pub const DUPLICATE_PROPERTIES: Rule = Rule::new(
+    "duplicateProperties",
+    "Do not use duplicate style definitions",
+    Level::Ignore,
+);
+pub const IMPORT_STATEMENT: Rule = Rule::new(
+    "importStatement",
+    "Import statements do not load in parallel",
+    Level::Ignore,
+);
+pub const UNIVERSAL_SELECTOR: Rule = Rule::new(
+    "universalSelector",
+    "The universal selector (*) is known to be slow",
+    Level::Ignore,
+);
+pub const ZERO_UNITS: Rule = Rule::new("zeroUnits", "No unit for zero needed", Level::Ignore);
+pub const FONT_FACE_PROPERTIES: Rule = Rule::new(
+    "fontFaceProperties",
+    "@font-face rule must define 'src' and 'font-family' properties",
+    Level::Warning,
+);
+pub const IMPORTANT: Rule = Rule::new(
+    "important",
+    "Avoid using !important. It is an indication that the specificity of the entire CSS has gotten out of control and needs to be refactored.",
+    Level::Ignore,
+);
+pub const FLOAT: Rule = Rule::new(
+    "float",
+    "Avoid using 'float'. Floats lead to fragile CSS that is easy to break if one aspect of the layout changes.",
+    Level::Ignore,
+);
+pub const ID_SELECTOR: Rule = Rule::new(
+    "idSelector",
+    "Selectors should not contain IDs because these rules are too tightly coupled with the HTML.",
+    Level::Ignore,
+);
+pub const UNKNOWN_PROPERTIES: Rule =
+    Rule::new("unknownProperties", "Unknown property.", Level::Warning);
+pub const UNKNOWN_AT_RULES: Rule = Rule::new("unknownAtRules", "Unknown at rule.", Level::Warning);
+pub const UNKNOWN_PSEUDO_SELECTORS: Rule = Rule::new(
+    "unknownPseudoSelectors",
+    "Unknown pseudo-class or pseudo-element.",
+    Level::Warning,
+);
+pub const VENDOR_PREFIX: Rule = Rule::new(
+    "vendorPrefix",
+    "When using a vendor-specific prefix also include the standard property",
+    Level::Warning,
+);
+pub const INVALID_PROPERTY_VALUES: Rule = Rule::new(
+    "invalidPropertyValues",
+    "Invalid property value.",
+    Level::Warning,
+);
+
+pub const ALL_RULES: &[&Rule] = &[
+    &EMPTY_RULES,
+    &DUPLICATE_PROPERTIES,
+    &IMPORT_STATEMENT,
+    &UNIVERSAL_SELECTOR,
+    &ZERO_UNITS,
+    &FONT_FACE_PROPERTIES,
+    &IMPORTANT,
+    &FLOAT,
+    &ID_SELECTOR,
+    &UNKNOWN_PROPERTIES,
+    &UNKNOWN_AT_RULES,
+    &UNKNOWN_PSEUDO_SELECTORS,
+    &VENDOR_PREFIX,
+    &INVALID_PROPERTY_VALUES,
+];
+
+/// The level of each rule: the one configured for its id, or else its default
+pub struct LintConfiguration<'a> {
+    settings: &'a LintSettings,
+}
+
+impl<'a> LintConfiguration<'a> {
+    pub fn new(settings: &'a LintSettings) -> Self {
+        Self { settings }
+    }
+
+    pub fn get_level(&self, rule: &Rule) -> Level {
+        self.settings
+            .get(rule.id)
+            .and_then(|value| Level::parse(value))
+            .unwrap_or(rule.default_level)
+    }
+}
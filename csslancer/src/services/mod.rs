@@ -1,8 +1,28 @@
+pub mod code_actions;
+pub mod code_lens;
+pub mod color;
+pub mod completion;
 pub mod css_selection_range;
 pub mod css_validation;
+pub mod definition;
+pub mod document_colors;
+pub mod document_links;
+pub mod document_symbols;
+pub mod folding_ranges;
+pub mod formatting;
 pub mod semantic_tokens;
+pub mod workspace_changes;
 pub mod hover;
+pub mod inlay_hints;
+pub mod lint;
+pub mod lint_rules;
+pub mod progress;
+pub mod references;
+pub mod rename;
 pub mod selector_printing;
+pub mod signature_help;
+pub mod value_validation;
+pub mod workspace_symbols;
 
 use crate::css_language_types::HoverSettings;
 use crate::data::data_manager::CssDataManager;
@@ -10,7 +30,7 @@ use crate::logging::LspLayer;
 use crate::workspace::source::Source;
 use crate::{
     config::{Config, SemanticTokensMode},
-    ext::InitializeParamsExt,
+    ext::{InitializeParamsExt, LocalFs},
     services::semantic_tokens::{
         get_semantic_tokens_registration, get_semantic_tokens_unregistration,
     },
@@ -27,6 +47,7 @@ use lsp_types::{
     ServerCapabilities, SignatureHelp, SignatureHelpParams, WorkDoneProgressOptions,
 };
 use serde_json::Value as JsonValue;
+use std::path::PathBuf;
 use std::sync::OnceLock;
 use tokio::sync::RwLock;
 use tower_lsp::{
@@ -44,6 +65,9 @@ use std::sync::{Arc, RwLock as SyncRwLock};
 
 use self::semantic_tokens::{get_semantic_tokens_options, SemanticTokenCache};
 
+/// Number of indexed files between two progress reports
+const INDEX_REPORT_INTERVAL: usize = 50;
+
 pub struct CssLancerServer {
     pub client: Client,
     workspace: OnceLock<RwLock<Workspace>>,
@@ -91,6 +115,20 @@ impl CssLancerServer {
         Self::new(client, lsp_tracing_layer_handle)
     }
 
+    /// A server without a client, with the configuration of a client using UTF-16
+    /// positions
+    #[cfg(test)]
+    pub fn new_test() -> Self {
+        let ls = Self::new_dud();
+        ls.const_config
+            .set(ConstConfig {
+                position_encoding: crate::config::PositionEncoding::Utf16,
+                supports_semantic_tokens_dynamic_registration: false,
+            })
+            .unwrap();
+        ls
+    }
+
     pub async fn workspace_read(&self) -> tokio::sync::RwLockReadGuard<'_, Workspace> {
         self.workspace
             .get()
@@ -131,6 +169,121 @@ impl CssLancerServer {
             .expect("const config should be initialized");
     }
 
+    /// Indexes the stylesheets under the workspace roots, reporting progress to the client
+    #[tracing::instrument(skip_all)]
+    pub async fn index_workspace(&self) {
+        let roots = self.workspace_read().await.roots().to_vec();
+        self.index_roots(roots).await;
+    }
+
+    /// Indexes the stylesheets under `roots`, reporting progress to the client. The
+    /// directories are walked on the blocking thread pool, without holding the workspace
+    /// lock.
+    pub async fn index_roots(&self, roots: Vec<Url>) {
+        let settings = self.config.read().await.files.clone();
+        let discovered = tokio::task::spawn_blocking(move || {
+            let mut paths = Vec::new();
+            for root in roots.iter() {
+                match Workspace::discover_files_in(root, &settings) {
+                    Ok(root_paths) => paths.extend(root_paths),
+                    Err(err) => error!(%err, %root, "could not discover workspace files"),
+                }
+            }
+            paths
+        })
+        .await;
+        match discovered {
+            Ok(paths) => self.index_files(paths).await,
+            Err(err) => error!(%err, "could not discover workspace files"),
+        }
+    }
+
+    /// Reads and registers the stylesheets at `paths`, reporting progress to the client.
+    /// The files are read and parsed on the blocking thread pool, and the workspace is
+    /// only locked to register each batch of them.
+    pub async fn index_files(&self, paths: Vec<PathBuf>) {
+        let work_done = self.begin_work_done("Indexing stylesheets").await;
+        let total = paths.len();
+        for (i, batch) in paths.chunks(INDEX_REPORT_INTERVAL).enumerate() {
+            let done = i * INDEX_REPORT_INTERVAL;
+            let percentage = (done * 100 / total) as u32;
+            work_done.report(format!("{done}/{total}"), percentage).await;
+
+            let batch = batch.to_vec();
+            let read = tokio::task::spawn_blocking(move || {
+                batch
+                    .iter()
+                    .filter_map(|path| match Workspace::read_file(path) {
+                        Ok(source) => Some(source),
+                        Err(err) => {
+                            warn!(%err, path = %path.display(), "could not index workspace file");
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .await;
+            match read {
+                Ok(sources) => {
+                    let mut workspace = self.workspace_write().await;
+                    for source in sources {
+                        workspace.register(source);
+                    }
+                }
+                Err(err) => error!(%err, "could not read workspace files"),
+            }
+        }
+        work_done.end(format!("Indexed {total} stylesheets")).await;
+    }
+
+    /// Indexes again those of the stylesheets at `paths` that are indexable, with their
+    /// content on disk, and evicts the ones that can no longer be read. They are checked
+    /// and read on the blocking thread pool, without holding the workspace lock.
+    pub async fn reindex_files(&self, paths: Vec<PathBuf>) {
+        let roots = self.workspace_read().await.roots().to_vec();
+        let settings = self.config.read().await.files.clone();
+        let read = tokio::task::spawn_blocking(move || {
+            paths
+                .into_iter()
+                .filter(|path| match Workspace::is_indexable(&roots, path, &settings) {
+                    Ok(indexable) => indexable,
+                    Err(err) => {
+                        warn!(
+                            %err,
+                            path = %path.display(),
+                            "could not check whether file should be indexed"
+                        );
+                        false
+                    }
+                })
+                .map(|path| {
+                    let source = Workspace::read_file(&path);
+                    (path, source)
+                })
+                .collect::<Vec<_>>()
+        })
+        .await;
+        let read = match read {
+            Ok(read) => read,
+            Err(err) => {
+                error!(%err, "could not read workspace files");
+                return;
+            }
+        };
+        let mut workspace = self.workspace_write().await;
+        for (path, source) in read {
+            match source {
+                Ok(source) => workspace.register(source),
+                Err(err) => {
+                    warn!(%err, path = %path.display(), "could not index file");
+                    if let Ok(url) = Url::from_file_path(&path) {
+                        workspace.evict(&url);
+                    }
+                }
+            }
+        }
+    }
+
     #[tracing::instrument(skip_all)]
     pub async fn on_source_changed(&self, uri: &Url) -> anyhow::Result<()> {
         //self.workspace_write().await.get_document_mut(uri).unwrap().
@@ -145,7 +298,7 @@ impl LanguageServer for CssLancerServer {
         self.tracing_init();
 
         self.workspace
-            .set(RwLock::new(Workspace::new()))
+            .set(RwLock::new(Workspace::new(params.root_uris())))
             .map_err(|_| ())
             .expect("workspace should not yet be initialized");
 
@@ -169,11 +322,6 @@ impl LanguageServer for CssLancerServer {
                 .map_err(jsonrpc::Error::invalid_params)?;
         }
 
-        if let Err(err) = self.workspace_write().await.register_files() {
-            tracing::error!(%err, "could not register workspace files on init");
-            return Err(jsonrpc::Error::internal_error());
-        }
-
         let semantic_tokens_provider =
             if self.config.read().await.semantic_tokens == SemanticTokensMode::Enable {
                 if !params.supports_semantic_tokens_dynamic_registration() {
@@ -185,6 +333,19 @@ impl LanguageServer for CssLancerServer {
                 None
             };
 
+        // Clients without pull support get diagnostics pushed on every change instead
+        let diagnostic_provider = self.supports_pull_diagnostics().then(|| {
+            DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                identifier: Some("csslancer".to_string()),
+                //identifier: None,
+                inter_file_dependencies: true,
+                workspace_diagnostics: true,
+                work_done_progress_options: WorkDoneProgressOptions {
+                    work_done_progress: None,
+                },
+            })
+        });
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
@@ -195,20 +356,56 @@ impl LanguageServer for CssLancerServer {
                         ..Default::default()
                     },
                 )),
-                diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
-                    DiagnosticOptions {
-                        identifier: Some("csslancer".to_string()),
-                        //identifier: None,
-                        inter_file_dependencies: true,
-                        workspace_diagnostics: true,
-                        work_done_progress_options: WorkDoneProgressOptions {
-                            work_done_progress: None,
-                        },
-                    },
-                )),
+                diagnostic_provider,
                 semantic_tokens_provider,
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec!["@".to_owned(), ":".to_owned()]),
+                    ..Default::default()
+                }),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_owned(), ",".to_owned()]),
+                    ..Default::default()
+                }),
                 selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                color_provider: Some(ColorProviderCapability::Simple(true)),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        ..Default::default()
+                    },
+                )),
+                document_link_provider: Some(DocumentLinkOptions {
+                    resolve_provider: Some(false),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                    first_trigger_character: "}".to_owned(),
+                    more_trigger_character: Some(vec![";".to_owned()]),
+                }),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                workspace: Some(WorkspaceServerCapabilities {
+                    workspace_folders: Some(WorkspaceFoldersServerCapabilities {
+                        supported: Some(true),
+                        change_notifications: Some(OneOf::Left(true)),
+                    }),
+                    file_operations: None,
+                }),
                 ..Default::default()
             },
             ..Default::default()
@@ -261,6 +458,10 @@ impl LanguageServer for CssLancerServer {
                 SemanticTokensMode::Disable => unregister().boxed(),
             }));
         }
+        drop(config);
+
+        self.register_file_watchers().await;
+        self.index_workspace().await;
         trace!("end of initialized");
     }
 
@@ -291,11 +492,15 @@ impl LanguageServer for CssLancerServer {
         trace!("did_close()");
         let uri = params.text_document.uri;
 
-        let mut workspace = self.workspace_write().await;
-        workspace.close(&uri);
-        drop(workspace);
+        // stylesheets in the workspace stay indexed with their content on disk
+        let path = self.workspace_write().await.close(&uri);
+        if let Some(path) = path {
+            self.reindex_files(vec![path]).await;
+        }
 
-        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+        if !self.supports_pull_diagnostics() {
+            self.client.publish_diagnostics(uri, Vec::new(), None).await;
+        }
     }
 
     #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
@@ -326,9 +531,17 @@ impl LanguageServer for CssLancerServer {
         }
     }
 
-    async fn did_change_watched_files(&self, _params: DidChangeWatchedFilesParams) {}
+    #[tracing::instrument(skip_all)]
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        trace!("did_change_watched_files()");
+        self.on_watched_files_changed(params.changes).await;
+    }
 
-    async fn did_change_workspace_folders(&self, _params: DidChangeWorkspaceFoldersParams) {}
+    #[tracing::instrument(skip_all)]
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        trace!("did_change_workspace_folders()");
+        self.on_workspace_folders_changed(params.event).await;
+    }
 
     async fn execute_command(
         &self,
@@ -358,32 +571,301 @@ impl LanguageServer for CssLancerServer {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document_position.text_document.uri))]
     async fn completion(
         &self,
-        _params: CompletionParams,
+        params: CompletionParams,
     ) -> jsonrpc::Result<Option<CompletionResponse>> {
-        Ok(None)
+        let url = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let src = match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle completion (could not lock source file)");
+                return Err(jsonrpc::Error::internal_error());
+            }
+            Ok(o) => o,
+        };
+        let config = self.config.read().await;
+        self.get_completion(&src, position, &config.completion).map_err(|err| {
+            error!(%err, %url, "error getting completion");
+            jsonrpc::Error::internal_error()
+        })
     }
 
     async fn signature_help(
         &self,
-        _params: SignatureHelpParams,
+        params: SignatureHelpParams,
     ) -> jsonrpc::Result<Option<SignatureHelp>> {
-        Ok(None)
+        let url = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle signature help (could not lock source file)");
+                jsonrpc::Result::Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(self.get_signature_help(&src, position)),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document_position_params.text_document.uri))]
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> jsonrpc::Result<Option<GotoDefinitionResponse>> {
+        let url = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        let workspace = self.workspace_read().await;
+        match workspace.get_document_ref(&url) {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle definition (could not find source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => {
+                let alias = &self.config.read().await.alias;
+                Ok(self.get_definition(&workspace, src, position, alias))
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document_position.text_document.uri))]
+    async fn references(&self, params: ReferenceParams) -> jsonrpc::Result<Option<Vec<Location>>> {
+        let url = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let workspace = self.workspace_read().await;
+        match workspace.get_document_ref(&url) {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle references (could not find source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(self.get_references(
+                &workspace,
+                src,
+                position,
+                params.context.include_declaration,
+            )),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document_position_params.text_document.uri))]
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> jsonrpc::Result<Option<Vec<DocumentHighlight>>> {
+        let url = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle document highlight (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(self.get_document_highlights(&src, position)),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> jsonrpc::Result<Option<PrepareRenameResponse>> {
+        let url = params.text_document.uri;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle prepare rename (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(self.get_prepare_rename(&src, params.position)),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document_position.text_document.uri))]
+    async fn rename(&self, params: RenameParams) -> jsonrpc::Result<Option<WorkspaceEdit>> {
+        let url = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let workspace = self.workspace_read().await;
+        match workspace.get_document_ref(&url) {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle rename (could not find source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => self
+                .get_rename(&workspace, src, position, &params.new_name)
+                .map_err(jsonrpc::Error::invalid_params),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn document_color(
+        &self,
+        params: DocumentColorParams,
+    ) -> jsonrpc::Result<Vec<ColorInformation>> {
+        let url = params.text_document.uri;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle document colors (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(self.get_document_colors(&src)),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn color_presentation(
+        &self,
+        params: ColorPresentationParams,
+    ) -> jsonrpc::Result<Vec<ColorPresentation>> {
+        Ok(self.get_color_presentations(params.color, params.range))
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> jsonrpc::Result<Option<Vec<FoldingRange>>> {
+        let url = params.text_document.uri;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle folding ranges (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(Some(self.get_folding_ranges(&src))),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn document_link(
+        &self,
+        params: DocumentLinkParams,
+    ) -> jsonrpc::Result<Option<Vec<DocumentLink>>> {
+        let url = params.text_document.uri;
+        let (references, roots) = {
+            let workspace = self.workspace_read().await;
+            match workspace.get_document_ref(&url) {
+                Err(err) => {
+                    tracing::error!(%err, %url, "could not handle document links (could not find source file)");
+                    return Err(jsonrpc::Error::internal_error());
+                }
+                Ok(src) => (self.get_link_references(src), workspace.roots().to_vec()),
+            }
+        };
+        let alias = self.config.read().await.alias.clone();
+        let links = document_links::resolve_links(references, &url, &roots, &alias, &LocalFs {})
+            .await;
+        Ok(Some(links))
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn code_action(
+        &self,
+        params: CodeActionParams,
+    ) -> jsonrpc::Result<Option<CodeActionResponse>> {
+        let url = params.text_document.uri;
+        let wants_quick_fixes = params.context.only.as_ref().is_none_or(|only| {
+            only.iter()
+                .any(|kind| CodeActionKind::QUICKFIX.as_str().starts_with(kind.as_str()))
+        });
+        if !wants_quick_fixes {
+            return Ok(None);
+        }
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle code actions (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(Some(self.get_code_actions(&src, &params.context.diagnostics))),
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn inlay_hint(&self, params: InlayHintParams) -> jsonrpc::Result<Option<Vec<InlayHint>>> {
+        let url = params.text_document.uri;
+        let workspace = self.workspace_read().await;
+        match workspace.get_document_ref(&url) {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle inlay hints (could not find source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => {
+                let config = self.config.read().await;
+                Ok(Some(self.get_inlay_hints(
+                    &workspace,
+                    src,
+                    params.range,
+                    &config.inlay_hints,
+                    &config.alias,
+                )))
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn code_lens(&self, params: CodeLensParams) -> jsonrpc::Result<Option<Vec<CodeLens>>> {
+        let url = params.text_document.uri;
+        let workspace = self.workspace_read().await;
+        match workspace.get_document_ref(&url) {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle code lens (could not find source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(Some(self.get_code_lenses(&workspace, src))),
+        }
     }
 
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
     async fn document_symbol(
         &self,
-        _params: DocumentSymbolParams,
+        params: DocumentSymbolParams,
     ) -> jsonrpc::Result<Option<DocumentSymbolResponse>> {
-        Ok(None)
+        let url = params.text_document.uri;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle document symbols (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => Ok(Some(DocumentSymbolResponse::Nested(self.get_document_symbols(&src)))),
+        }
     }
 
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> jsonrpc::Result<DocumentDiagnosticReportResult> {
+        let url = params.text_document.uri;
+        match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle diagnostics (could not lock source file)");
+                Err(jsonrpc::Error::internal_error())
+            }
+            Ok(src) => {
+                let lint = &self.config.read().await.lint;
+                Ok(DocumentDiagnosticReportResult::Report(self.get_document_diagnostic_report(
+                    &src,
+                    lint,
+                    params.previous_result_id.as_deref(),
+                )))
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> jsonrpc::Result<WorkspaceDiagnosticReportResult> {
+        let lint = &self.config.read().await.lint;
+        let workspace = self.workspace_read().await;
+        Ok(WorkspaceDiagnosticReportResult::Report(
+            self.get_workspace_diagnostic_report(&workspace, lint, &params.previous_result_ids),
+        ))
+    }
+
+    #[tracing::instrument(skip_all, fields(query = %params.query))]
     async fn symbol(
         &self,
-        _params: WorkspaceSymbolParams,
+        params: WorkspaceSymbolParams,
     ) -> jsonrpc::Result<Option<Vec<SymbolInformation>>> {
-        Ok(None)
+        let workspace = self.workspace_read().await;
+        Ok(Some(self.get_workspace_symbols(&workspace, &params.query)))
     }
 
     #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
@@ -442,8 +924,24 @@ impl LanguageServer for CssLancerServer {
         }
     }
 
-    #[tracing::instrument(skip(self))]
-    async fn did_change_configuration(&self, _params: DidChangeConfigurationParams) {}
+    #[tracing::instrument(skip_all)]
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        trace!("did_change_configuration()");
+        let mut config = self.config.write().await;
+        let files = config.files.clone();
+        if let Err(err) = config.update(&params.settings).await {
+            error!(%err, "could not update configuration");
+            return;
+        }
+        let files_changed = config.files != files;
+        drop(config);
+
+        if files_changed {
+            self.on_files_settings_changed().await;
+        }
+        self.refresh_diags().await;
+        self.refresh_inlay_hints().await;
+    }
 
     async fn selection_range(
         &self,
@@ -460,10 +958,55 @@ impl LanguageServer for CssLancerServer {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
     async fn formatting(
         &self,
-        _params: DocumentFormattingParams,
+        params: DocumentFormattingParams,
     ) -> jsonrpc::Result<Option<Vec<TextEdit>>> {
-        Ok(None)
+        let url = params.text_document.uri;
+        let src = match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle formatting (could not lock source file)");
+                return Err(jsonrpc::Error::internal_error());
+            }
+            Ok(o) => o,
+        };
+        let config = self.config.read().await;
+        Ok(self.get_formatting(&src, &params.options, &config.format))
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document.uri))]
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> jsonrpc::Result<Option<Vec<TextEdit>>> {
+        let url = params.text_document.uri;
+        let src = match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle range formatting (could not lock source file)");
+                return Err(jsonrpc::Error::internal_error());
+            }
+            Ok(o) => o,
+        };
+        let config = self.config.read().await;
+        Ok(self.get_range_formatting(&src, params.range, &params.options, &config.format))
+    }
+
+    #[tracing::instrument(skip_all, fields(uri = %params.text_document_position.text_document.uri))]
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> jsonrpc::Result<Option<Vec<TextEdit>>> {
+        let url = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let src = match self.source_read(&url).await {
+            Err(err) => {
+                tracing::error!(%err, %url, "could not handle on type formatting (could not lock source file)");
+                return Err(jsonrpc::Error::internal_error());
+            }
+            Ok(o) => o,
+        };
+        let config = self.config.read().await;
+        Ok(self.get_on_type_formatting(&src, position, &params.ch, &params.options, &config.format))
     }
 }
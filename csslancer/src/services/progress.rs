@@ -0,0 +1,95 @@
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use lsp_types::notification::Progress;
+use lsp_types::request::WorkDoneProgressCreate;
+use lsp_types::{
+    NumberOrString, ProgressParams, ProgressParamsValue, WorkDoneProgress, WorkDoneProgressBegin,
+    WorkDoneProgressCreateParams, WorkDoneProgressEnd, WorkDoneProgressReport,
+};
+use tower_lsp::Client;
+use tracing::warn;
+
+use super::CssLancerServer;
+
+static NEXT_TOKEN: AtomicI32 = AtomicI32::new(0);
+
+/// A server initiated `$/progress` report. Does nothing when the client does not
+/// support work done progress.
+pub struct WorkDone {
+    client: Client,
+    token: Option<NumberOrString>,
+}
+
+impl CssLancerServer {
+    fn supports_work_done_progress(&self) -> bool {
+        self.client_capabilities
+            .get()
+            .and_then(|capabilities| capabilities.window.as_ref())
+            .and_then(|window| window.work_done_progress)
+            .unwrap_or(false)
+    }
+
+    /// Creates a progress token with the client and reports the start of the work
+    pub async fn begin_work_done(&self, title: &str) -> WorkDone {
+        let mut work_done = WorkDone {
+            client: self.client.clone(),
+            token: None,
+        };
+        if !self.supports_work_done_progress() {
+            return work_done;
+        }
+
+        let token = NumberOrString::Number(NEXT_TOKEN.fetch_add(1, Ordering::Relaxed));
+        let create = WorkDoneProgressCreateParams {
+            token: token.clone(),
+        };
+        if let Err(err) = self
+            .client
+            .send_request::<WorkDoneProgressCreate>(create)
+            .await
+        {
+            warn!(%err, "could not create work done progress");
+            return work_done;
+        }
+        work_done.token = Some(token);
+        work_done
+            .notify(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: title.to_owned(),
+                cancellable: Some(false),
+                message: None,
+                percentage: Some(0),
+            }))
+            .await;
+        work_done
+    }
+}
+
+impl WorkDone {
+    pub async fn report(&self, message: String, percentage: u32) {
+        self.notify(WorkDoneProgress::Report(WorkDoneProgressReport {
+            cancellable: Some(false),
+            message: Some(message),
+            percentage: Some(percentage.min(100)),
+        }))
+        .await;
+    }
+
+    pub async fn end(self, message: String) {
+        self.notify(WorkDoneProgress::End(WorkDoneProgressEnd {
+            message: Some(message),
+        }))
+        .await;
+    }
+
+    async fn notify(&self, progress: WorkDoneProgress) {
+        let Some(token) = self.token.clone() else {
+            return;
+        };
+        self.client
+            .send_notification::<Progress>(ProgressParams {
+                token,
+                value: ProgressParamsValue::WorkDone(progress),
+            })
+            .await;
+    }
+}
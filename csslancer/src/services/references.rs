@@ -0,0 +1,208 @@
+use itertools::Itertools;
+use lsp_types::{DocumentHighlight, DocumentHighlightKind, Location, Position, Range};
+use rowan::{TextRange, TextSize};
+
+use super::definition::{occurrence, occurrence_at, Occurrence, Symbol, SymbolKind};
+use super::CssLancerServer;
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::interop::csslancer_to_client::text_range;
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::workspace::{source::Source, Workspace};
+
+/// All occurrences of `symbol` in the tree of `root`, in order. Only the first
+/// declaration of a layer defines it, the later ones refer to it.
+pub fn occurrences(root: &SyntaxNode, symbol: &Symbol) -> Vec<Occurrence> {
+    let mut defined = false;
+    root.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter_map(|token| occurrence(&token))
+        .filter(|found| found.symbol == *symbol)
+        .map(|mut found| {
+            if found.symbol.kind == SymbolKind::Layer && found.definition.is_some() {
+                if defined {
+                    found.definition = None;
+                }
+                defined = true;
+            }
+            found
+        })
+        .collect()
+}
+
+fn to_range(range: TextRange, src: &Source, server: &CssLancerServer) -> Range {
+    let encoding = server.const_config().position_encoding;
+    text_range(range, src, encoding)
+}
+
+impl CssLancerServer {
+    fn symbol_at(&self, src: &Source, position: Position) -> Option<Symbol> {
+        let offset = position_to_offset(position, self.const_config().position_encoding, src);
+        occurrence_at(&src.parse.tree().syntax, TextSize::from(offset as u32))
+            .map(|found| found.symbol)
+    }
+
+    /// Every occurrence in the workspace of the symbol at `position`, without the ones
+    /// that define it unless `include_declaration` is set
+    pub fn get_references(
+        &self,
+        workspace: &Workspace,
+        src: &Source,
+        position: Position,
+        include_declaration: bool,
+    ) -> Option<Vec<Location>> {
+        let symbol = self.symbol_at(src, position)?;
+        let locations = workspace
+            .files
+            .values()
+            .sorted_unstable_by(|a, b| a.url.cmp(&b.url))
+            .flat_map(|source| {
+                occurrences(&source.parse.tree().syntax, &symbol)
+                    .into_iter()
+                    .filter(|found| include_declaration || found.definition.is_none())
+                    .map(|found| {
+                        Location::new(source.url.clone(), to_range(found.range, source, self))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Some(locations)
+    }
+
+    /// Occurrences in `src` of the symbol at `position`. Definitions are writes and other
+    /// occurrences reads, except for selectors, which are plain text.
+    pub fn get_document_highlights(
+        &self,
+        src: &Source,
+        position: Position,
+    ) -> Option<Vec<DocumentHighlight>> {
+        let symbol = self.symbol_at(src, position)?;
+        let highlights = occurrences(&src.parse.tree().syntax, &symbol)
+            .into_iter()
+            .map(|found| {
+                let kind = match (symbol.kind, &found.definition) {
+                    (SymbolKind::Class | SymbolKind::Id, _) => DocumentHighlightKind::TEXT,
+                    (_, Some(_)) => DocumentHighlightKind::WRITE,
+                    (_, None) => DocumentHighlightKind::READ,
+                };
+                DocumentHighlight {
+                    range: to_range(found.range, src, self),
+                    kind: Some(kind),
+                }
+            })
+            .collect();
+        Some(highlights)
+    }
+}
+
+#[cfg(test)]
+mod references_test {
+    use lsp_types::{DocumentHighlightKind, Position, Url};
+
+    use crate::services::CssLancerServer;
+    use crate::workspace::{source::Source, Workspace};
+
+    const FILES: &[(&str, &str)] = &[
+        (
+            "test://foo/a.css",
+            ".btn, #main .btn:not(.btn-x) { --gap: 1px; margin: var(--gap); }\n@layer base;\n@layer base { .c {} }",
+        ),
+        ("test://foo/b.css", "#main > .btn { --gap: 2px; }"),
+    ];
+
+    fn references(position: Position, include_declaration: bool) -> Vec<(String, u32, u32)> {
+        let workspace = Workspace::new_test(FILES);
+        let url = Url::parse("test://foo/a.css").unwrap();
+        let src = workspace.get_document_ref(&url).unwrap();
+        CssLancerServer::new_test()
+            .get_references(&workspace, src, position, include_declaration)
+            .unwrap()
+            .into_iter()
+            .map(|l| {
+                (
+                    l.uri.path().to_owned(),
+                    l.range.start.character,
+                    l.range.end.character,
+                )
+            })
+            .collect()
+    }
+
+    fn highlights(text: &str, position: Position) -> Option<Vec<(u32, DocumentHighlightKind)>> {
+        let src = Source::new(Url::parse("test://foo/a.css").unwrap(), text, 0);
+        let highlights = CssLancerServer::new_test().get_document_highlights(&src, position)?;
+        Some(
+            highlights
+                .into_iter()
+                .map(|h| (h.range.start.character, h.kind.unwrap()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn selectors() {
+        let in_class = Position::new(0, 2);
+        assert_eq!(
+            references(in_class, true),
+            vec![
+                ("/a.css".to_owned(), 1, 4),
+                ("/a.css".to_owned(), 13, 16),
+                ("/b.css".to_owned(), 9, 12),
+            ]
+        );
+        let in_id = Position::new(0, 8);
+        assert_eq!(
+            references(in_id, true),
+            vec![("/a.css".to_owned(), 7, 11), ("/b.css".to_owned(), 1, 5)]
+        );
+    }
+
+    #[test]
+    fn custom_properties() {
+        let in_var = Position::new(0, 57);
+        assert_eq!(
+            references(in_var, true),
+            vec![
+                ("/a.css".to_owned(), 31, 36),
+                ("/a.css".to_owned(), 55, 60),
+                ("/b.css".to_owned(), 15, 20),
+            ]
+        );
+        assert_eq!(
+            references(in_var, false),
+            vec![("/a.css".to_owned(), 55, 60)]
+        );
+    }
+
+    #[test]
+    fn layers() {
+        let in_statement = Position::new(1, 8);
+        assert_eq!(
+            references(in_statement, true),
+            vec![("/a.css".to_owned(), 7, 11), ("/a.css".to_owned(), 7, 11)]
+        );
+        assert_eq!(
+            references(in_statement, false),
+            vec![("/a.css".to_owned(), 7, 11)]
+        );
+        assert_eq!(
+            highlights("@layer a, b; @layer a {}", Position::new(0, 20)),
+            Some(vec![
+                (7, DocumentHighlightKind::WRITE),
+                (20, DocumentHighlightKind::READ)
+            ])
+        );
+    }
+
+    #[test]
+    fn highlights_kinds() {
+        let text = "@keyframes spin {} .a { animation: spin 1s; }";
+        assert_eq!(
+            highlights(text, Position::new(0, 36)),
+            Some(vec![
+                (11, DocumentHighlightKind::WRITE),
+                (35, DocumentHighlightKind::READ)
+            ])
+        );
+        assert_eq!(highlights(text, Position::new(0, 41)), None);
+    }
+}
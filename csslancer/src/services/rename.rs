@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use lsp_types::{Position, PrepareRenameResponse, TextEdit, WorkspaceEdit};
+use rowan::TextSize;
+
+use super::completion::CSS_WIDE_KEYWORDS;
+use super::definition::{occurrence_at, Occurrence, SymbolKind};
+use super::references::occurrences;
+use super::CssLancerServer;
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::interop::csslancer_to_client::text_range;
+use crate::tokenizer::{extra::unescape, tokenize, TokenKind};
+use crate::workspace::{source::Source, Workspace};
+
+/// Why `name`, as written in a stylesheet, can not be the new name of a symbol of `kind`,
+/// if it can not. Names are checked with their escapes resolved, so `\31 0` is `10`.
+pub fn invalid_name_reason(kind: SymbolKind, name: &str) -> Option<String> {
+    let tokens = tokenize(name).map(|token| token.kind).collect::<Vec<_>>();
+    // layer names are dot separated identifiers
+    let is_name = match kind {
+        SymbolKind::Layer => {
+            tokens.len() % 2 == 1
+                && tokens.iter().enumerate().all(|(i, kind)| match i % 2 {
+                    0 => *kind == TokenKind::Ident,
+                    _ => *kind == TokenKind::DelimFullStop,
+                })
+        }
+        _ => tokens == [TokenKind::Ident],
+    };
+    if !is_name {
+        return Some(format!("'{name}' is not a valid identifier"));
+    }
+
+    let value = unescape(name);
+    let lowercase = value.to_lowercase();
+    match kind {
+        SymbolKind::CustomProperty if !value.starts_with("--") => Some(format!(
+            "Custom property names must start with '--', got '{value}'"
+        )),
+        SymbolKind::CustomProperty | SymbolKind::Class | SymbolKind::Id => None,
+        _ if value.starts_with("--") => {
+            Some(format!("'{value}' is reserved for custom properties"))
+        }
+        _ if CSS_WIDE_KEYWORDS
+            .iter()
+            .any(|(keyword, _)| *keyword == lowercase)
+            || lowercase == "default" =>
+        {
+            Some(format!("'{value}' is a reserved keyword"))
+        }
+        SymbolKind::Keyframes if lowercase == "none" => {
+            Some(format!("'{value}' is a reserved keyword"))
+        }
+        _ => None,
+    }
+}
+
+impl CssLancerServer {
+    fn renameable_at(&self, src: &Source, position: Position) -> Option<Occurrence> {
+        let offset = position_to_offset(position, self.const_config().position_encoding, src);
+        occurrence_at(&src.parse.tree().syntax, TextSize::from(offset as u32))
+    }
+
+    /// The range and current name of the symbol at `position`, if there is one to rename
+    pub fn get_prepare_rename(
+        &self,
+        src: &Source,
+        position: Position,
+    ) -> Option<PrepareRenameResponse> {
+        let encoding = self.const_config().position_encoding;
+        let found = self.renameable_at(src, position)?;
+        let range = text_range(found.range, src, encoding);
+        Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range,
+            placeholder: found.symbol.name,
+        })
+    }
+
+    /// Edits renaming every occurrence in the workspace of the symbol at `position` to
+    /// `new_name`, or why `new_name` is not a valid name for it
+    pub fn get_rename(
+        &self,
+        workspace: &Workspace,
+        src: &Source,
+        position: Position,
+        new_name: &str,
+    ) -> Result<Option<WorkspaceEdit>, String> {
+        let Some(found) = self.renameable_at(src, position) else {
+            return Ok(None);
+        };
+        if let Some(reason) = invalid_name_reason(found.symbol.kind, new_name) {
+            return Err(reason);
+        }
+
+        let encoding = self.const_config().position_encoding;
+        let changes: HashMap<_, _> = workspace
+            .files
+            .values()
+            .filter_map(|source| {
+                let edits = occurrences(&source.parse.tree().syntax, &found.symbol)
+                    .into_iter()
+                    .map(|occurrence| {
+                        let range = text_range(occurrence.range, source, encoding);
+                        TextEdit::new(range, new_name.to_owned())
+                    })
+                    .collect::<Vec<_>>();
+                (!edits.is_empty()).then(|| (source.url.clone(), edits))
+            })
+            .collect();
+        Ok(Some(WorkspaceEdit::new(changes)))
+    }
+}
+
+#[cfg(test)]
+mod rename_test {
+    use lsp_types::{Position, PrepareRenameResponse, Range, Url};
+
+    use super::invalid_name_reason;
+    use crate::services::definition::SymbolKind;
+    use crate::services::CssLancerServer;
+    use crate::workspace::{source::Source, Workspace};
+
+    const FILES: &[(&str, &str)] = &[
+        (
+            "test://foo/a.css",
+            ":root { --brand: red; }\n@keyframes spin {}\n@layer base;",
+        ),
+        (
+            "test://foo/b.css",
+            ".a { color: var(--brand); animation: spin 1s, -webkit-spin; }\n@layer base { .b { animation-name: spin; } }",
+        ),
+        ("test://foo/c.css", ".c { color: blue; }"),
+    ];
+
+    fn rename(position: Position, new_name: &str) -> Result<Vec<(String, u32, u32)>, String> {
+        let workspace = Workspace::new_test(FILES);
+        let url = Url::parse("test://foo/b.css").unwrap();
+        let src = workspace.get_document_ref(&url).unwrap();
+        let edit = CssLancerServer::new_test()
+            .get_rename(&workspace, src, position, new_name)?
+            .unwrap();
+        let mut edits = edit
+            .changes
+            .unwrap()
+            .into_iter()
+            .flat_map(|(url, edits)| {
+                edits.into_iter().map(move |edit| {
+                    assert_eq!(edit.new_text, new_name);
+                    (
+                        url.path().to_owned(),
+                        edit.range.start.line,
+                        edit.range.start.character,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        edits.sort();
+        Ok(edits)
+    }
+
+    #[test]
+    fn prepare() {
+        let src = Source::new(
+            Url::parse("test://foo/a.css").unwrap(),
+            "@layer a.b; .c { color: red; }",
+            0,
+        );
+        assert_eq!(
+            CssLancerServer::new_test().get_prepare_rename(&src, Position::new(0, 8)),
+            Some(PrepareRenameResponse::RangeWithPlaceholder {
+                range: Range::new(Position::new(0, 7), Position::new(0, 10)),
+                placeholder: "a.b".to_owned(),
+            })
+        );
+        assert_eq!(
+            CssLancerServer::new_test().get_prepare_rename(&src, Position::new(0, 19)),
+            None
+        );
+    }
+
+    #[test]
+    fn workspace_edits() {
+        let edits = vec![("/a.css".to_owned(), 0, 8), ("/b.css".to_owned(), 0, 16)];
+        assert_eq!(rename(Position::new(0, 18), "--accent"), Ok(edits));
+
+        let edits = vec![
+            ("/a.css".to_owned(), 1, 11),
+            ("/b.css".to_owned(), 0, 37),
+            ("/b.css".to_owned(), 1, 35),
+        ];
+        assert_eq!(rename(Position::new(0, 38), "rotate"), Ok(edits));
+
+        let edits = vec![("/a.css".to_owned(), 2, 7), ("/b.css".to_owned(), 1, 7)];
+        assert_eq!(rename(Position::new(1, 8), "base.reset"), Ok(edits));
+    }
+
+    #[test]
+    fn invalid_names() {
+        assert!(rename(Position::new(0, 18), "accent").is_err());
+        assert!(rename(Position::new(0, 38), "1turn").is_err());
+        assert!(rename(Position::new(0, 38), "none").is_err());
+        assert!(rename(Position::new(0, 38), "fade in").is_err());
+        assert!(invalid_name_reason(SymbolKind::Keyframes, "\\31 0").is_none());
+        assert!(invalid_name_reason(SymbolKind::Keyframes, "INHERIT").is_some());
+        assert!(invalid_name_reason(SymbolKind::Keyframes, "\\69nherit").is_some());
+        assert!(invalid_name_reason(SymbolKind::CustomProperty, "-\\2dx").is_none());
+        assert!(invalid_name_reason(SymbolKind::CustomProperty, "-x").is_some());
+        assert!(invalid_name_reason(SymbolKind::Layer, "base.").is_some());
+        assert!(invalid_name_reason(SymbolKind::Class, "btn-primary").is_none());
+    }
+}
@@ -206,8 +206,8 @@ impl Quotes {
     }
 }
 
-#[derive(Debug, Default)]
-struct Specificity {
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Specificity {
     // count of identifiers (e.g. `#app`)
     pub id: usize,
     // count of attributes (`[type="number"]`), classes (`.container-fluid`), and pseudo-classes (`:hover`)
@@ -414,6 +414,11 @@ impl SelectorPrinting {
         }
     }
 
+    /// Specificity of a `SELECTOR` or `SIMPLE_SELECTOR`
+    pub fn selector_specificity(&self, selector: &SyntaxNode<CssLanguage>) -> Specificity {
+        self.calculate_score(selector)
+    }
+
     fn selector_to_specificity_marked_string(&self, selector: &SyntaxNode<CssLanguage>) -> MarkedString {
         assert!(matches!(selector.kind(), SyntaxKind::SELECTOR | SyntaxKind::SIMPLE_SELECTOR));
         let specificity = self.calculate_score(selector);
@@ -592,6 +597,8 @@ struct SelectorElementBuilder<'a> {
     ele_tree: &'a mut Tree<Element>,
     prev_node: Option<SyntaxNode<CssLanguage>>,
     element: NodeId,
+    /// Element matched by the start of the `@scope` rule around the selector, which `:scope` refers to
+    scope_element: Option<NodeId>,
 }
 
 impl<'a> SelectorElementBuilder<'a> {
@@ -600,6 +607,7 @@ impl<'a> SelectorElementBuilder<'a> {
             ele_tree,
             prev_node: None,
             element,
+            scope_element: None,
         }
     }
 
@@ -621,7 +629,15 @@ impl<'a> SelectorElementBuilder<'a> {
             }
         }
 
-        for mut selector_child in selector.children() {
+        for (i, mut selector_child) in selector.children().enumerate() {
+            if let (0, Some(scope_element)) = (i, self.scope_element) {
+                if is_scope_pseudo(&selector_child) {
+                    // a leading `:scope` continues from the scoping root instead of a descendant
+                    self.element = scope_element;
+                    self.prev_node = Some(selector_child);
+                    continue
+                }
+            }
             if selector_child.kind() == SyntaxKind::SIMPLE_SELECTOR {                
                 if let Some(prev) = &self.prev_node {
                     // we go deeper in the tree
@@ -668,6 +684,14 @@ fn is_new_selector_context(node: &SyntaxNode<CssLanguage>) -> bool {
     matches!(node.kind(), SyntaxKind::XCSS_MIXIN_DECLARATION | SyntaxKind::SOURCE_FILE)
 }
 
+/// Whether `node` is a simple selector consisting of only `:scope`
+fn is_scope_pseudo(node: &SyntaxNode<CssLanguage>) -> bool {
+    let mut children = node.children();
+    node.kind() == SyntaxKind::SIMPLE_SELECTOR
+        && children.next().is_some_and(|ch| ch.kind() == SyntaxKind::SELECTOR_PSEUDO && ch.text().to_string().eq_ignore_ascii_case(":scope"))
+        && children.next().is_none()
+}
+
 /// Creates `Tree<Element>` for CssNodeType::Selector at `node_id` in `node_tree`, searching upwards of `node_id` for parent rulesets
 fn selector_to_element(typed_node: &nodes_gen::Selector) -> Option<Tree<Element>> {
     let syntax_node = typed_node.syntax();
@@ -676,12 +700,17 @@ fn selector_to_element(typed_node: &nodes_gen::Selector) -> Option<Tree<Element>
         return None
     }
 
-    let mut parent_rule_sets: Vec<nodes_gen::RuleSet> = Vec::new();
+    // first selectors of the parent rulesets, and of the starts of the `@scope` rules around
+    // the selector, which the selector is matched within
+    let mut parent_selectors: Vec<(nodes_gen::Selector, bool)> = Vec::new();
     let maybe_rule_set = syntax_node.parent();
 
     if let Some(maybe_rule_set) = maybe_rule_set {
-        if maybe_rule_set.kind() == SyntaxKind::RULE_SET {
+        if matches!(maybe_rule_set.kind(), SyntaxKind::RULE_SET | SyntaxKind::SCOPE_START | SyntaxKind::SCOPE_END) {
             let mut parent = maybe_rule_set.parent(); // parent of the selector's ruleset
+            if maybe_rule_set.kind() == SyntaxKind::SCOPE_START {
+                parent = parent.and_then(|scope| scope.parent());
+            }
             while let Some(par) = parent {
                 if is_new_selector_context(&par) {break}
                 if let Some(typed) = nodes_gen::RuleSet::cast(par.clone()) {
@@ -689,7 +718,13 @@ fn selector_to_element(typed_node: &nodes_gen::Selector) -> Option<Tree<Element>
                     if selectors.into_iter().fold(String::new(), |acc, nex| acc + &nex.syntax.text().to_string()) == "@at-root" {{
                         break;
                     }}
-                    parent_rule_sets.push(typed);
+                    if let Some(sel) = typed.selectors().next() {
+                        parent_selectors.push((sel, false));
+                    }
+                } else if let Some(typed) = nodes_gen::Scope::cast(par.clone()) {
+                    if let Some(sel) = typed.scope_start().and_then(|start| start.selectors().next()) {
+                        parent_selectors.push((sel, true));
+                    }
                 }
                 parent = par.parent();
             }
@@ -699,11 +734,10 @@ fn selector_to_element(typed_node: &nodes_gen::Selector) -> Option<Tree<Element>
     let mut ele_tree = Tree::new(Element::default());
     let ele_tree_root = ele_tree.root().id();
     let mut builder = SelectorElementBuilder::new(&mut ele_tree, ele_tree_root); 
-    for rule_set in parent_rule_sets.into_iter().rev() { 
-        let mut selectors = rule_set.selectors();
-        
-        if let Some(sel) = selectors.next() {
-            builder.process_selector(&sel);
+    for (sel, is_scope_start) in parent_selectors.into_iter().rev() { 
+        builder.process_selector(&sel);
+        if is_scope_start {
+            builder.scope_element = Some(builder.element);
         }
     }
 
@@ -909,6 +943,19 @@ mod selector_printing_test {
         assert_selector("e1 ~ e2 { }", "e2", "{e1|⋮|e2}"); //pass
     }
 
+    #[test]
+    fn scope() {
+        assert_selector("@scope (.card) { img { } }", "img", "{[class=card]{…{img}}}");
+        assert_selector("@scope (.card) to (.content) { :scope > img { } }", "img", "{[class=card]{img}}");
+        assert_selector("@scope (.card) { :scope { } }", ":scope", "{[class=card]}");
+        assert_selector("@scope (.card) { .a { :scope > img { } } }", "img", "{[class=card]{…{[class=a]}|img}}");
+        assert_selector("@scope (.card) to (.content) { }", "content", "{[class=card]{…{[class=content]}}}");
+        assert_selector("@scope (.card) to (:scope > .content) { }", "content", "{[class=card]{[class=content]}}");
+        assert_selector(".a { @scope (.b) { img { } } }", "img", "{[class=a]{…{[class=b]{…{img}}}}}");
+        assert_selector("@scope { img { } }", "img", "{img}");
+        assert_selector(":scope > img { }", "img", "{[:scope=]{img}}");
+    }
+
     #[test]
     fn escaping() {
         assert_selector("#\\34 04-error { }", "#\\34 04-error", "{[id=404-error]}");
@@ -0,0 +1,201 @@
+use lsp_types::{
+    Documentation, ParameterInformation, ParameterLabel, Position, SignatureHelp,
+    SignatureInformation,
+};
+use rowan::TextSize;
+
+use super::CssLancerServer;
+use crate::css_language_types::{FunctionData, HoverSettings};
+use crate::data::entry::{get_entry_description, IEntry2};
+use crate::interop::client_to_csslancer::position_to_offset;
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::source::Source;
+
+/// The functions whose arguments `offset` lies between the parentheses of, innermost
+/// first, with their lowercase names
+fn functions_around(root: &SyntaxNode, offset: TextSize) -> Vec<(SyntaxNode, String)> {
+    let Some(mut token) = root.token_at_offset(offset).left_biased() else {
+        return Vec::new();
+    };
+    // whitespace after an unclosed function at the end of the file is not part of it
+    if token.kind() == SyntaxKind::WHITESPACE {
+        if let Some(prev) = token.prev_token() {
+            token = prev;
+        }
+    }
+    token
+        .parent_ancestors()
+        .filter(|node| node.kind() == SyntaxKind::FUNCTION_WITH_ARGS)
+        .filter_map(|function| {
+            let mut tokens = function
+                .children_with_tokens()
+                .filter_map(|child| child.into_token());
+            let name = tokens.find(|t| t.kind() == SyntaxKind::FUNCTION)?;
+            let r_paren = tokens.find(|t| t.kind() == SyntaxKind::R_PAREN);
+            let inside = name.text_range().end() <= offset
+                && r_paren.is_none_or(|r_paren| offset <= r_paren.text_range().start());
+            let name = name.text().trim_end_matches('(').to_lowercase();
+            inside.then_some((function, name))
+        })
+        .collect()
+}
+
+/// Index of the argument of `function` at `offset`, by the commas separating its
+/// `FUNCTION_ARGUMENT`s before it
+fn argument_index(function: &SyntaxNode, offset: TextSize) -> usize {
+    function
+        .children_with_tokens()
+        .filter(|child| child.kind() == SyntaxKind::COMMA && child.text_range().end() <= offset)
+        .count()
+}
+
+/// The signature of `function`, `name(<a>, <b>)`, with the label of each parameter as
+/// its UTF-16 offsets in it
+fn signature_label(function: &FunctionData) -> (String, Vec<[u32; 2]>) {
+    let utf16_len = |s: &str| s.encode_utf16().count() as u32;
+    let mut label = format!("{}(", function.name);
+    let mut offsets = Vec::new();
+    for (i, parameter) in function.parameters.iter().enumerate() {
+        if i > 0 {
+            label += ", ";
+        }
+        let start = utf16_len(&label);
+        label += &parameter.name;
+        offsets.push([start, utf16_len(&label)]);
+    }
+    label += ")";
+    (label, offsets)
+}
+
+impl CssLancerServer {
+    /// The signature of the innermost function with known parameters that `position` is
+    /// inside the parentheses of, with the parameter of the argument at `position` active.
+    /// The last parameter stays active in the extra arguments when it is a comma
+    /// separated list, like `<calc-sum>#` in `min()`.
+    pub fn get_signature_help(&self, src: &Source, position: Position) -> Option<SignatureHelp> {
+        let offset = position_to_offset(position, self.const_config().position_encoding, src);
+        let offset = TextSize::from(offset as u32);
+        let (function, data) = functions_around(&src.parse.tree().syntax, offset)
+            .into_iter()
+            .find_map(|(function, name)| {
+                Some((function, self.css_data_manager.get_function(&name)?))
+            })?;
+
+        let mut active = argument_index(&function, offset) as u32;
+        let count = data.parameters.len() as u32;
+        if active >= count
+            && data
+                .parameters
+                .last()
+                .is_some_and(|p| p.name.ends_with('#'))
+        {
+            active = count - 1;
+        }
+
+        let (label, offsets) = signature_label(data);
+        let parameters = data
+            .parameters
+            .iter()
+            .zip(offsets)
+            .map(|(parameter, offsets)| ParameterInformation {
+                label: ParameterLabel::LabelOffsets(offsets),
+                documentation: parameter.description.clone().map(Documentation::String),
+            })
+            .collect();
+        let settings = Some(HoverSettings {
+            documentation: true,
+            references: true,
+        });
+        let documentation =
+            get_entry_description(IEntry2::Func(data), self.does_support_markdown(), &settings)
+                .map(Documentation::MarkupContent);
+        Some(SignatureHelp {
+            signatures: vec![SignatureInformation {
+                label,
+                documentation,
+                parameters: Some(parameters),
+                active_parameter: None,
+            }],
+            active_signature: Some(0),
+            active_parameter: Some(active),
+        })
+    }
+}
+
+#[cfg(test)]
+mod signature_help_test {
+    use lsp_types::{ParameterLabel, Position, Url};
+
+    use crate::services::CssLancerServer;
+    use crate::workspace::source::Source;
+
+    /// The signature label and the label of the active parameter at the `|` in `text`
+    fn signature_help(text: &str) -> Option<(String, String)> {
+        let ls = CssLancerServer::new_test();
+        let offset = text.find('|').unwrap();
+        let src = Source::new(
+            Url::parse("test://foo/a.css").unwrap(),
+            &text.replace('|', ""),
+            0,
+        );
+        let help = ls.get_signature_help(&src, Position::new(0, offset as u32))?;
+        let signature = &help.signatures[0];
+        let parameters = signature.parameters.as_ref().unwrap();
+        let ParameterLabel::LabelOffsets([start, end]) =
+            parameters[help.active_parameter.unwrap() as usize].label
+        else {
+            panic!("expected label offsets");
+        };
+        let label = signature.label.encode_utf16().collect::<Vec<_>>();
+        let parameter = String::from_utf16(&label[start as usize..end as usize]).unwrap();
+        Some((signature.label.clone(), parameter))
+    }
+
+    #[test]
+    fn active_parameter() {
+        let clamp = "clamp(<min>, <preferred>, <max>)".to_owned();
+        assert_eq!(
+            signature_help(".a { width: clamp(|1px, 2vw, 3px); }"),
+            Some((clamp.clone(), "<min>".to_owned()))
+        );
+        assert_eq!(
+            signature_help(".a { width: clamp(1px, 2vw|, 3px); }"),
+            Some((clamp.clone(), "<preferred>".to_owned()))
+        );
+        assert_eq!(
+            signature_help(".a { width: clamp(1px, 2vw, |"),
+            Some((clamp, "<max>".to_owned()))
+        );
+        assert_eq!(
+            signature_help(".a { color: color-mix(in srgb, red 10%, |); }")
+                .unwrap()
+                .1,
+            "<color> <percentage>?"
+        );
+        assert_eq!(signature_help(".a { t: cubic-bezier(|").unwrap().1, "<x1>");
+        assert_eq!(
+            signature_help(".a { width: min(1px, 2px, |3px); }")
+                .unwrap()
+                .1,
+            "<calc-sum>#"
+        );
+    }
+
+    #[test]
+    fn innermost_known_function() {
+        let text = ".a { grid-template-columns: repeat(2, minmax(0, |1fr)); }";
+        assert_eq!(signature_help(text).unwrap().1, "<max>");
+        let text = ".a { grid-template-columns: repeat(2, minmax(0, 1fr)|); }";
+        assert_eq!(signature_help(text).unwrap().1, "<track-list>");
+        let text = ".a { grid-template-columns: repeat(|2, 1fr); }";
+        assert_eq!(
+            signature_help(text).unwrap().1,
+            "<integer [1,∞]> | auto-fill | auto-fit"
+        );
+        let text = ".a { width: calc(1px + rgb(|1 2 3)); }";
+        assert_eq!(signature_help(text).unwrap().1, "<calc-sum>");
+        assert_eq!(signature_help(".a { width: clamp|(1px, 2vw, 3px); }"), None);
+        assert_eq!(signature_help(".a { width: clamp(1px, 2vw, 3px)|; }"), None);
+    }
+}
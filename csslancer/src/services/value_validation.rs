@@ -0,0 +1,619 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::OnceLock;
+
+use super::completion::CSS_WIDE_KEYWORDS;
+use super::lint::{descriptor_at_rule, Linter};
+use super::lint_rules;
+use crate::data::data_manager::CssDataManager;
+use crate::data::facts::{
+    COLORS, COLOR_FUNCTIONS, COLOR_KEYWORDS, DESCRIPTORS_MISSING_IN_DATA, FONT_FEATURE_VALUE_TYPES,
+    LENGTH_UNITS, MATH_FUNCTIONS, PROPERTY_SYNTAXES_MISSING_IN_DATA, SYSTEM_COLORS, VALUE_TYPES,
+};
+use crate::data::value_syntax::{self, Term};
+use crate::row_parser::nodes_types::{SyntaxNode, SyntaxToken};
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+
+/// Nesting depth of `<'property'>` references and type definitions after which any
+/// value is accepted
+const MAX_DEPTH: usize = 16;
+
+/// Values with more components than this are not validated, to bound matching time
+const MAX_COMPONENTS: usize = 64;
+
+/// A component value of a declaration, as far as the value syntax is concerned
+#[derive(Debug, Clone, PartialEq)]
+pub enum Component {
+    Ident(String),
+    Number {
+        value: f64,
+        is_integer: bool,
+    },
+    /// A number with a unit, `%` for percentages
+    Dimension {
+        value: f64,
+        unit: String,
+    },
+    String,
+    Url,
+    Hash(String),
+    Function {
+        name: String,
+        arguments: Vec<Component>,
+    },
+    Comma,
+    Slash,
+    /// Anything else, like `[names]` or `U+0-7F`, only accepted by types we do not know
+    Other,
+}
+
+/// Splits a dimension like `-1.5e2px` into its number and unit
+pub fn split_dimension(text: &str) -> (&str, &str) {
+    let mut unit_start = 0;
+    let mut prev = None;
+    for (i, c) in text.char_indices() {
+        let is_number_char = c.is_ascii_digit()
+            || c == '.'
+            || ((c == '+' || c == '-') && matches!(prev, None | Some('e' | 'E')))
+            || ((c == 'e' || c == 'E')
+                && text[i + 1..]
+                    .trim_start_matches(['+', '-'])
+                    .starts_with(|c: char| c.is_ascii_digit())
+                && prev.is_some_and(|p: char| p.is_ascii_digit()));
+        if !is_number_char {
+            break;
+        }
+        unit_start = i + c.len_utf8();
+        prev = Some(c);
+    }
+    text.split_at(unit_start)
+}
+
+/// The component values of the value `expression`, or `None` when it depends on
+/// something that is only known when computing it, like `var()`, or has vendor
+/// specific parts
+pub fn components(expression: &SyntaxNode) -> Option<Vec<Component>> {
+    let mut components = Vec::new();
+    push_node(expression, &mut components)?;
+    Some(components)
+}
+
+fn push_node(node: &SyntaxNode, out: &mut Vec<Component>) -> Option<()> {
+    match node.kind() {
+        SyntaxKind::EXPRESSION
+        | SyntaxKind::BINARY_EXPRESSION
+        | SyntaxKind::TERM
+        | SyntaxKind::OPERATOR
+        | SyntaxKind::NUMERIC_VALUE => {
+            for child in node.children_with_tokens() {
+                match child {
+                    rowan::NodeOrToken::Node(child) => push_node(&child, out)?,
+                    rowan::NodeOrToken::Token(token) => push_token(&token, out)?,
+                }
+            }
+        }
+        SyntaxKind::HEX_COLOR_VALUE => out.push(Component::Hash(node.text().to_string())),
+        SyntaxKind::URI_LITERAL => out.push(Component::Url),
+        SyntaxKind::FUNCTION_WITH_ARGS => {
+            let name = node
+                .first_token()?
+                .text()
+                .trim_end_matches('(')
+                .to_lowercase();
+            if matches!(name.as_str(), "var" | "env" | "attr") || name.starts_with('-') {
+                return None;
+            }
+            let mut arguments = Vec::new();
+            for child in node.children_with_tokens() {
+                match child {
+                    rowan::NodeOrToken::Node(argument) => push_node(&argument, &mut arguments)?,
+                    rowan::NodeOrToken::Token(token) if token.kind() == SyntaxKind::COMMA => {
+                        arguments.push(Component::Comma)
+                    }
+                    rowan::NodeOrToken::Token(_) => {}
+                }
+            }
+            out.push(Component::Function { name, arguments });
+        }
+        SyntaxKind::FUNCTION_ARGUMENT => {
+            for child in node.children() {
+                push_node(&child, out)?;
+            }
+        }
+        _ => out.push(Component::Other),
+    }
+    Some(())
+}
+
+fn push_token(token: &SyntaxToken, out: &mut Vec<Component>) -> Option<()> {
+    let text = token.text();
+    let component = match token.kind() {
+        SyntaxKind::WHITESPACE | SyntaxKind::COMMENT => return Some(()),
+        SyntaxKind::IDENTIFIER => {
+            if text.starts_with('-') && !text.starts_with("--") {
+                return None;
+            }
+            Component::Ident(text.to_owned())
+        }
+        kind if kind.is_cx_keyword() => Component::Ident(text.to_owned()),
+        SyntaxKind::NUMBER => Component::Number {
+            value: text.parse().ok()?,
+            is_integer: !text.contains(['.', 'e', 'E']),
+        },
+        kind if kind.is_dimension() => {
+            let (number, unit) = split_dimension(text);
+            Component::Dimension {
+                value: number.parse().ok()?,
+                unit: unit.to_lowercase(),
+            }
+        }
+        SyntaxKind::STRING => Component::String,
+        SyntaxKind::ID_HASH => Component::Hash(text.to_owned()),
+        SyntaxKind::COMMA => Component::Comma,
+        SyntaxKind::SLASH => Component::Slash,
+        _ => Component::Other,
+    };
+    out.push(component);
+    Some(())
+}
+
+/// Reports declarations of style rules whose value does not match the syntax of their
+/// property, and declarations of at-rules like `@counter-style` whose value does not
+/// match the syntax of their descriptor
+pub fn check_property_values(linter: &mut Linter, node: &SyntaxNode) {
+    if node.kind() != SyntaxKind::DECLARATION_BASIC
+        || !linter.is_enabled(&lint_rules::INVALID_PROPERTY_VALUES)
+    {
+        return;
+    }
+    let Some(block) = node
+        .ancestors()
+        .find(|a| a.kind() == SyntaxKind::DECLARATIONS)
+    else {
+        return;
+    };
+    let (Some(rule), Some(property), Some(expression)) = (
+        block.parent(),
+        node.children().find(|c| c.kind() == SyntaxKind::PROPERTY),
+        node.children().find(|c| c.kind() == SyntaxKind::EXPRESSION),
+    ) else {
+        return;
+    };
+    let name = property.text().to_string().to_lowercase();
+    let syntax = match rule.kind() {
+        SyntaxKind::RULE_SET => property_syntax(linter.data, &name),
+        SyntaxKind::FONT_FEATURE_VALUE_BLOCK => rule.first_token().and_then(|keyword| {
+            let keyword = keyword.text().to_lowercase();
+            FONT_FEATURE_VALUE_TYPES
+                .iter()
+                .find(|(block, _)| *block == keyword)
+                .map(|(_, syntax)| *syntax)
+        }),
+        // the descriptors of other at-rules, like `@font-face`, are not validated
+        _ => descriptor_at_rule(&block)
+            .and_then(|at_rule| descriptor_syntax(linter.data, &at_rule, &name)),
+    };
+    let Some(syntax) = syntax else {
+        return;
+    };
+    let Some(term) = parsed(linter.data, syntax) else {
+        return;
+    };
+    let Some(components) = components(&expression) else {
+        return;
+    };
+    if components.is_empty() || components.len() > MAX_COMPONENTS {
+        return;
+    }
+    if let [Component::Ident(ident)] = components.as_slice() {
+        if CSS_WIDE_KEYWORDS
+            .iter()
+            .any(|(keyword, _)| ident.eq_ignore_ascii_case(keyword))
+        {
+            return;
+        }
+    }
+
+    let matcher = Matcher {
+        data: linter.data,
+        components: &components,
+    };
+    if !matcher.matches_all(term, 0) {
+        let message = format!("Invalid value for '{name}'. Expected: {syntax}");
+        linter.add_with_message(
+            &lint_rules::INVALID_PROPERTY_VALUES,
+            expression.text_range(),
+            message,
+        );
+    }
+}
+
+/// The value definition syntax of the property `name`. For some properties, like `width`,
+/// the data only knows the descriptor of the same name of an at-rule like `@viewport`.
+fn property_syntax<'a>(data: &'a CssDataManager, name: &str) -> Option<&'a str> {
+    let property = data.get_property(name)?;
+    if property.at_rule.is_none() {
+        return property.syntax.as_deref();
+    }
+    PROPERTY_SYNTAXES_MISSING_IN_DATA
+        .iter()
+        .find(|(property, _)| *property == name)
+        .map(|(_, syntax)| *syntax)
+}
+
+/// The value definition syntax of the descriptor `name` of `at_rule`
+fn descriptor_syntax<'a>(data: &'a CssDataManager, at_rule: &str, name: &str) -> Option<&'a str> {
+    data.get_descriptor(at_rule, name)
+        .and_then(|d| d.syntax.as_deref())
+        .or_else(|| {
+            DESCRIPTORS_MISSING_IN_DATA
+                .iter()
+                .find(|(rule, descriptor, _)| *rule == at_rule && *descriptor == name)
+                .map(|(_, _, syntax)| *syntax)
+        })
+}
+
+/// The parsed value definition syntaxes in the facts, by their text
+fn fact_syntaxes() -> &'static HashMap<&'static str, Term> {
+    static SYNTAXES: OnceLock<HashMap<&'static str, Term>> = OnceLock::new();
+    SYNTAXES.get_or_init(|| {
+        let descriptors = DESCRIPTORS_MISSING_IN_DATA
+            .iter()
+            .map(|(_, _, syntax)| *syntax);
+        let types = FONT_FEATURE_VALUE_TYPES
+            .iter()
+            .chain(PROPERTY_SYNTAXES_MISSING_IN_DATA)
+            .chain(VALUE_TYPES)
+            .map(|(_, syntax)| *syntax);
+        descriptors
+            .chain(types)
+            .filter_map(|syntax| Some((syntax, value_syntax::parse(syntax)?)))
+            .collect()
+    })
+}
+
+/// The parsed value definition `syntax`, from the data or the facts missing there, so
+/// each syntax is only parsed once
+fn parsed<'a>(data: &'a CssDataManager, syntax: &str) -> Option<&'a Term> {
+    data.get_syntax(syntax)
+        .or_else(|| fact_syntaxes().get(syntax))
+}
+
+/// Matches component values against a value definition. Every match function returns
+/// all positions at which a match of the term starting at `start` can end.
+struct Matcher<'a> {
+    data: &'a CssDataManager,
+    components: &'a [Component],
+}
+
+impl Matcher<'_> {
+    fn matches_all(&self, term: &Term, depth: usize) -> bool {
+        self.ends(term, 0, depth).contains(&self.components.len())
+    }
+
+    fn ends(&self, term: &Term, start: usize, depth: usize) -> BTreeSet<usize> {
+        let next = || BTreeSet::from([start + 1]);
+        let component = self.components.get(start);
+        match term {
+            Term::Keyword(keyword) => match component {
+                Some(Component::Ident(ident)) if ident.eq_ignore_ascii_case(keyword) => next(),
+                _ => BTreeSet::new(),
+            },
+            Term::Literal(literal) => match (literal.as_str(), component) {
+                (",", Some(Component::Comma)) | ("/", Some(Component::Slash)) => next(),
+                _ => BTreeSet::new(),
+            },
+            Term::Type { name, range } => self.type_ends(name, *range, start, depth),
+            Term::Property(property) => {
+                let term = property_syntax(self.data, property)
+                    .and_then(|syntax| parsed(self.data, syntax));
+                match term {
+                    Some(term) if depth < MAX_DEPTH => self.ends(term, start, depth + 1),
+                    _ => self.any_ends(start),
+                }
+            }
+            Term::Function { name, arguments } => match component {
+                Some(Component::Function {
+                    name: function,
+                    arguments: values,
+                }) if function.eq_ignore_ascii_case(name) => {
+                    let matcher = Matcher {
+                        data: self.data,
+                        components: values,
+                    };
+                    let matches = match arguments {
+                        Some(arguments) => matcher.matches_all(arguments, depth),
+                        None => values.is_empty(),
+                    };
+                    if matches {
+                        next()
+                    } else {
+                        BTreeSet::new()
+                    }
+                }
+                _ => BTreeSet::new(),
+            },
+            Term::Sequence(terms) => terms.iter().fold(BTreeSet::from([start]), |starts, term| {
+                starts
+                    .into_iter()
+                    .flat_map(|start| self.ends(term, start, depth))
+                    .collect()
+            }),
+            Term::OneOf(terms) => terms
+                .iter()
+                .flat_map(|term| self.ends(term, start, depth))
+                .collect(),
+            Term::AllOf(terms) => self.unordered_ends(terms, start, depth, true),
+            Term::AnyOf(terms) => self.unordered_ends(terms, start, depth, false),
+            Term::Repeat {
+                term,
+                min,
+                max,
+                comma_separated,
+            } => self.repeat_ends(term, *min, *max, *comma_separated, start, depth),
+        }
+    }
+
+    /// `&&` when `all` is set, else `||`: the terms in any order, each at most once
+    fn unordered_ends(
+        &self,
+        terms: &[Term],
+        start: usize,
+        depth: usize,
+        all: bool,
+    ) -> BTreeSet<usize> {
+        let full = (1u64 << terms.len()) - 1;
+        let mut ends = BTreeSet::new();
+        let mut seen = BTreeSet::from([(start, 0u64)]);
+        let mut todo = vec![(start, 0u64)];
+        while let Some((pos, used)) = todo.pop() {
+            if (all && used == full) || (!all && used != 0) {
+                ends.insert(pos);
+            }
+            for (i, term) in terms.iter().enumerate() {
+                if used & (1 << i) != 0 {
+                    continue;
+                }
+                for end in self.ends(term, pos, depth) {
+                    if seen.insert((end, used | (1 << i))) {
+                        todo.push((end, used | (1 << i)));
+                    }
+                }
+            }
+        }
+        ends
+    }
+
+    fn repeat_ends(
+        &self,
+        term: &Term,
+        min: usize,
+        max: Option<usize>,
+        comma_separated: bool,
+        start: usize,
+        depth: usize,
+    ) -> BTreeSet<usize> {
+        let mut ends = BTreeSet::new();
+        let mut seen = BTreeSet::new();
+        let mut current = BTreeSet::from([start]);
+        let mut count = 0;
+        loop {
+            if count >= min {
+                ends.extend(current.iter().copied());
+            }
+            if max.is_some_and(|max| count >= max) || current.is_empty() {
+                break;
+            }
+            // past `min`, positions that were reached before can not lead anywhere new
+            if count >= min && current.iter().all(|pos| seen.contains(pos)) {
+                break;
+            }
+            if count >= min {
+                seen.extend(current.iter().copied());
+            }
+            current = current
+                .into_iter()
+                .filter_map(
+                    |pos| match (comma_separated && count > 0, self.components.get(pos)) {
+                        (false, _) => Some(pos),
+                        (true, Some(Component::Comma)) => Some(pos + 1),
+                        (true, _) => None,
+                    },
+                )
+                .flat_map(|pos| self.ends(term, pos, depth))
+                .collect();
+            count += 1;
+            if count > self.components.len() + min {
+                break;
+            }
+        }
+        ends
+    }
+
+    /// A type we can not check matches any components
+    fn any_ends(&self, start: usize) -> BTreeSet<usize> {
+        (start + 1..=self.components.len()).collect()
+    }
+
+    fn type_ends(
+        &self,
+        name: &str,
+        range: Option<(f64, f64)>,
+        start: usize,
+        depth: usize,
+    ) -> BTreeSet<usize> {
+        let Some(component) = self.components.get(start) else {
+            return BTreeSet::new();
+        };
+        if let Some(matches) = matches_primitive(name, range, component) {
+            return if matches {
+                BTreeSet::from([start + 1])
+            } else {
+                BTreeSet::new()
+            };
+        }
+        let definition = VALUE_TYPES
+            .iter()
+            .find(|(type_name, _)| *type_name == name)
+            .and_then(|(_, syntax)| parsed(self.data, syntax));
+        match definition {
+            Some(term) if depth < MAX_DEPTH => self.ends(term, start, depth + 1),
+            _ => self.any_ends(start),
+        }
+    }
+}
+
+/// Whether `component` is of the basic type `name`, `None` if `name` is not a basic type
+fn matches_primitive(name: &str, range: Option<(f64, f64)>, component: &Component) -> Option<bool> {
+    let in_range = |value: f64| range.is_none_or(|(min, max)| min <= value && value <= max);
+    let is_math = matches!(component, Component::Function { name, .. } if MATH_FUNCTIONS.contains(&name.as_str()));
+    let dimension_of = |units: &[&str]| match component {
+        Component::Dimension { value, unit } => units.contains(&unit.as_str()) && in_range(*value),
+        _ => is_math,
+    };
+    let zero = matches!(component, Component::Number { value, .. } if *value == 0.0);
+    let number = match component {
+        Component::Number { value, .. } => in_range(*value),
+        _ => is_math,
+    };
+    let percentage = dimension_of(&["%"]);
+
+    let matches = match name {
+        "length" => dimension_of(LENGTH_UNITS) || zero,
+        "percentage" => percentage,
+        "length-percentage" => dimension_of(LENGTH_UNITS) || zero || percentage,
+        "number" => number,
+        "integer" => match component {
+            Component::Number { value, is_integer } => *is_integer && in_range(*value),
+            _ => is_math,
+        },
+        "angle" => dimension_of(&["deg", "grad", "rad", "turn"]) || zero,
+        "angle-percentage" => dimension_of(&["deg", "grad", "rad", "turn"]) || zero || percentage,
+        "time" => dimension_of(&["s", "ms"]),
+        "time-percentage" => dimension_of(&["s", "ms"]) || percentage,
+        "frequency" => dimension_of(&["hz", "khz"]),
+        "resolution" => dimension_of(&["dpi", "dpcm", "dppx", "x"]),
+        "flex" => dimension_of(&["fr"]),
+        "string" => matches!(component, Component::String),
+        "url" => match component {
+            Component::Url => true,
+            Component::Function { name, .. } => name == "url" || name == "src",
+            _ => false,
+        },
+        "image" => matches!(component, Component::Url | Component::Function { .. }),
+        "color" => match component {
+            Component::Ident(ident) => {
+                let ident = ident.to_lowercase();
+                COLORS.iter().any(|(color, _)| *color == ident)
+                    || COLOR_KEYWORDS.iter().any(|(keyword, _)| *keyword == ident)
+                    || SYSTEM_COLORS.contains(&ident.as_str())
+            }
+            Component::Hash(hash) => {
+                let digits = hash.trim_start_matches('#');
+                matches!(digits.len(), 3 | 4 | 6 | 8)
+                    && digits.chars().all(|c| c.is_ascii_hexdigit())
+            }
+            Component::Function { name, .. } => {
+                COLOR_FUNCTIONS.iter().any(|f| f.name == name)
+                    || matches!(name.as_str(), "light-dark" | "device-cmyk")
+            }
+            _ => false,
+        },
+        "ident" => matches!(component, Component::Ident(_)),
+        "custom-ident" => match component {
+            Component::Ident(ident) => !CSS_WIDE_KEYWORDS
+                .iter()
+                .any(|(keyword, _)| ident.eq_ignore_ascii_case(keyword)),
+            _ => false,
+        },
+        "dashed-ident" => matches!(component, Component::Ident(ident) if ident.starts_with("--")),
+        _ => return None,
+    };
+    Some(matches)
+}
+
+#[cfg(test)]
+mod value_validation_test {
+    use super::split_dimension;
+    use crate::css_language_types::LintSettings;
+    use crate::data::data_manager::CssDataManager;
+    use crate::row_parser::nodes_gen::SourceFile;
+    use crate::services::lint::lint;
+
+    fn invalid_values(text: &str) -> Vec<String> {
+        let root = SourceFile::parse(text).tree().syntax;
+        let settings = LintSettings::from([("emptyRules".to_owned(), "ignore".to_owned())]);
+        lint(&root, &settings, &CssDataManager::new(true, None))
+            .into_iter()
+            .filter(|p| p.rule.id == "invalidPropertyValues")
+            .map(|p| text[p.range].to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn dimensions() {
+        assert_eq!(split_dimension("1px"), ("1", "px"));
+        assert_eq!(split_dimension("-1.5e2em"), ("-1.5e2", "em"));
+        assert_eq!(split_dimension("+.5ex"), ("+.5", "ex"));
+        assert_eq!(split_dimension("10%"), ("10", "%"));
+        assert_eq!(split_dimension("2e"), ("2", "e"));
+    }
+
+    #[test]
+    fn valid_values() {
+        let text = ".a {
+            margin: 1px -2px 3% auto;
+            padding: 0;
+            top: calc(100% - 2px);
+            z-index: 3;
+            color: rgb(1 2 3 / 50%);
+            border: 1px solid #ff000080;
+            font: italic 12px/1.5 \"A\", serif;
+            transition: opacity 1s ease-in, color 2s;
+            display: inline flex;
+            opacity: var(--x);
+            width: inherit;
+            display: -webkit-box;
+        }";
+        assert_eq!(invalid_values(text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn invalid_values_are_reported() {
+        let text = ".a { width: red; margin: 1px 2px 3px 4px 5px; z-index: 1.5; }";
+        assert_eq!(
+            invalid_values(text),
+            vec!["red", "1px 2px 3px 4px 5px", "1.5"]
+        );
+        let text = ".a { height: red; min-width: red; max-height: auto; font-weight: banana; font-stretch: 10px }";
+        assert_eq!(
+            invalid_values(text),
+            vec!["red", "red", "auto", "banana", "10px"]
+        );
+        let text = ".a { width: fit-content; min-height: -webkit-fill-available; max-width: none;
+            font-weight: 650; font-style: oblique 10deg; font-family: Arial, \"B\", sans-serif; zoom: 150% }";
+        assert_eq!(invalid_values(text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn descriptors_are_not_validated() {
+        let text = "@font-face { font-weight: 100 900; }";
+        assert_eq!(invalid_values(text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn descriptor_values() {
+        let text = "@counter-style a { system: extends decimal; symbols: \"*\" b url(x.png); pad: 3 \"0\" }
+            @font-palette-values --x { font-family: Font One, \"B\"; base-palette: 1; override-colors: 0 red }
+            @font-feature-values a { font-display: swap; @styleset { x: 1 20; } @character-variant { y: 2 } }
+            @view-transition { navigation: auto; types: slide forwards }";
+        assert_eq!(invalid_values(text), Vec::<String>::new());
+        let text = "@counter-style a { system: foo; range: auto auto }
+            @font-palette-values --x { base-palette: foo }
+            @font-feature-values a { font-display: fast; @swash { x: a } @styleset { y: 21 } }
+            @view-transition { navigation: always }";
+        assert_eq!(
+            invalid_values(text),
+            vec!["foo", "auto auto", "foo", "fast", "a", "21", "always"]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use lsp_types::{
+    DidChangeWatchedFilesRegistrationOptions, FileChangeType, FileEvent, FileSystemWatcher,
+    GlobPattern, Registration, Unregistration, WatchKind, WorkspaceFoldersChangeEvent,
+};
+use tracing::{error, trace};
+
+use super::CssLancerServer;
+
+const WATCHED_FILES_REGISTRATION_ID: &str = "csslancer/didChangeWatchedFiles";
+const WATCHED_FILES_METHOD_ID: &str = "workspace/didChangeWatchedFiles";
+
+/// Watches every file the `include` glob can match in any workspace root, and the
+/// deletion of anything, since deleting a folder only reports the folder. Events are
+/// filtered again by `Workspace::is_indexable`.
+pub fn get_watched_files_registration(include: &str) -> Registration {
+    let options = DidChangeWatchedFilesRegistrationOptions {
+        watchers: vec![
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String(format!(
+                    "**/{}",
+                    include.trim_start_matches("**/")
+                )),
+                kind: None,
+            },
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/*".to_owned()),
+                kind: Some(WatchKind::Delete),
+            },
+        ],
+    };
+    Registration {
+        id: WATCHED_FILES_REGISTRATION_ID.to_owned(),
+        method: WATCHED_FILES_METHOD_ID.to_owned(),
+        register_options: Some(
+            serde_json::to_value(options)
+                .expect("watched files options should be representable as JSON value"),
+        ),
+    }
+}
+
+impl CssLancerServer {
+    fn supports_watched_files_dynamic_registration(&self) -> bool {
+        self.client_capabilities
+            .get()
+            .and_then(|capabilities| capabilities.workspace.as_ref())
+            .and_then(|workspace| workspace.did_change_watched_files)
+            .and_then(|watched_files| watched_files.dynamic_registration)
+            .unwrap_or(false)
+    }
+
+    pub async fn register_file_watchers(&self) {
+        if !self.supports_watched_files_dynamic_registration() {
+            trace!("client does not support dynamic registration of file watchers");
+            return;
+        }
+        let include = self.config.read().await.files.include.clone();
+        let registration = get_watched_files_registration(&include);
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            error!(%err, "could not register file watchers");
+        }
+    }
+
+    /// Replaces the file watcher, whose glob depends on `files.include`, and indexes the
+    /// workspace again from scratch
+    pub async fn on_files_settings_changed(&self) {
+        if self.supports_watched_files_dynamic_registration() {
+            let unregistration = Unregistration {
+                id: WATCHED_FILES_REGISTRATION_ID.to_owned(),
+                method: WATCHED_FILES_METHOD_ID.to_owned(),
+            };
+            if let Err(err) = self
+                .client
+                .unregister_capability(vec![unregistration])
+                .await
+            {
+                error!(%err, "could not unregister file watchers");
+            }
+        }
+        self.register_file_watchers().await;
+
+        self.workspace_write().await.evict_all();
+        self.index_workspace().await;
+    }
+
+    /// Re-indexes created and changed stylesheets, evicts deleted ones and refreshes
+    /// the diagnostics of open documents, which may depend on them
+    pub async fn on_watched_files_changed(&self, changes: Vec<FileEvent>) {
+        let mut paths = Vec::new();
+        for change in changes {
+            let Ok(path) = change.uri.to_file_path() else {
+                continue;
+            };
+            if change.typ == FileChangeType::DELETED {
+                // this may be a folder, with all its stylesheets
+                self.workspace_write().await.evict_under(&path);
+            } else {
+                paths.push(path);
+            }
+        }
+        self.reindex_files(paths).await;
+        self.refresh_diags().await;
+    }
+
+    /// Indexes the stylesheets of added folders and evicts those of removed folders
+    pub async fn on_workspace_folders_changed(&self, event: WorkspaceFoldersChangeEvent) {
+        {
+            let mut workspace = self.workspace_write().await;
+            for folder in event.removed.iter() {
+                workspace.remove_root(&folder.uri);
+            }
+            for folder in event.added.iter() {
+                workspace.add_root(folder.uri.clone());
+            }
+        }
+
+        let added = event.added.into_iter().map(|folder| folder.uri).collect();
+        self.index_roots(added).await;
+        self.refresh_diags().await;
+    }
+}
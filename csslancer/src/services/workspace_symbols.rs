@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+
+use super::CssLancerServer;
+
+use lsp_types::{Location, SymbolInformation, SymbolKind};
+use rowan::TextRange;
+
+use crate::interop::csslancer_to_client;
+use crate::row_parser::nodes_types::SyntaxNode;
+use crate::row_parser::syntax_kind_gen::SyntaxKind;
+use crate::workspace::Workspace;
+
+/// Results after the best this many matches are dropped
+const MAX_SYMBOLS: usize = 100;
+
+impl CssLancerServer {
+    /// Class selectors, ids, custom properties, keyframes names and layer names of all
+    /// files in `workspace` that fuzzy match `query`, best matches first. Each name is
+    /// only listed once per file, at its first occurrence.
+    pub fn get_workspace_symbols(
+        &self,
+        workspace: &Workspace,
+        query: &str,
+    ) -> Vec<SymbolInformation> {
+        let position_encoding = self.const_config().position_encoding;
+        let query = query.to_lowercase();
+
+        let mut matches = Vec::new();
+        for src in workspace.files.values() {
+            let mut seen = HashSet::new();
+            for (name, kind, range) in named_symbols(&src.parse.tree().syntax) {
+                if !seen.insert(name.clone()) {
+                    continue;
+                }
+                let Some(score) = fuzzy_score(&query, &name) else {
+                    continue;
+                };
+                let range = csslancer_to_client::text_range(range, src, position_encoding);
+                // `SymbolInformation::deprecated` is deprecated in favour of `tags`
+                #[allow(deprecated)]
+                let symbol = SymbolInformation {
+                    name,
+                    kind,
+                    tags: None,
+                    deprecated: None,
+                    location: Location::new(src.url.clone(), range),
+                    container_name: None,
+                };
+                matches.push((score, symbol));
+            }
+        }
+
+        matches.sort_by(|(a_score, a), (b_score, b)| {
+            a_score
+                .cmp(b_score)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.location.uri.cmp(&b.location.uri))
+        });
+        matches
+            .into_iter()
+            .take(MAX_SYMBOLS)
+            .map(|(_, symbol)| symbol)
+            .collect()
+    }
+}
+
+/// Every name in the stylesheet that can be searched for across the workspace
+fn named_symbols(root: &SyntaxNode) -> impl Iterator<Item = (String, SymbolKind, TextRange)> {
+    root.descendants().filter_map(|node| {
+        let (kind, named) = match node.kind() {
+            SyntaxKind::SELECTOR_CLASS => (SymbolKind::CLASS, node),
+            SyntaxKind::SELECTOR_IDENTIFIER => (SymbolKind::FIELD, node),
+            SyntaxKind::DECLARATION_CUSTOM_PROPERTY => (
+                SymbolKind::VARIABLE,
+                node.children().find(|c| c.kind() == SyntaxKind::PROPERTY)?,
+            ),
+            SyntaxKind::KEYFRAME => {
+                let name = node
+                    .children_with_tokens()
+                    .filter_map(|c| c.into_token())
+                    .find(|t| t.kind() == SyntaxKind::IDENTIFIER)?;
+                return Some((
+                    name.text().to_owned(),
+                    SymbolKind::FUNCTION,
+                    name.text_range(),
+                ));
+            }
+            SyntaxKind::LAYER_NAME => (SymbolKind::NAMESPACE, node),
+            _ => return None,
+        };
+        Some((named.text().to_string(), kind, named.text_range()))
+    })
+}
+
+/// Matches when all characters of the lowercase `query` appear in `name` in order,
+/// ignoring case. Lower scores are better: every skipped character of `name` costs one.
+fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let mut score = 0;
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    for query_char in query.chars() {
+        loop {
+            if name_chars.next()? == query_char {
+                break;
+            }
+            score += 1;
+        }
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod workspace_symbols_test {
+    use lsp_types::{SymbolKind, Url};
+
+    use super::{fuzzy_score, MAX_SYMBOLS};
+    use crate::{
+        config::FilesSettings,
+        services::CssLancerServer,
+        workspace::{source::Source, Workspace},
+    };
+
+    #[test]
+    fn fuzzy() {
+        assert_eq!(fuzzy_score("", ".btn"), Some(0));
+        assert_eq!(fuzzy_score(".btn", ".btn-primary"), Some(0));
+        assert_eq!(fuzzy_score("bp", ".btn-primary"), Some(4));
+        assert_eq!(fuzzy_score("pb", ".btn-primary"), None);
+    }
+
+    #[test]
+    fn symbols_in_open_documents() {
+        let mut workspace = Workspace::new(Vec::new());
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let text = "#main .btn-primary { --accent: red; }
+            @keyframes fade-in {}
+            @layer base { .btn {} .btn:hover {} }";
+        workspace.open(url.clone(), Source::new(url, text, 0));
+
+        let symbols = CssLancerServer::new_test().get_workspace_symbols(&workspace, "");
+        let mut names = symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.kind))
+            .collect::<Vec<_>>();
+        names.sort_by_key(|(name, _)| *name);
+        assert_eq!(
+            names,
+            vec![
+                ("#main", SymbolKind::FIELD),
+                ("--accent", SymbolKind::VARIABLE),
+                (".btn", SymbolKind::CLASS),
+                (".btn-primary", SymbolKind::CLASS),
+                ("base", SymbolKind::NAMESPACE),
+                ("fade-in", SymbolKind::FUNCTION),
+            ]
+        );
+
+        let symbols = CssLancerServer::new_test().get_workspace_symbols(&workspace, "BTN");
+        let names = symbols.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec![".btn", ".btn-primary"]);
+        assert_eq!(symbols[0].location.range.start.line, 2);
+    }
+
+    #[test]
+    fn results_are_capped() {
+        let mut workspace = Workspace::new(Vec::new());
+        let url = Url::parse("test://foo/bar.css").unwrap();
+        let text = (0..MAX_SYMBOLS + 10)
+            .map(|i| format!(".c{i} {{}}"))
+            .collect::<String>();
+        workspace.open(url.clone(), Source::new(url, &text, 0));
+
+        let symbols = CssLancerServer::new_test().get_workspace_symbols(&workspace, "");
+        assert_eq!(symbols.len(), MAX_SYMBOLS);
+    }
+
+    #[test]
+    fn symbols_on_disk() {
+        let root = std::env::temp_dir().join(format!(
+            "csslancer-workspace-symbols-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(root.join("nested")).unwrap();
+        std::fs::create_dir_all(root.join(".hidden")).unwrap();
+        std::fs::write(root.join("nested/a.css"), ".on-disk {}").unwrap();
+        std::fs::write(root.join(".hidden/b.css"), ".hidden {}").unwrap();
+        std::fs::write(root.join("c.txt"), ".not-css {}").unwrap();
+
+        let mut workspace = Workspace::new(vec![Url::from_directory_path(&root).unwrap()]);
+        workspace.register_files(&FilesSettings::default()).unwrap();
+        let names = CssLancerServer::new_test()
+            .get_workspace_symbols(&workspace, "")
+            .into_iter()
+            .map(|s| s.name)
+            .collect::<Vec<_>>();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(names, vec![".on-disk".to_owned()]);
+    }
+}
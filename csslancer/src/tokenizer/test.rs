@@ -3,12 +3,13 @@
 
 #[cfg(test)]
 mod test_css_lexer {
-    use super::super::{TokenKind, TokenKind::*, tokenize_file};
+    use super::super::{TokenKind, TokenKind::*, tokenize, tokenize_file};
 
     fn ast(input: &str, expected_token_kinds: Vec<TokenKind>) {
         //expected_token_kinds.push(TokenKind::Eof);
-        let mut tokens = tokenize_file(input);
-        println!("tokens: {}", tokenize_file(input).into_iter().map(|t| format!("{:?}", t.kind)).collect::<Vec<std::string::String>>().join(" > "));
+        let mut diags = Vec::new();
+        let mut tokens = tokenize_file(input, &mut diags);
+        println!("tokens: {}", tokenize(input).into_iter().map(|t| format!("{:?}", t.kind)).collect::<Vec<std::string::String>>().join(" > "));
         for expected in expected_token_kinds {
             let received = tokens.next();
             assert!(received.is_some(), "less tokens than expected on input `{input}`, expecting token `{expected:?}`");
@@ -1,15 +1,26 @@
 pub mod source;
 
 use anyhow::anyhow;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::WalkBuilder;
 use lsp_types::{TextDocumentContentChangeEvent, Url};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tracing::warn;
 
-use crate::config::PositionEncoding;
+use crate::config::{FilesSettings, PositionEncoding};
+use crate::ext::LocalFs;
 use crate::interop::ClientRange;
 use source::Source;
 
 pub struct Workspace {
+    /// Open documents and the stylesheets indexed from disk
     pub files: HashMap<Url, Source>,
+    /// Files whose content in `files` is owned by the client rather than read from disk
+    open_files: HashSet<Url>,
+    /// Folders whose stylesheets are indexed by `register_files`
+    roots: Vec<Url>,
     // cache
     // id: FileId,
     // text: Prehashed<String>,
@@ -18,22 +29,52 @@ pub struct Workspace {
 }
 
 impl Workspace {
-    pub fn new() -> Self {
+    pub fn new(roots: Vec<Url>) -> Self {
         Self {
             files: HashMap::new(),
+            open_files: HashSet::new(),
+            roots,
         }
     }
 
+    /// A workspace without roots that indexes `files`, pairs of URL and text
+    #[cfg(test)]
+    pub fn new_test(files: &[(&str, &str)]) -> Self {
+        let mut workspace = Self::new(Vec::new());
+        for (url, text) in files {
+            let url = Url::parse(url).unwrap();
+            workspace.register(Source::new(url, text, 0));
+        }
+        workspace
+    }
+
     pub fn open(&mut self, url: Url, doc: Source) {
+        self.open_files.insert(url.clone());
         self.files.insert(url, doc);
     }
 
-    pub fn close(&mut self, uri: &Url) {
+    /// Forgets the document at `uri`. Returns its path on disk, if it has one, for the
+    /// caller to index it again with its content on disk when `is_indexable`.
+    pub fn close(&mut self, uri: &Url) -> Option<PathBuf> {
+        self.open_files.remove(uri);
         self.files.remove(uri);
+        uri.to_file_path().ok()
+    }
+
+    pub fn is_open(&self, uri: &Url) -> bool {
+        self.open_files.contains(uri)
+    }
+
+    fn is_in_roots(&self, path: &Path) -> bool {
+        self.roots
+            .iter()
+            .filter_map(|root| root.to_file_path().ok())
+            .any(|root| path.starts_with(root))
     }
 
     pub fn clear(&mut self) {
         self.files.clear();
+        self.open_files.clear();
     }
 
     // pub fn get_document_cloned(&self, uri: &Url) -> FsResult<Source> {
@@ -52,10 +93,187 @@ impl Workspace {
             .ok_or_else(|| FsError::NotProvided(anyhow!("URI not found")))
     }
 
-    pub fn register_files(&mut self) -> FsResult<()> {
+    /// Indexes every stylesheet under the workspace roots, see `discover_files`.
+    /// Files that cannot be read are skipped with a warning.
+    pub fn register_files(&mut self, settings: &FilesSettings) -> FsResult<()> {
+        for path in self.discover_files(settings)? {
+            match Self::read_file(&path) {
+                Ok(source) => self.register(source),
+                Err(err) => warn!(%err, path = %path.display(), "could not index workspace file"),
+            }
+        }
         Ok(())
     }
 
+    /// Paths of the stylesheets under the workspace roots, see `discover_files_in`
+    pub fn discover_files(&self, settings: &FilesSettings) -> FsResult<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for root in self.roots.iter() {
+            paths.extend(Self::discover_files_in(root, settings)?);
+        }
+        Ok(paths)
+    }
+
+    /// Paths of the stylesheets under `root` that match `settings.include`. Hidden files,
+    /// paths excluded by `.gitignore` or `.ignore` files and paths matching `settings.exclude`
+    /// (for directories, everything in them) are skipped.
+    pub fn discover_files_in(root: &Url, settings: &FilesSettings) -> FsResult<Vec<PathBuf>> {
+        let filter = FileFilter::new(settings)?;
+        let Ok(root) = root.to_file_path() else {
+            warn!(%root, "skipping workspace root that is not a local path");
+            return Ok(Vec::new());
+        };
+
+        let exclude = filter.exclude.clone();
+        let walk_root = root.clone();
+        let walk = WalkBuilder::new(&root)
+            .require_git(false)
+            .filter_entry(move |entry| {
+                let relative = entry
+                    .path()
+                    .strip_prefix(&walk_root)
+                    .unwrap_or(entry.path());
+                !exclude.is_match(relative)
+            })
+            .build();
+
+        let mut paths = Vec::new();
+        for entry in walk {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    warn!(%err, "could not walk workspace directory");
+                    continue;
+                }
+            };
+            let relative = entry.path().strip_prefix(&root).unwrap_or(entry.path());
+            if entry.file_type().is_some_and(|t| t.is_file()) && filter.include.is_match(relative) {
+                paths.push(entry.into_path());
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Whether `discover_files` would find the file at `path` under one of `roots`. This
+    /// reads the ignore files on the way, so it blocks.
+    pub fn is_indexable(roots: &[Url], path: &Path, settings: &FilesSettings) -> FsResult<bool> {
+        let filter = FileFilter::new(settings)?;
+        let Some(root) = roots
+            .iter()
+            .filter_map(|root| root.to_file_path().ok())
+            .find(|root| path.starts_with(root))
+        else {
+            return Ok(false);
+        };
+        let relative = path.strip_prefix(&root).unwrap_or(path);
+        if !filter.include.is_match(relative) {
+            return Ok(false);
+        }
+
+        // walk down from the root like `discover_files_in`, collecting ignore files on the way
+        let mut ignores = Vec::new();
+        let mut current = root.clone();
+        for component in relative.components() {
+            ignores.extend(ignore_file_matcher(&current));
+            current.push(component);
+            let is_dir = current != path;
+            let is_hidden = component.as_os_str().to_string_lossy().starts_with('.');
+            let is_excluded = filter
+                .exclude
+                .is_match(current.strip_prefix(&root).unwrap_or(&current));
+            // the deepest ignore file with a matching rule decides
+            let is_ignored = ignores
+                .iter()
+                .rev()
+                .map(|ignore| ignore.matched(&current, is_dir))
+                .find(|m| !m.is_none())
+                .is_some_and(|m| m.is_ignore());
+            if is_hidden || is_excluded || is_ignored {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn roots(&self) -> &[Url] {
+        &self.roots
+    }
+
+    pub fn add_root(&mut self, root: Url) {
+        if !self.roots.contains(&root) {
+            self.roots.push(root);
+        }
+    }
+
+    /// Removes `root` and evicts the stylesheets under it, except those that are open or
+    /// also under another root
+    pub fn remove_root(&mut self, root: &Url) {
+        self.roots.retain(|r| r != root);
+        let Ok(root) = root.to_file_path() else {
+            return;
+        };
+        let evicted = self
+            .files
+            .keys()
+            .filter(|url| {
+                url.to_file_path()
+                    .is_ok_and(|path| path.starts_with(&root) && !self.is_in_roots(&path))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for url in evicted.iter() {
+            self.evict(url);
+        }
+    }
+
+    /// Removes the stylesheet at `path`, or every stylesheet in the folder at `path`, from
+    /// the index, except those the client has open
+    pub fn evict_under(&mut self, path: &Path) {
+        let evicted = self
+            .files
+            .keys()
+            .filter(|url| url.to_file_path().is_ok_and(|p| p.starts_with(path)))
+            .cloned()
+            .collect::<Vec<_>>();
+        for url in evicted.iter() {
+            self.evict(url);
+        }
+    }
+
+    /// Removes the stylesheet at `url` from the index, unless the client has it open
+    pub fn evict(&mut self, url: &Url) {
+        if !self.is_open(url) {
+            self.files.remove(url);
+        }
+    }
+
+    /// Removes every stylesheet read from disk from the index, keeping open documents
+    pub fn evict_all(&mut self) {
+        let open_files = &self.open_files;
+        self.files.retain(|url, _| open_files.contains(url));
+    }
+
+    pub fn open_files(&self) -> impl Iterator<Item = &Url> {
+        self.open_files.iter()
+    }
+
+    /// Reads and parses the stylesheet at `path`, without registering it
+    pub fn read_file(path: &Path) -> FsResult<Source> {
+        let url = LocalFs::path_to_uri(path).map_err(|err| FsError::Other(err.into()))?;
+        let text = std::fs::read_to_string(path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => FsError::NotFoundLocal(path.to_owned()),
+            _ => FsError::OtherIo(err),
+        })?;
+        Ok(Source::new(url, &text, 0))
+    }
+
+    /// Indexes `source` read from disk, unless the client has it open
+    pub fn register(&mut self, source: Source) {
+        if !self.is_open(&source.url) {
+            self.files.insert(source.url.clone(), source);
+        }
+    }
+
     pub fn edit(
         &mut self,
         uri: &Url,
@@ -108,3 +326,206 @@ pub enum FsError {
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
+
+/// The compiled globs of `FilesSettings`
+struct FileFilter {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl FileFilter {
+    fn new(settings: &FilesSettings) -> FsResult<Self> {
+        Ok(Self {
+            include: glob_set(std::slice::from_ref(&settings.include))?,
+            exclude: glob_set(&settings.exclude)?,
+        })
+    }
+}
+
+fn glob_set(globs: &[String]) -> FsResult<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for glob in globs {
+        builder.add(Glob::new(glob).map_err(|err| FsError::Other(err.into()))?);
+    }
+    builder.build().map_err(|err| FsError::Other(err.into()))
+}
+
+/// Rules of the `.gitignore` and `.ignore` files in `dir`, if any
+fn ignore_file_matcher(dir: &Path) -> Option<Gitignore> {
+    let mut builder = GitignoreBuilder::new(dir);
+    let mut has_rules = false;
+    for name in [".gitignore", ".ignore"] {
+        let path = dir.join(name);
+        if path.is_file() {
+            if let Some(err) = builder.add(path) {
+                warn!(%err, dir = %dir.display(), "could not read ignore file");
+            }
+            has_rules = true;
+        }
+    }
+    if !has_rules {
+        return None;
+    }
+    builder
+        .build()
+        .inspect_err(|err| warn!(%err, dir = %dir.display(), "invalid ignore file"))
+        .ok()
+}
+
+#[cfg(test)]
+mod workspace_test {
+    use lsp_types::Url;
+    use std::path::{Path, PathBuf};
+
+    use super::{source::Source, Workspace};
+    use crate::config::FilesSettings;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("csslancer-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    fn write(root: &Path, path: &str, text: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, text).unwrap();
+    }
+
+    fn relative_paths(workspace: &Workspace, root: &Path, settings: &FilesSettings) -> Vec<String> {
+        let mut paths = workspace
+            .discover_files(settings)
+            .unwrap()
+            .into_iter()
+            .map(|p| {
+                p.strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn discover_files() {
+        let root = temp_root("discover-files");
+        write(&root, ".gitignore", "dist/\n");
+        write(&root, "a.css", "");
+        write(&root, "styles/b.css", "");
+        write(&root, "styles/c.scss", "");
+        write(&root, "dist/d.css", "");
+        write(&root, "node_modules/pkg/e.css", "");
+        write(&root, "vendor/f.css", "");
+        let workspace = Workspace::new(vec![Url::from_directory_path(&root).unwrap()]);
+
+        let default = relative_paths(&workspace, &root, &FilesSettings::default());
+        assert_eq!(default, vec!["a.css", "styles/b.css", "vendor/f.css"]);
+
+        let settings = FilesSettings {
+            include: "styles/**/*.{css,scss}".to_owned(),
+            exclude: vec!["vendor".to_owned()],
+        };
+        let custom = relative_paths(&workspace, &root, &settings);
+        assert_eq!(custom, vec!["styles/b.css", "styles/c.scss"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn open_and_on_disk_files() {
+        let root = temp_root("open-and-on-disk-files");
+        write(&root, "a.css", ".on-disk {}");
+        let url = Url::from_file_path(root.join("a.css")).unwrap();
+        let mut workspace = Workspace::new(vec![Url::from_directory_path(&root).unwrap()]);
+        let text =
+            |workspace: &Workspace| workspace.get_document_ref(&url).unwrap().text().to_string();
+
+        workspace.open(url.clone(), Source::new(url.clone(), ".opened {}", 1));
+        workspace.register_files(&FilesSettings::default()).unwrap();
+        assert!(workspace.is_open(&url));
+        assert_eq!(text(&workspace), ".opened {}");
+        workspace.evict_all();
+        assert_eq!(text(&workspace), ".opened {}");
+
+        let path = workspace.close(&url).unwrap();
+        assert!(!workspace.is_open(&url));
+        assert!(workspace.get_document_ref(&url).is_err());
+        assert!(
+            Workspace::is_indexable(workspace.roots(), &path, &FilesSettings::default()).unwrap()
+        );
+        workspace.register(Workspace::read_file(&path).unwrap());
+        assert_eq!(text(&workspace), ".on-disk {}");
+        workspace.evict_all();
+        assert!(workspace.get_document_ref(&url).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn indexable_files() {
+        let root = temp_root("indexable-files");
+        write(&root, ".gitignore", "dist/\n");
+        write(&root, "styles/.gitignore", "!keep.css\n*.css\n");
+        let workspace = Workspace::new(vec![Url::from_directory_path(&root).unwrap()]);
+        let settings = FilesSettings::default();
+        let indexable = |path: &str| {
+            Workspace::is_indexable(workspace.roots(), &root.join(path), &settings).unwrap()
+        };
+
+        assert!(indexable("a.css"));
+        assert!(!indexable("a.txt"));
+        assert!(!indexable("dist/b.css"));
+        assert!(!indexable("node_modules/pkg/c.css"));
+        assert!(!indexable(".hidden/d.css"));
+        assert!(!indexable("styles/e.css"));
+        assert!(!indexable("../outside.css"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn remove_root() {
+        let root = temp_root("remove-root");
+        write(&root, "a/one.css", "");
+        write(&root, "b/two.css", "");
+        let a = Url::from_directory_path(root.join("a")).unwrap();
+        let b = Url::from_directory_path(root.join("b")).unwrap();
+        let one = Url::from_file_path(root.join("a/one.css")).unwrap();
+        let two = Url::from_file_path(root.join("b/two.css")).unwrap();
+        let mut workspace = Workspace::new(vec![a.clone()]);
+        workspace.add_root(b.clone());
+        workspace.register_files(&FilesSettings::default()).unwrap();
+        workspace.open(two.clone(), Source::new(two.clone(), "", 1));
+
+        workspace.remove_root(&a);
+        workspace.remove_root(&b);
+        assert!(workspace.get_document_ref(&one).is_err());
+        assert!(workspace.get_document_ref(&two).is_ok());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn evict_deleted_folder() {
+        let root = temp_root("evict-deleted-folder");
+        write(&root, "styles/one.css", "");
+        write(&root, "styles/two.css", "");
+        write(&root, "styles-extra/three.css", "");
+        let one = Url::from_file_path(root.join("styles/one.css")).unwrap();
+        let two = Url::from_file_path(root.join("styles/two.css")).unwrap();
+        let three = Url::from_file_path(root.join("styles-extra/three.css")).unwrap();
+        let mut workspace = Workspace::new(vec![Url::from_directory_path(&root).unwrap()]);
+        workspace.register_files(&FilesSettings::default()).unwrap();
+        workspace.open(two.clone(), Source::new(two.clone(), "", 1));
+
+        workspace.evict_under(&root.join("styles"));
+        assert!(workspace.get_document_ref(&one).is_err());
+        assert!(workspace.get_document_ref(&two).is_ok());
+        assert!(workspace.get_document_ref(&three).is_ok());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
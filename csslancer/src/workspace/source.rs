@@ -176,6 +176,7 @@ impl Source {
                 insert: new,
             }
         );
+        self.lines = Line::lines(&self.text().to_string());
         // let old = self.text();
 
         // let mut prefix = old
@@ -234,6 +235,8 @@ impl Source {
         };
 
         self.parse = self.parse.reparse(&indel);
+        // FIXME: only recompute the lines after the edit
+        self.lines = Line::lines(&self.text().to_string());
         //return replace // FIXME
 
         // Update the text itself.